@@ -0,0 +1,27 @@
+use crate::commands::RpcClientCommand;
+use crate::responses::empty::EmptyResponse;
+use crate::types::Chain;
+
+/// Command for the `DELETE /chains/{chain_id}/invalid_blocks/{block_hash}` endpoint,
+/// removing a block from the node's list of invalid blocks so that it can be
+/// validated again.
+pub struct DeleteInvalidBlock {
+    pub chain_id: Chain,
+    pub block_hash: String,
+}
+
+impl RpcClientCommand for DeleteInvalidBlock {
+    type R = EmptyResponse;
+
+    fn get_url_string(&self) -> String {
+        format!(
+            "chains/{}/invalid_blocks/{}",
+            self.chain_id.to_str(),
+            &self.block_hash
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::DELETE
+    }
+}
@@ -0,0 +1,70 @@
+use super::block_responses::ConsensusRightsResponse;
+use super::get_endorsing_rights::GetConsensusRightsParameters;
+use crate::commands::RpcClientCommand;
+use crate::types::{Block, Chain};
+
+type Level = i32;
+type Cycle = i32;
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/helpers/attestation_rights` endpoint,
+/// the name of [`GetEndorsingRights`](super::get_endorsing_rights::GetEndorsingRights)
+/// from the Oxford protocol on.
+#[derive(Debug)]
+pub struct GetAttestationRights {
+    pub chain_id: Chain,
+    pub block_id: Block,
+    params: Option<GetConsensusRightsParameters>,
+}
+
+impl GetAttestationRights {
+    pub fn with_default_params(chain_id: Chain, block_id: Block) -> Self {
+        Self {
+            chain_id,
+            block_id,
+            params: None,
+        }
+    }
+
+    pub fn with_explicit_params(
+        chain_id: Chain,
+        block_id: Block,
+        levels: Vec<Level>,
+        cycles: Vec<Cycle>,
+        delegates: Vec<String>,
+        consensus_keys: Vec<String>,
+    ) -> Self {
+        let params = GetConsensusRightsParameters {
+            levels,
+            cycles,
+            delegates,
+            consensus_keys,
+        };
+
+        Self {
+            chain_id,
+            block_id,
+            params: Some(params),
+        }
+    }
+}
+
+impl RpcClientCommand for GetAttestationRights {
+    type R = ConsensusRightsResponse;
+
+    fn get_url_string(&self) -> String {
+        let mut url_string = format!(
+            "chains/{}/blocks/{}/helpers/attestation_rights",
+            self.chain_id.to_str(),
+            self.block_id.to_str()
+        );
+        if let Some(params) = &self.params {
+            url_string.push('?');
+            url_string.push_str(&params.to_url_query_string());
+        }
+        url_string
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
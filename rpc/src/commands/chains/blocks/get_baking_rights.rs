@@ -0,0 +1,183 @@
+use super::block_responses::BakingRightsResponse;
+use crate::commands::RpcClientCommand;
+use crate::types::{Block, Chain};
+use querystring;
+
+type Level = i32;
+type Cycle = i32;
+
+/// Upper bound on the rounds returned by [`GetBakingRights`].
+///
+/// Protocols before Ithaca call rounds priorities and only accept
+/// `MaxPriority`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundLimit {
+    MaxRound(u32),
+    MaxPriority(u32),
+}
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/helpers/baking_rights` endpoint,
+/// returning which delegates may bake blocks, at which round and when.
+///
+/// Without parameters ([the default constructor](Self::with_default_params)), the node
+/// returns the rights for the next level. [The explicit constructor](Self::with_explicit_params)
+/// filters rights by level, cycle, delegate or consensus key, and `all` includes the
+/// rights of delegates which already have a better round at the same level.
+#[derive(Debug)]
+pub struct GetBakingRights {
+    pub chain_id: Chain,
+    pub block_id: Block,
+    params: Option<GetBakingRightsParameters>,
+}
+
+impl GetBakingRights {
+    pub fn with_default_params(chain_id: Chain, block_id: Block) -> Self {
+        Self {
+            chain_id,
+            block_id,
+            params: None,
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_explicit_params(
+        chain_id: Chain,
+        block_id: Block,
+        levels: Vec<Level>,
+        cycles: Vec<Cycle>,
+        delegates: Vec<String>,
+        consensus_keys: Vec<String>,
+        round_limit: Option<RoundLimit>,
+        all: bool,
+    ) -> Self {
+        let params = GetBakingRightsParameters {
+            levels,
+            cycles,
+            delegates,
+            consensus_keys,
+            round_limit,
+            all,
+        };
+
+        Self {
+            chain_id,
+            block_id,
+            params: Some(params),
+        }
+    }
+}
+
+impl RpcClientCommand for GetBakingRights {
+    type R = BakingRightsResponse;
+
+    fn get_url_string(&self) -> String {
+        let mut url_string = format!(
+            "chains/{}/blocks/{}/helpers/baking_rights",
+            self.chain_id.to_str(),
+            self.block_id.to_str()
+        );
+        if let Some(params) = &self.params {
+            url_string.push('?');
+            url_string.push_str(&params.to_url_query_string());
+        }
+        url_string
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+#[derive(Debug)]
+struct GetBakingRightsParameters {
+    levels: Vec<Level>,
+    cycles: Vec<Cycle>,
+    delegates: Vec<String>,
+    consensus_keys: Vec<String>,
+    round_limit: Option<RoundLimit>,
+    all: bool,
+}
+
+impl GetBakingRightsParameters {
+    fn to_url_query_string(&self) -> String {
+        let mut query_pairs = Vec::new();
+
+        for level in &self.levels {
+            query_pairs.push(("level", level.to_string()));
+        }
+        for cycle in &self.cycles {
+            query_pairs.push(("cycle", cycle.to_string()));
+        }
+        for delegate in &self.delegates {
+            query_pairs.push(("delegate", delegate.to_string()));
+        }
+        for consensus_key in &self.consensus_keys {
+            query_pairs.push(("consensus_key", consensus_key.to_string()));
+        }
+        match &self.round_limit {
+            Some(RoundLimit::MaxRound(round)) => query_pairs.push(("max_round", round.to_string())),
+            Some(RoundLimit::MaxPriority(priority)) => {
+                query_pairs.push(("max_priority", priority.to_string()))
+            }
+            None => (),
+        }
+
+        let query_params = query_pairs.iter().map(|x| (x.0, x.1.as_str())).collect();
+        let mut query_string = querystring::stringify(query_params);
+        if self.all {
+            query_string.push_str("all");
+        }
+        query_string
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn params_with_round_limit(round_limit: Option<RoundLimit>) -> GetBakingRightsParameters {
+        GetBakingRightsParameters {
+            levels: vec![],
+            cycles: vec![],
+            delegates: vec![],
+            consensus_keys: vec![],
+            round_limit,
+            all: false,
+        }
+    }
+
+    #[test]
+    fn all_params_some_to_string_ok() {
+        let params = GetBakingRightsParameters {
+            levels: vec![10, 11],
+            cycles: vec![2],
+            delegates: vec!["tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx".to_string()],
+            consensus_keys: vec!["tz1VkjQjvH3NTVdFtGz9tWutgxLKwnhnMe2x".to_string()],
+            round_limit: Some(RoundLimit::MaxRound(3)),
+            all: true,
+        };
+
+        let correct_query_string = "level=10&level=11&cycle=2&delegate=tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx&consensus_key=tz1VkjQjvH3NTVdFtGz9tWutgxLKwnhnMe2x&max_round=3&all";
+        assert_eq!(correct_query_string, params.to_url_query_string());
+    }
+
+    #[test]
+    fn all_params_none_to_string_ok() {
+        assert_eq!("", params_with_round_limit(None).to_url_query_string());
+    }
+
+    #[test]
+    fn max_priority_to_string_ok() {
+        let params = params_with_round_limit(Some(RoundLimit::MaxPriority(64)));
+        assert_eq!("max_priority=64&", params.to_url_query_string());
+    }
+
+    #[test]
+    fn default_params_url_string_ok() {
+        let command = GetBakingRights::with_default_params(Chain::Main, Block::Head);
+        assert_eq!(
+            "chains/main/blocks/head/helpers/baking_rights",
+            command.get_url_string()
+        );
+    }
+}
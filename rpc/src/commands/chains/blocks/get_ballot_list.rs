@@ -0,0 +1,26 @@
+use super::block_responses::BallotListResponse;
+use crate::commands::RpcClientCommand;
+use crate::types::{Block, Chain};
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/votes/ballot_list` endpoint,
+/// listing the ballots cast by delegates in the current voting period.
+pub struct GetBallotList {
+    pub chain_id: Chain,
+    pub block_id: Block,
+}
+
+impl RpcClientCommand for GetBallotList {
+    type R = BallotListResponse;
+
+    fn get_url_string(&self) -> String {
+        format!(
+            "chains/{}/blocks/{}/votes/ballot_list",
+            &self.chain_id.to_str(),
+            &self.block_id.to_str()
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
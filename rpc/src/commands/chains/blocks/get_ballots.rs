@@ -0,0 +1,26 @@
+use super::block_responses::BallotsResponse;
+use crate::commands::RpcClientCommand;
+use crate::types::{Block, Chain};
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/votes/ballots` endpoint,
+/// returning the voting power of the ballots cast in the current voting period.
+pub struct GetBallots {
+    pub chain_id: Chain,
+    pub block_id: Block,
+}
+
+impl RpcClientCommand for GetBallots {
+    type R = BallotsResponse;
+
+    fn get_url_string(&self) -> String {
+        format!(
+            "chains/{}/blocks/{}/votes/ballots",
+            &self.chain_id.to_str(),
+            &self.block_id.to_str()
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -0,0 +1,41 @@
+use super::block_responses::BigMapValueResponse;
+use crate::commands::RpcClientCommand;
+use crate::types::{Block, Chain};
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/context/big_maps/{big_map_id}/{script_expr}`
+/// endpoint, returning the value bound to a key of a big map.
+///
+/// `script_expr` is the `expr...` hash of the packed key, see
+/// [`script_expr_hash`](crate::michelson::script_expr_hash).
+///
+/// The node answers `404 Not Found` with an empty body when the key is not
+/// bound, which gives a response without value. Other errors, such as a
+/// failing node, are still errors.
+pub struct GetBigMapValue {
+    pub chain_id: Chain,
+    pub block_id: Block,
+    pub big_map_id: String,
+    pub script_expr: String,
+}
+
+impl RpcClientCommand for GetBigMapValue {
+    type R = BigMapValueResponse;
+
+    fn get_url_string(&self) -> String {
+        format!(
+            "chains/{}/blocks/{}/context/big_maps/{}/{}",
+            &self.chain_id.to_str(),
+            &self.block_id.to_str(),
+            &self.big_map_id,
+            &self.script_expr
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn not_found_response(&self) -> Option<BigMapValueResponse> {
+        Some(BigMapValueResponse { value: None })
+    }
+}
@@ -0,0 +1,26 @@
+use super::block_responses::BlockHashResponse;
+use crate::commands::RpcClientCommand;
+use crate::types::{Block, Chain};
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/hash` endpoint,
+/// returning the hash of the block, e.g. to resolve `head`.
+pub struct GetBlockHash {
+    pub chain_id: Chain,
+    pub block_id: Block,
+}
+
+impl RpcClientCommand for GetBlockHash {
+    type R = BlockHashResponse;
+
+    fn get_url_string(&self) -> String {
+        format!(
+            "chains/{}/blocks/{}/hash",
+            self.chain_id.to_str(),
+            self.block_id.to_str()
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
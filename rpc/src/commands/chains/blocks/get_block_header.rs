@@ -0,0 +1,52 @@
+use super::block_responses::{BlockHeader, RawBlockHeaderResponse};
+use crate::commands::RpcClientCommand;
+use crate::types::{Block, Chain};
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/header` endpoint,
+/// returning the block's header.
+pub struct GetBlockHeader {
+    pub chain_id: Chain,
+    pub block_id: Block,
+}
+
+impl RpcClientCommand for GetBlockHeader {
+    type R = BlockHeader;
+
+    fn get_url_string(&self) -> String {
+        format!(
+            "chains/{}/blocks/{}/header",
+            self.chain_id.to_str(),
+            self.block_id.to_str()
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/header/raw` endpoint,
+/// returning the block's header in binary form, as signed by its baker.
+///
+/// Use it with [`BlockHeader::verify()`](super::block_responses::BlockHeader::verify())
+/// to check a header locally.
+pub struct GetBlockHeaderRaw {
+    pub chain_id: Chain,
+    pub block_id: Block,
+}
+
+impl RpcClientCommand for GetBlockHeaderRaw {
+    type R = RawBlockHeaderResponse;
+
+    fn get_url_string(&self) -> String {
+        format!(
+            "chains/{}/blocks/{}/header/raw",
+            self.chain_id.to_str(),
+            self.block_id.to_str()
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -0,0 +1,29 @@
+use crate::commands::RpcClientCommand;
+use crate::responses::protocols::BlockMetadata;
+use crate::types::{Block, Chain};
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/metadata` endpoint,
+/// returning the metadata computed when the block was applied.
+///
+/// Wrap it in [`WithProtocol`](crate::commands::protocols::with_protocol::WithProtocol)
+/// to check the response against the schema of the block's protocol.
+pub struct GetBlockMetadata {
+    pub chain_id: Chain,
+    pub block_id: Block,
+}
+
+impl RpcClientCommand for GetBlockMetadata {
+    type R = BlockMetadata;
+
+    fn get_url_string(&self) -> String {
+        format!(
+            "chains/{}/blocks/{}/metadata",
+            self.chain_id.to_str(),
+            self.block_id.to_str()
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -0,0 +1,27 @@
+use super::block_responses::BlockProtocolsResponse;
+use crate::commands::RpcClientCommand;
+use crate::types::{Block, Chain};
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/protocols` endpoint,
+/// returning the protocol the block was produced with and the protocol of
+/// its successor.
+pub struct GetBlockProtocols {
+    pub chain_id: Chain,
+    pub block_id: Block,
+}
+
+impl RpcClientCommand for GetBlockProtocols {
+    type R = BlockProtocolsResponse;
+
+    fn get_url_string(&self) -> String {
+        format!(
+            "chains/{}/blocks/{}/protocols",
+            self.chain_id.to_str(),
+            self.block_id.to_str()
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -81,7 +81,7 @@ impl GetBlocksInChainParameters {
             query_pairs.push(("head", head.to_string()));
         }
         if let Some(min_date) = &self.min_date {
-            query_pairs.push(("min_date", min_date.timestamp().to_string()));
+            query_pairs.push(("min_date", min_date.and_utc().timestamp().to_string()));
         }
 
         let query_params = query_pairs.iter().map(|x| (x.0, x.1.as_str())).collect();
@@ -93,18 +93,19 @@ impl GetBlocksInChainParameters {
 #[cfg(test)]
 mod test {
     use super::*;
+    use chrono::DateTime;
 
     #[test]
     fn all_params_some_to_string_ok() {
         let length = 10;
         let head = "head".to_string();
-        let min_date = NaiveDateTime::from_timestamp(0, 0);
+        let min_date = DateTime::from_timestamp(0, 0).unwrap().naive_utc();
 
         let correct_query_string = format!(
             "length={}&head={}&min_date={}&",
             &length,
             &head,
-            &min_date.timestamp()
+            &min_date.and_utc().timestamp()
         );
 
         let params = GetBlocksInChainParameters {
@@ -160,9 +161,9 @@ mod test {
 
     #[test]
     fn only_min_date_some_ok() {
-        let min_date = NaiveDateTime::from_timestamp(0, 0);
+        let min_date = DateTime::from_timestamp(0, 0).unwrap().naive_utc();
 
-        let correct_query_string = format!("min_date={}&", &min_date.timestamp());
+        let correct_query_string = format!("min_date={}&", &min_date.and_utc().timestamp());
 
         let params = GetBlocksInChainParameters {
             length: None,
@@ -0,0 +1,21 @@
+use super::block_responses::ChainIdResponse;
+use crate::commands::RpcClientCommand;
+use crate::types::Chain;
+
+/// Command for the `/chains/{chain_id}/chain_id` endpoint,
+/// returning the base58 identifier (`Net...`) of the chain.
+pub struct GetChainId {
+    pub chain_id: Chain,
+}
+
+impl RpcClientCommand for GetChainId {
+    type R = ChainIdResponse;
+
+    fn get_url_string(&self) -> String {
+        format!("chains/{}/chain_id", self.chain_id.to_str())
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
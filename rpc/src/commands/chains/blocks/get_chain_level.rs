@@ -0,0 +1,61 @@
+use super::block_responses::BlockLevelResponse;
+use crate::commands::RpcClientCommand;
+use crate::types::Chain;
+
+/// Remarkable blocks of the chain stored by a node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChainLevel {
+    /// Block below which the node refuses to reorganize the chain.
+    Checkpoint,
+    /// Oldest block for which the node keeps the full context and metadata.
+    Savepoint,
+    /// Oldest block the node stores at all.
+    Caboose,
+}
+
+impl ChainLevel {
+    pub fn to_str(&self) -> &str {
+        match self {
+            Self::Checkpoint => "checkpoint",
+            Self::Savepoint => "savepoint",
+            Self::Caboose => "caboose",
+        }
+    }
+}
+
+/// Command for the `/chains/{chain_id}/levels/{checkpoint,savepoint,caboose}` endpoints,
+/// returning the hash and level of one of the remarkable blocks of the chain.
+pub struct GetChainLevel {
+    pub chain_id: Chain,
+    pub level: ChainLevel,
+}
+
+impl RpcClientCommand for GetChainLevel {
+    type R = BlockLevelResponse;
+
+    fn get_url_string(&self) -> String {
+        format!(
+            "chains/{}/levels/{}",
+            self.chain_id.to_str(),
+            self.level.to_str()
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn savepoint_url_string_ok() {
+        let command = GetChainLevel {
+            chain_id: Chain::Main,
+            level: ChainLevel::Savepoint,
+        };
+        assert_eq!(command.get_url_string(), "chains/main/levels/savepoint");
+    }
+}
@@ -0,0 +1,24 @@
+use super::block_responses::CheckpointResponse;
+use crate::commands::RpcClientCommand;
+use crate::types::Chain;
+
+/// Command for the `/chains/{chain_id}/checkpoint` endpoint,
+/// returning the checkpoint, savepoint and caboose of the node along
+/// with its history mode.
+///
+/// Removed from recent nodes in favor of [`GetChainLevel`](super::get_chain_level::GetChainLevel).
+pub struct GetCheckpoint {
+    pub chain_id: Chain,
+}
+
+impl RpcClientCommand for GetCheckpoint {
+    type R = CheckpointResponse;
+
+    fn get_url_string(&self) -> String {
+        format!("chains/{}/checkpoint", self.chain_id.to_str())
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
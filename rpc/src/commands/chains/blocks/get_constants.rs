@@ -0,0 +1,26 @@
+use super::block_responses::ProtocolConstants;
+use crate::commands::RpcClientCommand;
+use crate::types::{Block, Chain};
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/context/constants` endpoint,
+/// returning the constants of the protocol the block is in.
+pub struct GetConstants {
+    pub chain_id: Chain,
+    pub block_id: Block,
+}
+
+impl RpcClientCommand for GetConstants {
+    type R = ProtocolConstants;
+
+    fn get_url_string(&self) -> String {
+        format!(
+            "chains/{}/blocks/{}/context/constants",
+            &self.chain_id.to_str(),
+            &self.block_id.to_str()
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
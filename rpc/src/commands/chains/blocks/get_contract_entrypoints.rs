@@ -0,0 +1,28 @@
+use super::block_responses::ContractEntrypointsResponse;
+use crate::commands::RpcClientCommand;
+use crate::types::{Block, Chain};
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/context/contracts/{contract_id}/entrypoints`
+/// endpoint, returning the type of each entrypoint of a smart contract.
+pub struct GetContractEntrypoints {
+    pub chain_id: Chain,
+    pub block_id: Block,
+    pub contract_id: String,
+}
+
+impl RpcClientCommand for GetContractEntrypoints {
+    type R = ContractEntrypointsResponse;
+
+    fn get_url_string(&self) -> String {
+        format!(
+            "chains/{}/blocks/{}/context/contracts/{}/entrypoints",
+            &self.chain_id.to_str(),
+            &self.block_id.to_str(),
+            &self.contract_id
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
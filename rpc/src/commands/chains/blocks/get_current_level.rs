@@ -0,0 +1,67 @@
+use super::block_responses::LevelInfo;
+use crate::commands::RpcClientCommand;
+use crate::types::{Block, Chain};
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/helpers/current_level` endpoint,
+/// returning the level of the block `offset` levels after `block_id`
+/// (before, when negative), along with its position in its cycle.
+///
+/// The offset defaults to 0 with [the default constructor](Self::with_default_params).
+#[derive(Debug)]
+pub struct GetCurrentLevel {
+    pub chain_id: Chain,
+    pub block_id: Block,
+    offset: Option<i32>,
+}
+
+impl GetCurrentLevel {
+    pub fn with_default_params(chain_id: Chain, block_id: Block) -> Self {
+        Self {
+            chain_id,
+            block_id,
+            offset: None,
+        }
+    }
+
+    pub fn with_explicit_params(chain_id: Chain, block_id: Block, offset: Option<i32>) -> Self {
+        Self {
+            chain_id,
+            block_id,
+            offset,
+        }
+    }
+}
+
+impl RpcClientCommand for GetCurrentLevel {
+    type R = LevelInfo;
+
+    fn get_url_string(&self) -> String {
+        let mut url_string = format!(
+            "chains/{}/blocks/{}/helpers/current_level",
+            &self.chain_id.to_str(),
+            &self.block_id.to_str()
+        );
+        if let Some(offset) = &self.offset {
+            url_string.push_str(&format!("?offset={}", offset));
+        }
+        url_string
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn negative_offset_url_string_ok() {
+        let command = GetCurrentLevel::with_explicit_params(Chain::Main, Block::Head, Some(-2));
+        assert_eq!(
+            command.get_url_string(),
+            "chains/main/blocks/head/helpers/current_level?offset=-2"
+        );
+    }
+}
@@ -0,0 +1,26 @@
+use super::block_responses::CurrentProposalResponse;
+use crate::commands::RpcClientCommand;
+use crate::types::{Block, Chain};
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/votes/current_proposal` endpoint,
+/// returning the protocol being voted on, if any.
+pub struct GetCurrentProposal {
+    pub chain_id: Chain,
+    pub block_id: Block,
+}
+
+impl RpcClientCommand for GetCurrentProposal {
+    type R = CurrentProposalResponse;
+
+    fn get_url_string(&self) -> String {
+        format!(
+            "chains/{}/blocks/{}/votes/current_proposal",
+            &self.chain_id.to_str(),
+            &self.block_id.to_str()
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
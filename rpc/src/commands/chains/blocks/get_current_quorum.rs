@@ -0,0 +1,26 @@
+use super::block_responses::CurrentQuorumResponse;
+use crate::commands::RpcClientCommand;
+use crate::types::{Block, Chain};
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/votes/current_quorum` endpoint,
+/// returning the participation needed for the current vote to be valid.
+pub struct GetCurrentQuorum {
+    pub chain_id: Chain,
+    pub block_id: Block,
+}
+
+impl RpcClientCommand for GetCurrentQuorum {
+    type R = CurrentQuorumResponse;
+
+    fn get_url_string(&self) -> String {
+        format!(
+            "chains/{}/blocks/{}/votes/current_quorum",
+            &self.chain_id.to_str(),
+            &self.block_id.to_str()
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -0,0 +1,26 @@
+use super::block_responses::VotingPeriodInfoResponse;
+use crate::commands::RpcClientCommand;
+use crate::types::{Block, Chain};
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/votes/current_period` endpoint,
+/// returning the voting period of the block and the block's position in it.
+pub struct GetCurrentVotingPeriod {
+    pub chain_id: Chain,
+    pub block_id: Block,
+}
+
+impl RpcClientCommand for GetCurrentVotingPeriod {
+    type R = VotingPeriodInfoResponse;
+
+    fn get_url_string(&self) -> String {
+        format!(
+            "chains/{}/blocks/{}/votes/current_period",
+            &self.chain_id.to_str(),
+            &self.block_id.to_str()
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -0,0 +1,29 @@
+use super::block_responses::DelegateResponse;
+use crate::commands::RpcClientCommand;
+use crate::types::{Block, Chain};
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/context/delegates/{pkh}`
+/// endpoint, returning everything known about a delegate: balances, deposits,
+/// delegators and voting power.
+pub struct GetDelegate {
+    pub chain_id: Chain,
+    pub block_id: Block,
+    pub pkh: String,
+}
+
+impl RpcClientCommand for GetDelegate {
+    type R = DelegateResponse;
+
+    fn get_url_string(&self) -> String {
+        format!(
+            "chains/{}/blocks/{}/context/delegates/{}",
+            &self.chain_id.to_str(),
+            &self.block_id.to_str(),
+            &self.pkh
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -0,0 +1,76 @@
+use super::block_responses::DelegateBalanceResponse;
+use crate::commands::RpcClientCommand;
+use crate::types::{Block, Chain};
+
+/// The balances of a delegate exposed under `/context/delegates/{pkh}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DelegateBalanceKind {
+    /// Spendable balance plus frozen deposits and bonds.
+    Full,
+    /// Full balance plus the balances of delegated contracts.
+    Staking,
+    /// Balances of the contracts delegating to the delegate.
+    Delegated,
+    /// Deposits frozen for the current cycle.
+    FrozenDeposits,
+    /// Deposits currently frozen, including those of past cycles.
+    CurrentFrozenDeposits,
+}
+
+impl DelegateBalanceKind {
+    pub fn to_str(&self) -> &str {
+        match self {
+            Self::Full => "full_balance",
+            Self::Staking => "staking_balance",
+            Self::Delegated => "delegated_balance",
+            Self::FrozenDeposits => "frozen_deposits",
+            Self::CurrentFrozenDeposits => "current_frozen_deposits",
+        }
+    }
+}
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/context/delegates/{pkh}/{balance}`
+/// endpoints, returning one of the balances of a delegate.
+pub struct GetDelegateBalance {
+    pub chain_id: Chain,
+    pub block_id: Block,
+    pub pkh: String,
+    pub kind: DelegateBalanceKind,
+}
+
+impl RpcClientCommand for GetDelegateBalance {
+    type R = DelegateBalanceResponse;
+
+    fn get_url_string(&self) -> String {
+        format!(
+            "chains/{}/blocks/{}/context/delegates/{}/{}",
+            &self.chain_id.to_str(),
+            &self.block_id.to_str(),
+            &self.pkh,
+            &self.kind.to_str()
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn staking_balance_url_string_ok() {
+        let command = GetDelegateBalance {
+            chain_id: Chain::Main,
+            block_id: Block::Head,
+            pkh: "tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx".to_string(),
+            kind: DelegateBalanceKind::Staking,
+        };
+        assert_eq!(
+            command.get_url_string(),
+            "chains/main/blocks/head/context/delegates/tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx/staking_balance"
+        );
+    }
+}
@@ -0,0 +1,29 @@
+use super::block_responses::DelegateDeactivatedResponse;
+use crate::commands::RpcClientCommand;
+use crate::types::{Block, Chain};
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/context/delegates/{pkh}/deactivated`
+/// endpoint, returning whether a delegate has been deactivated for not
+/// participating in consensus.
+pub struct GetDelegateDeactivated {
+    pub chain_id: Chain,
+    pub block_id: Block,
+    pub pkh: String,
+}
+
+impl RpcClientCommand for GetDelegateDeactivated {
+    type R = DelegateDeactivatedResponse;
+
+    fn get_url_string(&self) -> String {
+        format!(
+            "chains/{}/blocks/{}/context/delegates/{}/deactivated",
+            &self.chain_id.to_str(),
+            &self.block_id.to_str(),
+            &self.pkh
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
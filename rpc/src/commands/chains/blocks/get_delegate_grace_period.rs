@@ -0,0 +1,29 @@
+use super::block_responses::DelegateGracePeriodResponse;
+use crate::commands::RpcClientCommand;
+use crate::types::{Block, Chain};
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/context/delegates/{pkh}/grace_period`
+/// endpoint, returning the cycle after which a delegate is deactivated if it
+/// stays inactive.
+pub struct GetDelegateGracePeriod {
+    pub chain_id: Chain,
+    pub block_id: Block,
+    pub pkh: String,
+}
+
+impl RpcClientCommand for GetDelegateGracePeriod {
+    type R = DelegateGracePeriodResponse;
+
+    fn get_url_string(&self) -> String {
+        format!(
+            "chains/{}/blocks/{}/context/delegates/{}/grace_period",
+            &self.chain_id.to_str(),
+            &self.block_id.to_str(),
+            &self.pkh
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -0,0 +1,29 @@
+use super::block_responses::DelegateParticipationResponse;
+use crate::commands::RpcClientCommand;
+use crate::types::{Block, Chain};
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/context/delegates/{pkh}/participation`
+/// endpoint, returning a delegate's consensus participation in the current
+/// cycle.
+pub struct GetDelegateParticipation {
+    pub chain_id: Chain,
+    pub block_id: Block,
+    pub pkh: String,
+}
+
+impl RpcClientCommand for GetDelegateParticipation {
+    type R = DelegateParticipationResponse;
+
+    fn get_url_string(&self) -> String {
+        format!(
+            "chains/{}/blocks/{}/context/delegates/{}/participation",
+            &self.chain_id.to_str(),
+            &self.block_id.to_str(),
+            &self.pkh
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -0,0 +1,29 @@
+use super::block_responses::VotingPowerResponse;
+use crate::commands::RpcClientCommand;
+use crate::types::{Block, Chain};
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/context/delegates/{pkh}/voting_power`
+/// endpoint, returning the voting power of a delegate in the current voting
+/// period.
+pub struct GetDelegateVotingPower {
+    pub chain_id: Chain,
+    pub block_id: Block,
+    pub pkh: String,
+}
+
+impl RpcClientCommand for GetDelegateVotingPower {
+    type R = VotingPowerResponse;
+
+    fn get_url_string(&self) -> String {
+        format!(
+            "chains/{}/blocks/{}/context/delegates/{}/voting_power",
+            &self.chain_id.to_str(),
+            &self.block_id.to_str(),
+            &self.pkh
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -0,0 +1,101 @@
+use super::block_responses::DelegatesResponse;
+use crate::commands::RpcClientCommand;
+use crate::types::{Block, Chain};
+
+/// Filter on the delegates listed by [`GetDelegates`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DelegateStatus {
+    Active,
+    Inactive,
+}
+
+impl DelegateStatus {
+    pub fn to_str(&self) -> &str {
+        match self {
+            Self::Active => "active",
+            Self::Inactive => "inactive",
+        }
+    }
+}
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/context/delegates` endpoint,
+/// listing the public key hashes of registered delegates.
+///
+/// Lists all delegates with [the default constructor](Self::with_default_params),
+/// or only active or inactive ones with [the explicit constructor](Self::with_explicit_params)
+#[derive(Debug)]
+pub struct GetDelegates {
+    pub chain_id: Chain,
+    pub block_id: Block,
+    status: Option<DelegateStatus>,
+}
+
+impl GetDelegates {
+    pub fn with_default_params(chain_id: Chain, block_id: Block) -> Self {
+        Self {
+            chain_id,
+            block_id,
+            status: None,
+        }
+    }
+
+    pub fn with_explicit_params(
+        chain_id: Chain,
+        block_id: Block,
+        status: Option<DelegateStatus>,
+    ) -> Self {
+        Self {
+            chain_id,
+            block_id,
+            status,
+        }
+    }
+}
+
+impl RpcClientCommand for GetDelegates {
+    type R = DelegatesResponse;
+
+    fn get_url_string(&self) -> String {
+        let mut url_string = format!(
+            "chains/{}/blocks/{}/context/delegates",
+            &self.chain_id.to_str(),
+            &self.block_id.to_str()
+        );
+        if let Some(status) = &self.status {
+            url_string.push('?');
+            url_string.push_str(status.to_str());
+        }
+        url_string
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn active_delegates_url_string_ok() {
+        let command = GetDelegates::with_explicit_params(
+            Chain::Main,
+            Block::Head,
+            Some(DelegateStatus::Active),
+        );
+        assert_eq!(
+            command.get_url_string(),
+            "chains/main/blocks/head/context/delegates?active"
+        );
+    }
+
+    #[test]
+    fn default_params_url_string_ok() {
+        let command = GetDelegates::with_default_params(Chain::Main, Block::Head);
+        assert_eq!(
+            command.get_url_string(),
+            "chains/main/blocks/head/context/delegates"
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use super::block_responses::ConsensusRightsResponse;
+use crate::commands::RpcClientCommand;
+use crate::types::{Block, Chain};
+use querystring;
+
+type Level = i32;
+type Cycle = i32;
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/helpers/endorsing_rights` endpoint,
+/// returning which delegates may endorse blocks, with which slots and when.
+///
+/// Protocols from Oxford on call endorsements attestations and expose the same rights
+/// through [`GetAttestationRights`](super::get_attestation_rights::GetAttestationRights).
+/// Rights of protocols before Ithaca are grouped by level, with their slots listed.
+///
+/// Without parameters ([the default constructor](Self::with_default_params)), the node
+/// returns the rights for the current level. [The explicit constructor](Self::with_explicit_params)
+/// filters rights by level, cycle, delegate or consensus key.
+#[derive(Debug)]
+pub struct GetEndorsingRights {
+    pub chain_id: Chain,
+    pub block_id: Block,
+    params: Option<GetConsensusRightsParameters>,
+}
+
+impl GetEndorsingRights {
+    pub fn with_default_params(chain_id: Chain, block_id: Block) -> Self {
+        Self {
+            chain_id,
+            block_id,
+            params: None,
+        }
+    }
+
+    pub fn with_explicit_params(
+        chain_id: Chain,
+        block_id: Block,
+        levels: Vec<Level>,
+        cycles: Vec<Cycle>,
+        delegates: Vec<String>,
+        consensus_keys: Vec<String>,
+    ) -> Self {
+        let params = GetConsensusRightsParameters {
+            levels,
+            cycles,
+            delegates,
+            consensus_keys,
+        };
+
+        Self {
+            chain_id,
+            block_id,
+            params: Some(params),
+        }
+    }
+}
+
+impl RpcClientCommand for GetEndorsingRights {
+    type R = ConsensusRightsResponse;
+
+    fn get_url_string(&self) -> String {
+        let mut url_string = format!(
+            "chains/{}/blocks/{}/helpers/endorsing_rights",
+            self.chain_id.to_str(),
+            self.block_id.to_str()
+        );
+        if let Some(params) = &self.params {
+            url_string.push('?');
+            url_string.push_str(&params.to_url_query_string());
+        }
+        url_string
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// Query parameters shared by the endorsing and attestation rights endpoints.
+#[derive(Debug)]
+pub(super) struct GetConsensusRightsParameters {
+    pub(super) levels: Vec<Level>,
+    pub(super) cycles: Vec<Cycle>,
+    pub(super) delegates: Vec<String>,
+    pub(super) consensus_keys: Vec<String>,
+}
+
+impl GetConsensusRightsParameters {
+    pub(super) fn to_url_query_string(&self) -> String {
+        let mut query_pairs = Vec::new();
+
+        for level in &self.levels {
+            query_pairs.push(("level", level.to_string()));
+        }
+        for cycle in &self.cycles {
+            query_pairs.push(("cycle", cycle.to_string()));
+        }
+        for delegate in &self.delegates {
+            query_pairs.push(("delegate", delegate.to_string()));
+        }
+        for consensus_key in &self.consensus_keys {
+            query_pairs.push(("consensus_key", consensus_key.to_string()));
+        }
+
+        let query_params = query_pairs.iter().map(|x| (x.0, x.1.as_str())).collect();
+
+        querystring::stringify(query_params)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn levels_and_delegate_to_string_ok() {
+        let params = GetConsensusRightsParameters {
+            levels: vec![10, 11],
+            cycles: vec![],
+            delegates: vec!["tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx".to_string()],
+            consensus_keys: vec![],
+        };
+
+        let correct_query_string =
+            "level=10&level=11&delegate=tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx&";
+        assert_eq!(correct_query_string, params.to_url_query_string());
+    }
+
+    #[test]
+    fn cycle_url_string_ok() {
+        let command = GetEndorsingRights::with_explicit_params(
+            Chain::Main,
+            Block::Head,
+            vec![],
+            vec![5],
+            vec![],
+            vec![],
+        );
+        assert_eq!(
+            "chains/main/blocks/head/helpers/endorsing_rights?cycle=5&",
+            command.get_url_string()
+        );
+    }
+}
@@ -0,0 +1,26 @@
+use super::block_responses::InvalidBlock;
+use crate::commands::RpcClientCommand;
+use crate::types::Chain;
+
+/// Command for the `GET /chains/{chain_id}/invalid_blocks/{block_hash}` endpoint,
+/// returning the errors which made the node reject a block.
+pub struct GetInvalidBlock {
+    pub chain_id: Chain,
+    pub block_hash: String,
+}
+
+impl RpcClientCommand for GetInvalidBlock {
+    type R = InvalidBlock;
+
+    fn get_url_string(&self) -> String {
+        format!(
+            "chains/{}/invalid_blocks/{}",
+            self.chain_id.to_str(),
+            &self.block_hash
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -0,0 +1,22 @@
+use super::block_responses::BootstrappedResponse;
+use crate::commands::RpcClientCommand;
+use crate::types::Chain;
+
+/// Command for the `/chains/{chain_id}/is_bootstrapped` endpoint,
+/// returning whether the node is bootstrapped and how it is synchronized
+/// with its peers.
+pub struct GetIsBootstrapped {
+    pub chain_id: Chain,
+}
+
+impl RpcClientCommand for GetIsBootstrapped {
+    type R = BootstrappedResponse;
+
+    fn get_url_string(&self) -> String {
+        format!("chains/{}/is_bootstrapped", self.chain_id.to_str())
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
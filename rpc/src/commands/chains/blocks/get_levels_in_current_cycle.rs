@@ -0,0 +1,53 @@
+use super::block_responses::CycleLevelsResponse;
+use crate::commands::RpcClientCommand;
+use crate::types::{Block, Chain};
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/helpers/levels_in_current_cycle` endpoint,
+/// returning the first and last levels of the cycle containing the block
+/// `offset` levels after `block_id`.
+///
+/// The offset defaults to 0 with [the default constructor](Self::with_default_params).
+#[derive(Debug)]
+pub struct GetLevelsInCurrentCycle {
+    pub chain_id: Chain,
+    pub block_id: Block,
+    offset: Option<i32>,
+}
+
+impl GetLevelsInCurrentCycle {
+    pub fn with_default_params(chain_id: Chain, block_id: Block) -> Self {
+        Self {
+            chain_id,
+            block_id,
+            offset: None,
+        }
+    }
+
+    pub fn with_explicit_params(chain_id: Chain, block_id: Block, offset: Option<i32>) -> Self {
+        Self {
+            chain_id,
+            block_id,
+            offset,
+        }
+    }
+}
+
+impl RpcClientCommand for GetLevelsInCurrentCycle {
+    type R = CycleLevelsResponse;
+
+    fn get_url_string(&self) -> String {
+        let mut url_string = format!(
+            "chains/{}/blocks/{}/helpers/levels_in_current_cycle",
+            &self.chain_id.to_str(),
+            &self.block_id.to_str()
+        );
+        if let Some(offset) = &self.offset {
+            url_string.push_str(&format!("?offset={}", offset));
+        }
+        url_string
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
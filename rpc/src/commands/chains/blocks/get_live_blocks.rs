@@ -0,0 +1,27 @@
+use super::block_responses::LiveBlocksResponse;
+use crate::commands::RpcClientCommand;
+use crate::types::{Block, Chain};
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/live_blocks` endpoint,
+/// returning the blocks that operations injected on top of the block can use
+/// as their branch.
+pub struct GetLiveBlocks {
+    pub chain_id: Chain,
+    pub block_id: Block,
+}
+
+impl RpcClientCommand for GetLiveBlocks {
+    type R = LiveBlocksResponse;
+
+    fn get_url_string(&self) -> String {
+        format!(
+            "chains/{}/blocks/{}/live_blocks",
+            self.chain_id.to_str(),
+            self.block_id.to_str()
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
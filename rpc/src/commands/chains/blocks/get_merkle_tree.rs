@@ -0,0 +1,153 @@
+use super::block_responses::{ContextProofResponse, MerkleTreeResponse};
+use crate::commands::RpcClientCommand;
+use crate::types::{Block, Chain};
+
+fn merkle_tree_url_string(
+    chain_id: &Chain,
+    block_id: &Block,
+    endpoint: &str,
+    path: &str,
+    holey: Option<bool>,
+) -> String {
+    let mut url_string = format!(
+        "chains/{}/blocks/{}/context/{}",
+        chain_id.to_str(),
+        block_id.to_str(),
+        endpoint
+    );
+    let path = path.trim_matches('/');
+    if !path.is_empty() {
+        url_string.push('/');
+        url_string.push_str(path);
+    }
+    if let Some(holey) = holey {
+        url_string.push_str(&format!("?holey={}", holey));
+    }
+    url_string
+}
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/context/merkle_tree/{path}`
+/// endpoint, returning a merkle proof of the block's context at `path`.
+///
+/// With `holey`, the data at `path` itself is replaced by its hash.
+#[derive(Debug)]
+pub struct GetMerkleTree {
+    pub chain_id: Chain,
+    pub block_id: Block,
+    pub path: String,
+    holey: Option<bool>,
+}
+
+impl GetMerkleTree {
+    pub fn with_default_params(chain_id: Chain, block_id: Block, path: &str) -> Self {
+        Self::with_explicit_params(chain_id, block_id, path, None)
+    }
+
+    pub fn with_explicit_params(
+        chain_id: Chain,
+        block_id: Block,
+        path: &str,
+        holey: Option<bool>,
+    ) -> Self {
+        Self {
+            chain_id,
+            block_id,
+            path: path.to_string(),
+            holey,
+        }
+    }
+}
+
+impl RpcClientCommand for GetMerkleTree {
+    type R = MerkleTreeResponse;
+
+    fn get_url_string(&self) -> String {
+        merkle_tree_url_string(
+            &self.chain_id,
+            &self.block_id,
+            "merkle_tree",
+            &self.path,
+            self.holey,
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/context/merkle_tree_v2/{path}`
+/// endpoint, returning an Irmin proof of the block's context at `path`.
+#[derive(Debug)]
+pub struct GetContextProof {
+    pub chain_id: Chain,
+    pub block_id: Block,
+    pub path: String,
+    holey: Option<bool>,
+}
+
+impl GetContextProof {
+    pub fn with_default_params(chain_id: Chain, block_id: Block, path: &str) -> Self {
+        Self::with_explicit_params(chain_id, block_id, path, None)
+    }
+
+    pub fn with_explicit_params(
+        chain_id: Chain,
+        block_id: Block,
+        path: &str,
+        holey: Option<bool>,
+    ) -> Self {
+        Self {
+            chain_id,
+            block_id,
+            path: path.to_string(),
+            holey,
+        }
+    }
+}
+
+impl RpcClientCommand for GetContextProof {
+    type R = ContextProofResponse;
+
+    fn get_url_string(&self) -> String {
+        merkle_tree_url_string(
+            &self.chain_id,
+            &self.block_id,
+            "merkle_tree_v2",
+            &self.path,
+            self.holey,
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn holey_merkle_tree_url_string_ok() {
+        let command = GetMerkleTree::with_explicit_params(
+            Chain::Main,
+            Block::Head,
+            "contracts/index/0000b1",
+            Some(true),
+        );
+        assert_eq!(
+            command.get_url_string(),
+            "chains/main/blocks/head/context/merkle_tree/contracts/index/0000b1?holey=true"
+        );
+    }
+
+    #[test]
+    fn context_proof_url_string_ok() {
+        let command = GetContextProof::with_default_params(Chain::Main, Block::Head, "/version");
+        assert_eq!(
+            command.get_url_string(),
+            "chains/main/blocks/head/context/merkle_tree_v2/version"
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use super::block_responses::{
+    OperationHashResponse, OperationHashesResponse, ValidationPassHashesResponse,
+};
+use crate::commands::RpcClientCommand;
+use crate::types::{Block, Chain};
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/operation_hashes`
+/// endpoint, returning the hashes of all the block's operations.
+pub struct GetOperationHashes {
+    pub chain_id: Chain,
+    pub block_id: Block,
+}
+
+impl RpcClientCommand for GetOperationHashes {
+    type R = OperationHashesResponse;
+
+    fn get_url_string(&self) -> String {
+        format!(
+            "chains/{}/blocks/{}/operation_hashes",
+            self.chain_id.to_str(),
+            self.block_id.to_str()
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// Command for the
+/// `/chains/{chain_id}/blocks/{block_id}/operation_hashes/{validation_pass}`
+/// endpoint, returning the hashes of the block's operations in one
+/// validation pass (`0` for consensus operations, `3` for manager operations).
+pub struct GetValidationPassHashes {
+    pub chain_id: Chain,
+    pub block_id: Block,
+    pub validation_pass: usize,
+}
+
+impl RpcClientCommand for GetValidationPassHashes {
+    type R = ValidationPassHashesResponse;
+
+    fn get_url_string(&self) -> String {
+        format!(
+            "chains/{}/blocks/{}/operation_hashes/{}",
+            self.chain_id.to_str(),
+            self.block_id.to_str(),
+            self.validation_pass
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// Command for the
+/// `/chains/{chain_id}/blocks/{block_id}/operation_hashes/{validation_pass}/{operation_index}`
+/// endpoint, returning the hash of a single operation of the block.
+pub struct GetOperationHash {
+    pub chain_id: Chain,
+    pub block_id: Block,
+    pub validation_pass: usize,
+    pub operation_index: usize,
+}
+
+impl RpcClientCommand for GetOperationHash {
+    type R = OperationHashResponse;
+
+    fn get_url_string(&self) -> String {
+        format!(
+            "chains/{}/blocks/{}/operation_hashes/{}/{}",
+            self.chain_id.to_str(),
+            self.block_id.to_str(),
+            self.validation_pass,
+            self.operation_index
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
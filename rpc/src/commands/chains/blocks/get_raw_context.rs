@@ -0,0 +1,156 @@
+use super::block_responses::{RawContextBytesResponse, RawContextResponse};
+use crate::commands::RpcClientCommand;
+use crate::types::{Block, Chain};
+
+fn raw_context_url_string(
+    chain_id: &Chain,
+    block_id: &Block,
+    format: &str,
+    path: &str,
+    depth: Option<u32>,
+) -> String {
+    let mut url_string = format!(
+        "chains/{}/blocks/{}/context/raw/{}",
+        chain_id.to_str(),
+        block_id.to_str(),
+        format
+    );
+    let path = path.trim_matches('/');
+    if !path.is_empty() {
+        url_string.push('/');
+        url_string.push_str(path);
+    }
+    if let Some(depth) = depth {
+        url_string.push_str(&format!("?depth={}", depth));
+    }
+    url_string
+}
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/context/raw/json/{path}`
+/// endpoint, returning the subtree of the block's context at `path`
+/// (e.g. `contracts/index/tz1...`) with its values decoded to JSON.
+///
+/// The whole subtree is returned with [the default constructor](Self::with_default_params);
+/// `depth` limits how many levels of directories are expanded.
+#[derive(Debug)]
+pub struct GetRawContext {
+    pub chain_id: Chain,
+    pub block_id: Block,
+    pub path: String,
+    depth: Option<u32>,
+}
+
+impl GetRawContext {
+    pub fn with_default_params(chain_id: Chain, block_id: Block, path: &str) -> Self {
+        Self::with_explicit_params(chain_id, block_id, path, None)
+    }
+
+    pub fn with_explicit_params(
+        chain_id: Chain,
+        block_id: Block,
+        path: &str,
+        depth: Option<u32>,
+    ) -> Self {
+        Self {
+            chain_id,
+            block_id,
+            path: path.to_string(),
+            depth,
+        }
+    }
+}
+
+impl RpcClientCommand for GetRawContext {
+    type R = RawContextResponse;
+
+    fn get_url_string(&self) -> String {
+        raw_context_url_string(
+            &self.chain_id,
+            &self.block_id,
+            "json",
+            &self.path,
+            self.depth,
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/context/raw/bytes/{path}`
+/// endpoint, returning the subtree of the block's context at `path` with its
+/// values in binary, as stored.
+#[derive(Debug)]
+pub struct GetRawContextBytes {
+    pub chain_id: Chain,
+    pub block_id: Block,
+    pub path: String,
+    depth: Option<u32>,
+}
+
+impl GetRawContextBytes {
+    pub fn with_default_params(chain_id: Chain, block_id: Block, path: &str) -> Self {
+        Self::with_explicit_params(chain_id, block_id, path, None)
+    }
+
+    pub fn with_explicit_params(
+        chain_id: Chain,
+        block_id: Block,
+        path: &str,
+        depth: Option<u32>,
+    ) -> Self {
+        Self {
+            chain_id,
+            block_id,
+            path: path.to_string(),
+            depth,
+        }
+    }
+}
+
+impl RpcClientCommand for GetRawContextBytes {
+    type R = RawContextBytesResponse;
+
+    fn get_url_string(&self) -> String {
+        raw_context_url_string(
+            &self.chain_id,
+            &self.block_id,
+            "bytes",
+            &self.path,
+            self.depth,
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn raw_context_url_string_ok() {
+        let command = GetRawContext::with_explicit_params(
+            Chain::Main,
+            Block::Head,
+            "/contracts/index/",
+            Some(2),
+        );
+        assert_eq!(
+            command.get_url_string(),
+            "chains/main/blocks/head/context/raw/json/contracts/index?depth=2"
+        );
+    }
+
+    #[test]
+    fn raw_context_bytes_root_url_string_ok() {
+        let command = GetRawContextBytes::with_default_params(Chain::Main, Block::Genesis, "");
+        assert_eq!(
+            command.get_url_string(),
+            "chains/main/blocks/genesis/context/raw/bytes"
+        );
+    }
+}
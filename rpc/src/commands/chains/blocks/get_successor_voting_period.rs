@@ -0,0 +1,27 @@
+use super::block_responses::VotingPeriodInfoResponse;
+use crate::commands::RpcClientCommand;
+use crate::types::{Block, Chain};
+
+/// Command for the `/chains/{chain_id}/blocks/{block_id}/votes/successor_period` endpoint,
+/// returning the voting period of the next block and that block's position in
+/// it.
+pub struct GetSuccessorVotingPeriod {
+    pub chain_id: Chain,
+    pub block_id: Block,
+}
+
+impl RpcClientCommand for GetSuccessorVotingPeriod {
+    type R = VotingPeriodInfoResponse;
+
+    fn get_url_string(&self) -> String {
+        format!(
+            "chains/{}/blocks/{}/votes/successor_period",
+            &self.chain_id.to_str(),
+            &self.block_id.to_str()
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -1,4 +1,45 @@
+pub mod delete_invalid_block;
+pub mod get_attestation_rights;
+pub mod get_baking_rights;
 pub mod get_balance;
+pub mod get_ballot_list;
+pub mod get_ballots;
+pub mod get_big_map_value;
+pub mod get_block_hash;
+pub mod get_block_header;
+pub mod get_block_metadata;
+pub mod get_block_protocols;
 pub mod get_blocks_in_chain;
+pub mod get_chain_id;
+pub mod get_chain_level;
+pub mod get_checkpoint;
+pub mod get_constants;
+pub mod get_contract_entrypoints;
+pub mod get_contract_script;
+pub mod get_current_level;
+pub mod get_current_proposal;
+pub mod get_current_quorum;
+pub mod get_current_voting_period;
+pub mod get_delegate;
+pub mod get_delegate_balance;
+pub mod get_delegate_deactivated;
+pub mod get_delegate_grace_period;
+pub mod get_delegate_participation;
+pub mod get_delegate_voting_power;
+pub mod get_delegated_contracts;
+pub mod get_delegates;
+pub mod get_endorsing_rights;
+pub mod get_invalid_block;
 pub mod get_invalid_blocks_in_chain;
+pub mod get_is_bootstrapped;
+pub mod get_levels_in_current_cycle;
+pub mod get_live_blocks;
+pub mod get_merkle_tree;
+pub mod get_operation_hashes;
+pub mod get_proposals;
+pub mod get_raw_context;
+pub mod get_successor_voting_period;
+pub mod get_voting_listings;
+pub mod run_script_view;
+pub mod run_view;
 use crate::responses::chains::blocks as block_responses;
@@ -0,0 +1,149 @@
+use super::block_responses::RunViewResponse;
+use crate::commands::RpcClientCommand;
+use crate::michelson::Micheline;
+use crate::types::{Block, Chain, UnparsingMode};
+use serde::Serialize;
+
+/// Command for the [`/chains/{chain_id}/blocks/{block_id}/helpers/scripts/run_script_view` endpoint](https://tezos.gitlab.io/active/rpc.html#post-block-id-helpers-scripts-run-script-view),
+/// executing an on-chain view of a contract at the given block.
+///
+/// `network_chain_id` is the base58 chain id (`Net...`) of the network, which
+/// the node requires in the request body.
+///
+/// Optional execution settings can be passed with
+/// [an explicit constructor](Self::with_explicit_params), or left to the
+/// node's defaults with [the default constructor](Self::with_default_params)
+pub struct RunScriptView {
+    pub chain_id: Chain,
+    pub block_id: Block,
+    params: RunScriptViewParameters,
+}
+
+impl RunScriptView {
+    pub fn with_default_params(
+        chain_id: Chain,
+        block_id: Block,
+        contract: String,
+        view: String,
+        input: Micheline,
+        network_chain_id: String,
+    ) -> Self {
+        Self::with_explicit_params(
+            chain_id,
+            block_id,
+            contract,
+            view,
+            input,
+            network_chain_id,
+            None,
+            None,
+            None,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_explicit_params(
+        chain_id: Chain,
+        block_id: Block,
+        contract: String,
+        view: String,
+        input: Micheline,
+        network_chain_id: String,
+        source: Option<String>,
+        gas: Option<u64>,
+        unparsing_mode: Option<UnparsingMode>,
+    ) -> Self {
+        let params = RunScriptViewParameters {
+            contract,
+            view,
+            input,
+            chain_id: network_chain_id,
+            source,
+            gas: gas.map(|gas| gas.to_string()),
+            unparsing_mode: unparsing_mode.unwrap_or_default(),
+        };
+
+        Self {
+            chain_id,
+            block_id,
+            params,
+        }
+    }
+}
+
+impl RpcClientCommand for RunScriptView {
+    type R = RunViewResponse;
+
+    fn get_url_string(&self) -> String {
+        format!(
+            "chains/{}/blocks/{}/helpers/scripts/run_script_view",
+            self.chain_id.to_str(),
+            self.block_id.to_str()
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn get_request_body(&self) -> Option<String> {
+        serde_json::to_string(&self.params).ok()
+    }
+}
+
+#[derive(Serialize, Debug)]
+struct RunScriptViewParameters {
+    contract: String,
+    view: String,
+    input: Micheline,
+    chain_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gas: Option<String>,
+    unparsing_mode: UnparsingMode,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn default_params_to_body_ok() {
+        let command = RunScriptView::with_default_params(
+            Chain::Main,
+            Block::Head,
+            "KT1Hkg5qeNhfwpKW4fXvq7HGZB9z2EnmCCA9".to_string(),
+            "get_price".to_string(),
+            Micheline::prim("Unit", vec![], vec![]),
+            "NetXdQprcVkpaWU".to_string(),
+        );
+
+        let correct_body = r#"{"contract":"KT1Hkg5qeNhfwpKW4fXvq7HGZB9z2EnmCCA9","view":"get_price","input":{"prim":"Unit"},"chain_id":"NetXdQprcVkpaWU","unparsing_mode":"Readable"}"#;
+
+        assert_eq!(command.get_request_body().unwrap(), correct_body);
+        assert_eq!(
+            command.get_url_string(),
+            "chains/main/blocks/head/helpers/scripts/run_script_view"
+        );
+    }
+
+    #[test]
+    fn explicit_params_to_body_ok() {
+        let command = RunScriptView::with_explicit_params(
+            Chain::Main,
+            Block::Head,
+            "KT1Hkg5qeNhfwpKW4fXvq7HGZB9z2EnmCCA9".to_string(),
+            "get_price".to_string(),
+            Micheline::int(1),
+            "NetXdQprcVkpaWU".to_string(),
+            Some("tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx".to_string()),
+            Some(10_000),
+            Some(UnparsingMode::Optimized),
+        );
+
+        let correct_body = r#"{"contract":"KT1Hkg5qeNhfwpKW4fXvq7HGZB9z2EnmCCA9","view":"get_price","input":{"int":"1"},"chain_id":"NetXdQprcVkpaWU","source":"tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx","gas":"10000","unparsing_mode":"Optimized"}"#;
+
+        assert_eq!(command.get_request_body().unwrap(), correct_body);
+    }
+}
@@ -0,0 +1,125 @@
+use super::block_responses::RunViewResponse;
+use crate::commands::RpcClientCommand;
+use crate::michelson::Micheline;
+use crate::types::{Block, Chain, UnparsingMode};
+use serde::Serialize;
+
+/// Command for the [`/chains/{chain_id}/blocks/{block_id}/helpers/scripts/run_view` endpoint](https://tezos.gitlab.io/active/rpc.html#post-block-id-helpers-scripts-run-view),
+/// simulating a call to a TZIP-4 view entrypoint (an entrypoint taking a
+/// `contract` callback as its last argument) and returning the value that
+/// would have been sent to the callback.
+///
+/// `network_chain_id` is the base58 chain id (`Net...`) of the network, which
+/// the node requires in the request body.
+pub struct RunView {
+    pub chain_id: Chain,
+    pub block_id: Block,
+    params: RunViewParameters,
+}
+
+impl RunView {
+    pub fn with_default_params(
+        chain_id: Chain,
+        block_id: Block,
+        contract: String,
+        entrypoint: String,
+        input: Micheline,
+        network_chain_id: String,
+    ) -> Self {
+        Self::with_explicit_params(
+            chain_id,
+            block_id,
+            contract,
+            entrypoint,
+            input,
+            network_chain_id,
+            None,
+            None,
+            None,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_explicit_params(
+        chain_id: Chain,
+        block_id: Block,
+        contract: String,
+        entrypoint: String,
+        input: Micheline,
+        network_chain_id: String,
+        source: Option<String>,
+        gas: Option<u64>,
+        unparsing_mode: Option<UnparsingMode>,
+    ) -> Self {
+        let params = RunViewParameters {
+            contract,
+            entrypoint,
+            input,
+            chain_id: network_chain_id,
+            source,
+            gas: gas.map(|gas| gas.to_string()),
+            unparsing_mode: unparsing_mode.unwrap_or_default(),
+        };
+
+        Self {
+            chain_id,
+            block_id,
+            params,
+        }
+    }
+}
+
+impl RpcClientCommand for RunView {
+    type R = RunViewResponse;
+
+    fn get_url_string(&self) -> String {
+        format!(
+            "chains/{}/blocks/{}/helpers/scripts/run_view",
+            self.chain_id.to_str(),
+            self.block_id.to_str()
+        )
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn get_request_body(&self) -> Option<String> {
+        serde_json::to_string(&self.params).ok()
+    }
+}
+
+#[derive(Serialize, Debug)]
+struct RunViewParameters {
+    contract: String,
+    entrypoint: String,
+    input: Micheline,
+    chain_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gas: Option<String>,
+    unparsing_mode: UnparsingMode,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn default_params_to_body_ok() {
+        let command = RunView::with_default_params(
+            Chain::Main,
+            Block::Head,
+            "KT1PWx2mnDueood7fEmfbBDKx1D9BAnnXitn".to_string(),
+            "getBalance".to_string(),
+            Micheline::string("tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx"),
+            "NetXdQprcVkpaWU".to_string(),
+        );
+
+        let correct_body = r#"{"contract":"KT1PWx2mnDueood7fEmfbBDKx1D9BAnnXitn","entrypoint":"getBalance","input":{"string":"tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx"},"chain_id":"NetXdQprcVkpaWU","unparsing_mode":"Readable"}"#;
+
+        assert_eq!(command.get_request_body().unwrap(), correct_body);
+        assert_eq!(command.get_http_method(), reqwest::Method::POST);
+    }
+}
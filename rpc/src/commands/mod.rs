@@ -2,12 +2,29 @@ use crate::errors::ParseError;
 use crate::responses::Response;
 
 pub mod chains;
+pub mod network;
+pub mod node;
+pub mod protocols;
+pub mod workers;
 
 pub trait RpcClientCommand {
     type R: Response;
 
     fn get_url_string(&self) -> String;
     fn get_http_method(&self) -> reqwest::Method;
+    /// JSON body sent along with the request, for `POST` commands.
+    fn get_request_body(&self) -> Option<String> {
+        None
+    }
+    /// Response standing for a `404 Not Found` answer with an empty body, which
+    /// is how the node reports that an optional resource is missing.
+    ///
+    /// `None`, the default, makes such answers errors like any other non-2xx
+    /// status.
+    fn not_found_response(&self) -> Option<Self::R> {
+        None
+    }
+    #[allow(clippy::wrong_self_convention)]
     fn from_response_str(&self, string: &str) -> Result<Self::R, ParseError> {
         Self::R::from_response_str(string)
     }
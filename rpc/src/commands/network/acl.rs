@@ -0,0 +1,20 @@
+use serde::Serialize;
+
+/// Access control applied by the node to a peer or point.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Acl {
+    /// Refuse connections and disconnect if connected.
+    Ban,
+    /// Always accept connections and never greylist.
+    Trust,
+    /// Default policy, removing any ban or trust.
+    Open,
+}
+
+#[derive(Serialize, Debug)]
+pub(super) struct AclPatch {
+    pub(super) acl: Acl,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) peer_id: Option<String>,
+}
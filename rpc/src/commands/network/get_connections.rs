@@ -0,0 +1,18 @@
+use super::network_responses::ConnectionsResponse;
+use crate::commands::RpcClientCommand;
+
+/// Command for the `/network/connections` endpoint,
+/// listing the node's open connections.
+pub struct GetConnections;
+
+impl RpcClientCommand for GetConnections {
+    type R = ConnectionsResponse;
+
+    fn get_url_string(&self) -> String {
+        "network/connections".to_string()
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
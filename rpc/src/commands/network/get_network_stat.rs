@@ -0,0 +1,18 @@
+use super::network_responses::NetworkStat;
+use crate::commands::RpcClientCommand;
+
+/// Command for the `/network/stat` endpoint,
+/// returning the node's global bandwidth statistics.
+pub struct GetNetworkStat;
+
+impl RpcClientCommand for GetNetworkStat {
+    type R = NetworkStat;
+
+    fn get_url_string(&self) -> String {
+        "network/stat".to_string()
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -0,0 +1,20 @@
+use super::network_responses::PeerInfo;
+use crate::commands::RpcClientCommand;
+
+/// Command for the `/network/peers/{peer_id}` endpoint,
+/// returning what the node knows about a peer.
+pub struct GetPeer {
+    pub peer_id: String,
+}
+
+impl RpcClientCommand for GetPeer {
+    type R = PeerInfo;
+
+    fn get_url_string(&self) -> String {
+        format!("network/peers/{}", &self.peer_id)
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -0,0 +1,59 @@
+use super::network_responses::{ConnectionState, PeersResponse};
+use crate::commands::RpcClientCommand;
+use querystring;
+
+/// Command for the `/network/peers` endpoint,
+/// listing the peers the node knows of, optionally only those in some connection states.
+#[derive(Debug)]
+pub struct GetPeers {
+    filters: Vec<ConnectionState>,
+}
+
+impl GetPeers {
+    pub fn with_default_params() -> Self {
+        Self { filters: vec![] }
+    }
+
+    pub fn with_explicit_params(filters: Vec<ConnectionState>) -> Self {
+        Self { filters }
+    }
+}
+
+impl RpcClientCommand for GetPeers {
+    type R = PeersResponse;
+
+    fn get_url_string(&self) -> String {
+        let mut url_string = "network/peers".to_string();
+        if !self.filters.is_empty() {
+            let query_params = self
+                .filters
+                .iter()
+                .map(|filter| ("filter", filter.to_str()))
+                .collect();
+            url_string.push('?');
+            url_string.push_str(&querystring::stringify(query_params));
+        }
+        url_string
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn filtered_peers_url_string_ok() {
+        let command = GetPeers::with_explicit_params(vec![
+            ConnectionState::Running,
+            ConnectionState::Accepted,
+        ]);
+        assert_eq!(
+            command.get_url_string(),
+            "network/peers?filter=running&filter=accepted&"
+        );
+    }
+}
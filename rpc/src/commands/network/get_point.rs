@@ -0,0 +1,20 @@
+use super::network_responses::PointInfo;
+use crate::commands::RpcClientCommand;
+
+/// Command for the `/network/points/{point}` endpoint,
+/// returning what the node knows about a point (`address:port`).
+pub struct GetPoint {
+    pub point: String,
+}
+
+impl RpcClientCommand for GetPoint {
+    type R = PointInfo;
+
+    fn get_url_string(&self) -> String {
+        format!("network/points/{}", &self.point)
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -0,0 +1,42 @@
+use super::network_responses::{ConnectionState, PointsResponse};
+use crate::commands::RpcClientCommand;
+use querystring;
+
+/// Command for the `/network/points` endpoint,
+/// listing the points the node knows of, optionally only those in some connection states.
+#[derive(Debug)]
+pub struct GetPoints {
+    filters: Vec<ConnectionState>,
+}
+
+impl GetPoints {
+    pub fn with_default_params() -> Self {
+        Self { filters: vec![] }
+    }
+
+    pub fn with_explicit_params(filters: Vec<ConnectionState>) -> Self {
+        Self { filters }
+    }
+}
+
+impl RpcClientCommand for GetPoints {
+    type R = PointsResponse;
+
+    fn get_url_string(&self) -> String {
+        let mut url_string = "network/points".to_string();
+        if !self.filters.is_empty() {
+            let query_params = self
+                .filters
+                .iter()
+                .map(|filter| ("filter", filter.to_str()))
+                .collect();
+            url_string.push('?');
+            url_string.push_str(&querystring::stringify(query_params));
+        }
+        url_string
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
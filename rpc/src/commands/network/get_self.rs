@@ -0,0 +1,18 @@
+use super::network_responses::PeerIdResponse;
+use crate::commands::RpcClientCommand;
+
+/// Command for the `/network/self` endpoint,
+/// returning the peer id the node identifies itself with.
+pub struct GetSelf;
+
+impl RpcClientCommand for GetSelf {
+    type R = PeerIdResponse;
+
+    fn get_url_string(&self) -> String {
+        "network/self".to_string()
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
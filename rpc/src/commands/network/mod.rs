@@ -0,0 +1,12 @@
+mod acl;
+pub mod get_connections;
+pub mod get_network_stat;
+pub mod get_peer;
+pub mod get_peers;
+pub mod get_point;
+pub mod get_points;
+pub mod get_self;
+pub mod set_peer_acl;
+pub mod set_point_acl;
+use crate::responses::network as network_responses;
+pub use acl::Acl;
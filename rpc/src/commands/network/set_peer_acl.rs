@@ -0,0 +1,30 @@
+use super::acl::{Acl, AclPatch};
+use super::network_responses::PeerInfo;
+use crate::commands::RpcClientCommand;
+
+/// Command for the `PATCH /network/peers/{peer_id}` endpoint, banning,
+/// trusting or untrusting (with [`Acl::Open`]) a peer.
+pub struct SetPeerAcl {
+    pub peer_id: String,
+    pub acl: Acl,
+}
+
+impl RpcClientCommand for SetPeerAcl {
+    type R = PeerInfo;
+
+    fn get_url_string(&self) -> String {
+        format!("network/peers/{}", &self.peer_id)
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::PATCH
+    }
+
+    fn get_request_body(&self) -> Option<String> {
+        let patch = AclPatch {
+            acl: self.acl,
+            peer_id: None,
+        };
+        serde_json::to_string(&patch).ok()
+    }
+}
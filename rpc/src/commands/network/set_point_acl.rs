@@ -0,0 +1,50 @@
+use super::acl::{Acl, AclPatch};
+use crate::commands::RpcClientCommand;
+use crate::responses::empty::EmptyResponse;
+
+/// Command for the `PATCH /network/points/{point}` endpoint, banning,
+/// trusting or untrusting (with [`Acl::Open`]) a point.
+///
+/// `peer_id`, when given, is the peer expected behind the point.
+pub struct SetPointAcl {
+    pub point: String,
+    pub acl: Acl,
+    pub peer_id: Option<String>,
+}
+
+impl RpcClientCommand for SetPointAcl {
+    type R = EmptyResponse;
+
+    fn get_url_string(&self) -> String {
+        format!("network/points/{}", &self.point)
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::PATCH
+    }
+
+    fn get_request_body(&self) -> Option<String> {
+        let patch = AclPatch {
+            acl: self.acl,
+            peer_id: self.peer_id.clone(),
+        };
+        serde_json::to_string(&patch).ok()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn ban_point_request_ok() {
+        let command = SetPointAcl {
+            point: "51.15.220.7:9732".to_string(),
+            acl: Acl::Ban,
+            peer_id: None,
+        };
+
+        assert_eq!(command.get_url_string(), "network/points/51.15.220.7:9732");
+        assert_eq!(command.get_request_body().unwrap(), r#"{"acl":"ban"}"#);
+    }
+}
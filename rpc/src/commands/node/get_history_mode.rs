@@ -0,0 +1,18 @@
+use super::node_responses::HistoryModeResponse;
+use crate::commands::RpcClientCommand;
+
+/// Command for the `/config/history_mode` endpoint,
+/// returning how much of the chain's history the node keeps.
+pub struct GetHistoryMode;
+
+impl RpcClientCommand for GetHistoryMode {
+    type R = HistoryModeResponse;
+
+    fn get_url_string(&self) -> String {
+        "config/history_mode".to_string()
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -0,0 +1,18 @@
+use super::node_responses::UserActivatedUpgradesResponse;
+use crate::commands::RpcClientCommand;
+
+/// Command for the `/config/network/user_activated_upgrades` endpoint,
+/// listing the protocol switches forced by the node's network configuration.
+pub struct GetUserActivatedUpgrades;
+
+impl RpcClientCommand for GetUserActivatedUpgrades {
+    type R = UserActivatedUpgradesResponse;
+
+    fn get_url_string(&self) -> String {
+        "config/network/user_activated_upgrades".to_string()
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -0,0 +1,18 @@
+use super::node_responses::VersionResponse;
+use crate::commands::RpcClientCommand;
+
+/// Command for the `/version` endpoint,
+/// returning the node's version and the network it runs on.
+pub struct GetVersion;
+
+impl RpcClientCommand for GetVersion {
+    type R = VersionResponse;
+
+    fn get_url_string(&self) -> String {
+        "version".to_string()
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
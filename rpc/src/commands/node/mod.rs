@@ -0,0 +1,6 @@
+//! Commands about the node itself: its version and configuration.
+
+pub mod get_history_mode;
+pub mod get_user_activated_upgrades;
+pub mod get_version;
+use crate::responses::node as node_responses;
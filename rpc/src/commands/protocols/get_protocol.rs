@@ -0,0 +1,21 @@
+use super::protocol_responses::ProtocolSourcesResponse;
+use crate::commands::RpcClientCommand;
+use crate::types::Protocol;
+
+/// Command for the `/protocols/{protocol_hash}` endpoint,
+/// returning the sources of a protocol known to the node.
+pub struct GetProtocol {
+    pub protocol: Protocol,
+}
+
+impl RpcClientCommand for GetProtocol {
+    type R = ProtocolSourcesResponse;
+
+    fn get_url_string(&self) -> String {
+        format!("protocols/{}", self.protocol.hash())
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
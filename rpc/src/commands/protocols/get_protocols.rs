@@ -0,0 +1,18 @@
+use super::protocol_responses::ProtocolsResponse;
+use crate::commands::RpcClientCommand;
+
+/// Command for the `/protocols` endpoint,
+/// returning the hashes of all protocols known to the node.
+pub struct GetProtocols;
+
+impl RpcClientCommand for GetProtocols {
+    type R = ProtocolsResponse;
+
+    fn get_url_string(&self) -> String {
+        "protocols".to_string()
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
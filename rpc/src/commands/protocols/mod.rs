@@ -0,0 +1,8 @@
+//! Commands about the protocols known to the node, and the
+//! [`WithProtocol`](with_protocol::WithProtocol) adapter decoding responses
+//! with the schema of a given protocol.
+
+pub mod get_protocol;
+pub mod get_protocols;
+pub mod with_protocol;
+use crate::responses::protocols as protocol_responses;
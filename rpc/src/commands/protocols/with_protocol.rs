@@ -0,0 +1,93 @@
+use crate::commands::RpcClientCommand;
+use crate::errors::ParseError;
+use crate::responses::ProtocolResponse;
+use crate::types::Protocol;
+
+/// Runs `command`, decoding its response with the schema of `protocol`
+/// rather than the protocol-agnostic one.
+///
+/// The protocol of a block can be found with
+/// [`RpcClient::detect_protocol()`](crate::RpcClient::detect_protocol()).
+pub struct WithProtocol<C> {
+    pub command: C,
+    pub protocol: Protocol,
+}
+
+impl<C> WithProtocol<C> {
+    pub fn new(command: C, protocol: Protocol) -> Self {
+        Self { command, protocol }
+    }
+}
+
+impl<C> RpcClientCommand for WithProtocol<C>
+where
+    C: RpcClientCommand,
+    C::R: ProtocolResponse,
+{
+    type R = C::R;
+
+    fn get_url_string(&self) -> String {
+        self.command.get_url_string()
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        self.command.get_http_method()
+    }
+
+    fn get_request_body(&self) -> Option<String> {
+        self.command.get_request_body()
+    }
+
+    fn from_response_str(&self, string: &str) -> Result<Self::R, ParseError> {
+        Self::R::from_protocol_response_str(&self.protocol, string)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::responses::Response;
+
+    #[derive(Debug, PartialEq)]
+    struct Tagged(String);
+
+    impl Response for Tagged {
+        fn from_response_str(response: &str) -> Result<Self, ParseError> {
+            Ok(Self(response.to_string()))
+        }
+    }
+
+    impl ProtocolResponse for Tagged {
+        fn from_protocol_response_str(
+            protocol: &Protocol,
+            response: &str,
+        ) -> Result<Self, ParseError> {
+            Ok(Self(format!("{}:{}", protocol.name(), response)))
+        }
+    }
+
+    struct GetTagged;
+
+    impl RpcClientCommand for GetTagged {
+        type R = Tagged;
+
+        fn get_url_string(&self) -> String {
+            "tagged".to_string()
+        }
+
+        fn get_http_method(&self) -> reqwest::Method {
+            reqwest::Method::GET
+        }
+    }
+
+    #[test]
+    fn decodes_with_protocol_schema() {
+        let command = WithProtocol::new(GetTagged, Protocol::Paris);
+
+        assert_eq!(command.get_url_string(), "tagged");
+        assert_eq!(
+            command.from_response_str("{}").unwrap(),
+            Tagged("paris:{}".to_string())
+        );
+    }
+}
@@ -0,0 +1,18 @@
+use super::worker_responses::WorkerStateResponse;
+use crate::commands::RpcClientCommand;
+
+/// Command for the `/workers/block_validator` endpoint,
+/// returning the state and request queue of the worker validating blocks.
+pub struct GetBlockValidator;
+
+impl RpcClientCommand for GetBlockValidator {
+    type R = WorkerStateResponse;
+
+    fn get_url_string(&self) -> String {
+        "workers/block_validator".to_string()
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -0,0 +1,21 @@
+use super::worker_responses::WorkerStateResponse;
+use crate::commands::RpcClientCommand;
+use crate::types::Chain;
+
+/// Command for the `/workers/chain_validators/{chain_id}` endpoint,
+/// returning the state and request queue of the worker following a chain.
+pub struct GetChainValidator {
+    pub chain_id: Chain,
+}
+
+impl RpcClientCommand for GetChainValidator {
+    type R = WorkerStateResponse;
+
+    fn get_url_string(&self) -> String {
+        format!("workers/chain_validators/{}", self.chain_id.to_str())
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -0,0 +1,18 @@
+use super::worker_responses::ChainWorkersResponse;
+use crate::commands::RpcClientCommand;
+
+/// Command for the `/workers/chain_validators` endpoint,
+/// listing the workers following each chain and their status.
+pub struct GetChainValidators;
+
+impl RpcClientCommand for GetChainValidators {
+    type R = ChainWorkersResponse;
+
+    fn get_url_string(&self) -> String {
+        "workers/chain_validators".to_string()
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
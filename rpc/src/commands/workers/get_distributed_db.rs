@@ -0,0 +1,38 @@
+use super::worker_responses::DistributedDbResponse;
+use crate::commands::RpcClientCommand;
+use crate::types::Chain;
+
+/// Command for the `/workers/chain_validators/{chain_id}/ddb` endpoint,
+/// returning the state of the distributed database a chain's validator
+/// fetches blocks and operations with.
+pub struct GetDistributedDb {
+    pub chain_id: Chain,
+}
+
+impl RpcClientCommand for GetDistributedDb {
+    type R = DistributedDbResponse;
+
+    fn get_url_string(&self) -> String {
+        format!("workers/chain_validators/{}/ddb", self.chain_id.to_str())
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn distributed_db_url_string_ok() {
+        let command = GetDistributedDb {
+            chain_id: Chain::Main,
+        };
+        assert_eq!(
+            command.get_url_string(),
+            "workers/chain_validators/main/ddb"
+        );
+    }
+}
@@ -0,0 +1,21 @@
+use super::worker_responses::WorkerStateResponse;
+use crate::commands::RpcClientCommand;
+use crate::types::Chain;
+
+/// Command for the `/workers/prevalidators/{chain_id}` endpoint,
+/// returning the state and request queue of a chain's mempool worker.
+pub struct GetPrevalidator {
+    pub chain_id: Chain,
+}
+
+impl RpcClientCommand for GetPrevalidator {
+    type R = WorkerStateResponse;
+
+    fn get_url_string(&self) -> String {
+        format!("workers/prevalidators/{}", self.chain_id.to_str())
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
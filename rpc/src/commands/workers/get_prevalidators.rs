@@ -0,0 +1,18 @@
+use super::worker_responses::ChainWorkersResponse;
+use crate::commands::RpcClientCommand;
+
+/// Command for the `/workers/prevalidators` endpoint,
+/// listing the mempool workers of each chain and their status.
+pub struct GetPrevalidators;
+
+impl RpcClientCommand for GetPrevalidators {
+    type R = ChainWorkersResponse;
+
+    fn get_url_string(&self) -> String {
+        "workers/prevalidators".to_string()
+    }
+
+    fn get_http_method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
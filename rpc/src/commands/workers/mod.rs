@@ -0,0 +1,7 @@
+pub mod get_block_validator;
+pub mod get_chain_validator;
+pub mod get_chain_validators;
+pub mod get_distributed_db;
+pub mod get_prevalidator;
+pub mod get_prevalidators;
+use crate::responses::workers as worker_responses;
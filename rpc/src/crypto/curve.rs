@@ -0,0 +1,67 @@
+use crate::encoding::base58::{self, Prefix};
+use std::fmt;
+
+/// Signature scheme of an implicit account, which its address prefix tells:
+/// `tz1` for Ed25519, `tz2` for secp256k1 and `tz3` for P-256.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Curve {
+    Ed25519,
+    Secp256k1,
+    P256,
+}
+
+impl Curve {
+    pub fn to_str(&self) -> &str {
+        match self {
+            Self::Ed25519 => "ed25519",
+            Self::Secp256k1 => "secp256k1",
+            Self::P256 => "p256",
+        }
+    }
+
+    pub(crate) fn public_key_prefix(&self) -> Prefix {
+        match self {
+            Self::Ed25519 => base58::EDPK,
+            Self::Secp256k1 => base58::SPPK,
+            Self::P256 => base58::P2PK,
+        }
+    }
+
+    pub(crate) fn public_key_hash_prefix(&self) -> Prefix {
+        match self {
+            Self::Ed25519 => base58::TZ1,
+            Self::Secp256k1 => base58::TZ2,
+            Self::P256 => base58::TZ3,
+        }
+    }
+
+    pub(crate) fn secret_key_prefix(&self) -> Prefix {
+        match self {
+            Self::Ed25519 => base58::EDSK,
+            Self::Secp256k1 => base58::SPSK,
+            Self::P256 => base58::P2SK,
+        }
+    }
+
+    pub(crate) fn encrypted_secret_key_prefix(&self) -> Prefix {
+        match self {
+            Self::Ed25519 => base58::EDESK,
+            Self::Secp256k1 => base58::SPESK,
+            Self::P256 => base58::P2ESK,
+        }
+    }
+
+    pub(crate) fn signature_prefix(&self) -> Prefix {
+        match self {
+            Self::Ed25519 => base58::EDSIG,
+            Self::Secp256k1 => base58::SPSIG,
+            Self::P256 => base58::P2SIG,
+        }
+    }
+}
+
+impl fmt::Display for Curve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_str())
+    }
+}
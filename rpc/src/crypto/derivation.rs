@@ -0,0 +1,360 @@
+//! Keys derived from BIP39 mnemonics.
+//!
+//! Keys are derived along hierarchical paths following SLIP-10, which for
+//! secp256k1 is the same as BIP32. Ed25519 only supports hardened indexes.
+//! Wallets use paths under `m/44'/1729'`, 1729 being the coin type of Tezos.
+
+use super::{Curve, SecretKey};
+use crate::errors::ParseError;
+use hmac::{Hmac, Mac};
+use k256::elliptic_curve::ff::{Field, PrimeField};
+use k256::elliptic_curve::sec1::{FromEncodedPoint, ModulusSize, ToEncodedPoint};
+use k256::elliptic_curve::{AffinePoint, CurveArithmetic, FieldBytes, FieldBytesSize};
+use rand::rngs::OsRng;
+use rand::RngCore;
+use sha2::Sha512;
+use std::convert::TryInto;
+use std::fmt;
+use std::str::FromStr;
+
+/// Path of a key in a derivation tree, such as `m/44'/1729'/0'/0'`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DerivationPath(Vec<u32>);
+
+impl DerivationPath {
+    /// Bit set on hardened indexes.
+    pub const HARDENED: u32 = 0x8000_0000;
+
+    pub fn new(indexes: Vec<u32>) -> Self {
+        Self(indexes)
+    }
+
+    /// The `m/44'/1729'/{account}'/0'` path used by Tezos wallets.
+    pub fn tezos(account: u32) -> Self {
+        Self(vec![
+            44 | Self::HARDENED,
+            1729 | Self::HARDENED,
+            account | Self::HARDENED,
+            Self::HARDENED,
+        ])
+    }
+
+    pub fn indexes(&self) -> &[u32] {
+        &self.0
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = ParseError;
+
+    /// Parses a path in the form `m/44'/1729'/0'/0'`, where hardened indexes
+    /// are marked with `'` or `h`.
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        let invalid_path = || ParseError::RequestParsingError(format!("invalid path {}", path));
+        let mut segments = path.trim().split('/');
+        if segments.next() != Some("m") {
+            return Err(invalid_path());
+        }
+
+        let indexes = segments
+            .map(|segment| {
+                let (index, hardened) = match segment.strip_suffix(|c| c == '\'' || c == 'h') {
+                    Some(index) => (index, Self::HARDENED),
+                    None => (segment, 0),
+                };
+                match index.parse::<u32>() {
+                    Ok(index) if index < Self::HARDENED => Ok(index | hardened),
+                    _ => Err(invalid_path()),
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self(indexes))
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "m")?;
+        for index in &self.0 {
+            if index & Self::HARDENED != 0 {
+                write!(f, "/{}'", index & !Self::HARDENED)?;
+            } else {
+                write!(f, "/{}", index)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Generates an English BIP39 mnemonic of 12, 15, 18, 21 or 24 words.
+pub fn generate_mnemonic(word_count: usize) -> Result<String, ParseError> {
+    if ![12, 15, 18, 21, 24].contains(&word_count) {
+        let detail = format!("mnemonics have 12 to 24 words, not {}", word_count);
+        return Err(ParseError::RequestParsingError(detail));
+    }
+    let mut entropy = vec![0; word_count * 4 / 3];
+    OsRng.fill_bytes(&mut entropy);
+    let mnemonic = bip39::Mnemonic::from_entropy(&entropy).map_err(mnemonic_error)?;
+    Ok(mnemonic.to_string())
+}
+
+impl SecretKey {
+    /// Derives the key at `path` from a BIP39 mnemonic and its optional
+    /// passphrase.
+    pub fn from_mnemonic(
+        mnemonic: &str,
+        passphrase: &str,
+        curve: Curve,
+        path: &DerivationPath,
+    ) -> Result<Self, ParseError> {
+        let seed = parse_mnemonic(mnemonic)?.to_seed(passphrase);
+        Self::from_seed(&seed, curve, path)
+    }
+
+    /// Derives the key at `path` from a BIP39 seed.
+    pub fn from_seed(seed: &[u8], curve: Curve, path: &DerivationPath) -> Result<Self, ParseError> {
+        let mut key = ExtendedKey::master(curve, seed)?;
+        for index in path.indexes() {
+            key = key.child(curve, *index)?;
+        }
+        Self::from_bytes(curve, &key.key)
+    }
+
+    /// Restores the Ed25519 key of a fundraiser account from the mnemonic,
+    /// email and password the fundraiser handed out.
+    ///
+    /// These keys are not derived along a path: the key is the first half of
+    /// the mnemonic's seed, with the email and password as passphrase.
+    pub fn from_fundraiser(
+        mnemonic: &str,
+        email: &str,
+        password: &str,
+    ) -> Result<Self, ParseError> {
+        let seed = parse_mnemonic(mnemonic)?.to_seed(format!("{}{}", email, password));
+        Self::from_bytes(Curve::Ed25519, &seed[..32])
+    }
+}
+
+fn parse_mnemonic(mnemonic: &str) -> Result<bip39::Mnemonic, ParseError> {
+    bip39::Mnemonic::parse(mnemonic).map_err(mnemonic_error)
+}
+
+fn mnemonic_error(error: bip39::Error) -> ParseError {
+    ParseError::RequestParsingError(format!("invalid mnemonic: {}", error))
+}
+
+/// A key and its chain code, from which child keys are derived.
+struct ExtendedKey {
+    key: [u8; 32],
+    chain_code: [u8; 32],
+}
+
+impl ExtendedKey {
+    fn master(curve: Curve, seed: &[u8]) -> Result<Self, ParseError> {
+        let hmac_key: &[u8] = match curve {
+            Curve::Ed25519 => b"ed25519 seed",
+            Curve::Secp256k1 => b"Bitcoin seed",
+            Curve::P256 => b"Nist256p1 seed",
+        };
+        let mut data = seed.to_vec();
+        loop {
+            let (key, chain_code) = hmac_sha512(hmac_key, &data);
+            // Out of range ECDSA keys are hashed again, as SLIP-10 specifies.
+            if SecretKey::from_bytes(curve, &key).is_ok() {
+                return Ok(Self { key, chain_code });
+            }
+            data = [key, chain_code].concat();
+        }
+    }
+
+    fn child(&self, curve: Curve, index: u32) -> Result<Self, ParseError> {
+        let hardened = index & DerivationPath::HARDENED != 0;
+        let mut data = Vec::with_capacity(37);
+        match curve {
+            _ if hardened => {
+                data.push(0);
+                data.extend(&self.key);
+            }
+            Curve::Ed25519 => {
+                let detail = format!(
+                    "ed25519 keys cannot be derived at unhardened index {}",
+                    index
+                );
+                return Err(ParseError::RequestParsingError(detail));
+            }
+            Curve::Secp256k1 | Curve::P256 => {
+                data.extend(
+                    SecretKey::from_bytes(curve, &self.key)?
+                        .public_key()
+                        .to_bytes(),
+                );
+            }
+        }
+        data.extend(&index.to_be_bytes());
+
+        let (tweak, chain_code) = hmac_sha512(&self.chain_code, &data);
+        let key = match curve {
+            Curve::Ed25519 => Some(tweak),
+            Curve::Secp256k1 => add_scalars::<k256::Secp256k1>(&tweak, &self.key),
+            Curve::P256 => add_scalars::<p256::NistP256>(&tweak, &self.key),
+        };
+
+        let key = key.ok_or_else(|| {
+            let detail = format!("no valid key at index {}, use the next one", index);
+            ParseError::RequestParsingError(detail)
+        })?;
+        Ok(Self { key, chain_code })
+    }
+}
+
+/// Adds two scalars of the curve `C`, or returns `None` if `tweak` is out of
+/// range or the sum is zero.
+fn add_scalars<C>(tweak: &[u8; 32], parent: &[u8; 32]) -> Option<[u8; 32]>
+where
+    C: CurveArithmetic,
+    FieldBytesSize<C>: ModulusSize,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+{
+    let tweak: C::Scalar = Option::from(C::Scalar::from_repr(FieldBytes::<C>::clone_from_slice(
+        tweak,
+    )))?;
+    let parent: C::Scalar = Option::from(C::Scalar::from_repr(FieldBytes::<C>::clone_from_slice(
+        parent,
+    )))?;
+    let child = tweak + parent;
+    if bool::from(child.is_zero()) {
+        return None;
+    }
+    child.to_repr().as_slice().try_into().ok()
+}
+
+fn hmac_sha512(key: &[u8], data: &[u8]) -> ([u8; 32], [u8; 32]) {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data);
+    let output = mac.finalize().into_bytes();
+    let (left, right) = output.split_at(32);
+    (
+        left.try_into().expect("SHA-512 outputs 64 bytes"),
+        right.try_into().expect("SHA-512 outputs 64 bytes"),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SEED: &str = "000102030405060708090a0b0c0d0e0f";
+    const ABANDON_MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon \
+                                    abandon abandon abandon abandon abandon about";
+
+    fn derive(curve: Curve, path: &str) -> ExtendedKey {
+        let path: DerivationPath = path.parse().unwrap();
+        let mut key = ExtendedKey::master(curve, &hex::decode(SEED).unwrap()).unwrap();
+        for index in path.indexes() {
+            key = key.child(curve, *index).unwrap();
+        }
+        key
+    }
+
+    #[test]
+    fn parse_path_ok() {
+        let path: DerivationPath = "m/44'/1729h/0'/0'".parse().unwrap();
+
+        assert_eq!(path, DerivationPath::tezos(0));
+        assert_eq!(path.to_string(), "m/44'/1729'/0'/0'");
+        assert!("44'/1729'".parse::<DerivationPath>().is_err());
+        assert!("m/2147483648".parse::<DerivationPath>().is_err());
+    }
+
+    #[test]
+    fn slip10_ed25519_vectors_ok() {
+        let master = derive(Curve::Ed25519, "m");
+        assert_eq!(
+            hex::encode(master.key),
+            "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7"
+        );
+        assert_eq!(
+            hex::encode(master.chain_code),
+            "90046a93de5380a72b5e45010748567d5ea02bbf6522f979e05c0d8d8ca9fffb"
+        );
+
+        let child = derive(Curve::Ed25519, "m/0'");
+        assert_eq!(
+            hex::encode(child.key),
+            "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3"
+        );
+        assert_eq!(
+            hex::encode(child.chain_code),
+            "8b59aa11380b624e81507a27fedda59fea6d0b779a778918a2fd3590e16e9c69"
+        );
+    }
+
+    #[test]
+    fn bip32_secp256k1_vectors_ok() {
+        assert_eq!(
+            hex::encode(derive(Curve::Secp256k1, "m").key),
+            "e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35"
+        );
+        assert_eq!(
+            hex::encode(derive(Curve::Secp256k1, "m/0'").key),
+            "edb2e14f9ee77d26dd93b4ecede8d16ed408ce149b6cd80b0715a2d911a0afea"
+        );
+        assert_eq!(
+            hex::encode(derive(Curve::Secp256k1, "m/0'/1").key),
+            "3c6cb8d0f6a264c91ea8b5030fadaa8e538b020f0a387421a12de9319dc93368"
+        );
+    }
+
+    #[test]
+    fn slip10_p256_vectors_ok() {
+        assert_eq!(
+            hex::encode(derive(Curve::P256, "m").key),
+            "612091aaa12e22dd2abef664f8a01a82cae99ad7441b7ef8110424915c268bc2"
+        );
+        assert_eq!(
+            hex::encode(derive(Curve::P256, "m/0'").key),
+            "6939694369114c67917a182c59ddb8cafc3004e63ca5d3b84403ba8613debc0c"
+        );
+    }
+
+    #[test]
+    fn unhardened_ed25519_derivation_fails() {
+        let seed = hex::decode(SEED).unwrap();
+        let path = "m/44'/1729'/0/0".parse().unwrap();
+
+        assert!(SecretKey::from_seed(&seed, Curve::Ed25519, &path).is_err());
+    }
+
+    #[test]
+    fn fundraiser_key_ok() {
+        // BIP39 test vector, whose seed with the passphrase "TREZOR" is known.
+        let secret_key = SecretKey::from_fundraiser(ABANDON_MNEMONIC, "TRE", "ZOR").unwrap();
+
+        assert_eq!(
+            hex::encode(secret_key.to_bytes()),
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e5349553"
+        );
+        assert!(secret_key.public_key_hash().starts_with("tz1"));
+    }
+
+    #[test]
+    fn mnemonic_keys_ok() {
+        let mnemonic = generate_mnemonic(24).unwrap();
+        assert_eq!(mnemonic.split_whitespace().count(), 24);
+        assert!(generate_mnemonic(13).is_err());
+
+        let path = DerivationPath::tezos(0);
+        let tz1 = SecretKey::from_mnemonic(&mnemonic, "", Curve::Ed25519, &path).unwrap();
+        let tz2 = SecretKey::from_mnemonic(&mnemonic, "", Curve::Secp256k1, &path).unwrap();
+        let tz3 = SecretKey::from_mnemonic(&mnemonic, "", Curve::P256, &path).unwrap();
+        assert!(tz1.public_key_hash().starts_with("tz1"));
+        assert!(tz2.public_key_hash().starts_with("tz2"));
+        assert!(tz3.public_key_hash().starts_with("tz3"));
+        assert_ne!(
+            tz1,
+            SecretKey::from_mnemonic(&mnemonic, "passphrase", Curve::Ed25519, &path).unwrap()
+        );
+        assert!(SecretKey::from_mnemonic("abandon about", "", Curve::Ed25519, &path).is_err());
+    }
+}
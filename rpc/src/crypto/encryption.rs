@@ -0,0 +1,94 @@
+//! Password encrypted secret keys, in the `edesk`/`spesk`/`p2esk` form
+//! octez-client stores them.
+//!
+//! The key is sealed in a NaCl secretbox with a zero nonce, under a key
+//! derived from the password with PBKDF2-HMAC-SHA512 and a random salt.
+
+use super::{Curve, SecretKey};
+use crate::encoding::base58;
+use crate::errors::ParseError;
+use crypto_secretbox::aead::{Aead, KeyInit};
+use crypto_secretbox::XSalsa20Poly1305;
+use rand::rngs::OsRng;
+use rand::RngCore;
+use sha2::Sha512;
+
+const SALT_LENGTH: usize = 8;
+const PBKDF2_ROUNDS: u32 = 32768;
+
+impl SecretKey {
+    /// Encrypts the key with `password`, with a fresh random salt.
+    pub fn encrypt(&self, password: &str) -> String {
+        let mut salt = [0; SALT_LENGTH];
+        OsRng.fill_bytes(&mut salt);
+        let ciphertext = secretbox(password, &salt)
+            .encrypt(&Default::default(), self.to_bytes().as_ref())
+            .expect("secretbox encryption of a key cannot fail");
+
+        let payload = [salt.as_ref(), &ciphertext].concat();
+        base58::encode(self.curve().encrypted_secret_key_prefix(), &payload)
+            .expect("encrypted secret keys are 56 bytes long")
+    }
+
+    /// Decrypts an `edesk`, `spesk` or `p2esk` key.
+    pub fn from_encrypted(encrypted_key: &str, password: &str) -> Result<Self, ParseError> {
+        for curve in [Curve::Ed25519, Curve::Secp256k1, Curve::P256] {
+            let payload = match base58::decode(curve.encrypted_secret_key_prefix(), encrypted_key) {
+                Ok(payload) => payload,
+                Err(_) => continue,
+            };
+            let (salt, ciphertext) = payload.split_at(SALT_LENGTH);
+            let bytes = secretbox(password, salt)
+                .decrypt(&Default::default(), ciphertext)
+                .map_err(|_| {
+                    ParseError::RequestParsingError(
+                        "wrong password or corrupted encrypted key".to_string(),
+                    )
+                })?;
+            return Self::from_bytes(curve, &bytes);
+        }
+
+        Err(ParseError::RequestParsingError(
+            "not a supported encrypted secret key".to_string(),
+        ))
+    }
+}
+
+fn secretbox(password: &str, salt: &[u8]) -> XSalsa20Poly1305 {
+    let mut key = [0; 32];
+    pbkdf2::pbkdf2_hmac::<Sha512>(password.as_bytes(), salt, PBKDF2_ROUNDS, &mut key);
+    XSalsa20Poly1305::new(&key.into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn encryption_round_trip_ok() {
+        for (curve, prefix) in [
+            (Curve::Ed25519, "edesk"),
+            (Curve::Secp256k1, "spesk"),
+            (Curve::P256, "p2esk"),
+        ] {
+            let secret_key = SecretKey::from_bytes(curve, &[4; 32]).unwrap();
+            let encrypted = secret_key.encrypt("hunter2");
+
+            assert!(encrypted.starts_with(prefix));
+            assert_eq!(
+                SecretKey::from_encrypted(&encrypted, "hunter2").unwrap(),
+                secret_key
+            );
+        }
+    }
+
+    #[test]
+    fn wrong_password_fails() {
+        let encrypted = SecretKey::from_bytes(Curve::Ed25519, &[4; 32])
+            .unwrap()
+            .encrypt("hunter2");
+
+        assert!(SecretKey::from_encrypted(&encrypted, "hunter3").is_err());
+        assert!(SecretKey::from_encrypted("edsk_not_encrypted", "hunter2").is_err());
+    }
+}
@@ -0,0 +1,32 @@
+//! Keys, signatures and watermarks of the curves supported by Tezos, and
+//! generation of keys from randomness or BIP39 mnemonics.
+//!
+//! Tezos signs the blake2b digest of the watermarked bytes rather than the
+//! bytes themselves; see [`signing_digest()`].
+
+mod curve;
+mod derivation;
+mod encryption;
+mod public_key;
+mod secret_key;
+mod signature;
+mod watermark;
+pub use curve::Curve;
+pub use derivation::{generate_mnemonic, DerivationPath};
+pub use public_key::PublicKey;
+pub use secret_key::SecretKey;
+pub use signature::Signature;
+pub use watermark::Watermark;
+
+use crate::encoding::hash;
+use crate::errors::ParseError;
+
+/// Digest actually signed for `bytes` under `watermark`.
+pub fn signing_digest(watermark: Option<&Watermark>, bytes: &[u8]) -> Result<[u8; 32], ParseError> {
+    let mut watermarked = match watermark {
+        Some(watermark) => watermark.to_bytes()?,
+        None => Vec::new(),
+    };
+    watermarked.extend_from_slice(bytes);
+    Ok(hash::blake2b_256(&watermarked))
+}
@@ -0,0 +1,233 @@
+use super::{signing_digest, Curve, Signature, Watermark};
+use crate::encoding::{base58, hash};
+use crate::errors::ParseError;
+use ed25519_dalek::Verifier;
+use k256::ecdsa::signature::hazmat::PrehashVerifier;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::TryInto;
+use std::fmt;
+use std::str::FromStr;
+
+/// A public key of one of the curves Tezos supports, validated on creation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PublicKey {
+    Ed25519(ed25519_dalek::VerifyingKey),
+    Secp256k1(k256::ecdsa::VerifyingKey),
+    P256(p256::ecdsa::VerifyingKey),
+}
+
+impl PublicKey {
+    /// Parses the raw bytes of a key: 32 bytes for Ed25519, 33 bytes (in
+    /// compressed SEC1 form) for secp256k1 and P-256.
+    pub fn from_bytes(curve: Curve, bytes: &[u8]) -> Result<Self, ParseError> {
+        let public_key = match curve {
+            Curve::Ed25519 => bytes
+                .try_into()
+                .ok()
+                .and_then(|bytes| ed25519_dalek::VerifyingKey::from_bytes(bytes).ok())
+                .map(Self::Ed25519),
+            Curve::Secp256k1 => k256::ecdsa::VerifyingKey::from_sec1_bytes(bytes)
+                .ok()
+                .map(Self::Secp256k1),
+            Curve::P256 => p256::ecdsa::VerifyingKey::from_sec1_bytes(bytes)
+                .ok()
+                .map(Self::P256),
+        };
+
+        public_key.ok_or_else(|| {
+            let detail = format!("invalid {} public key", curve);
+            ParseError::ResponseParsingError(detail)
+        })
+    }
+
+    /// Parses an `edpk`, `sppk` or `p2pk` key.
+    pub fn from_base58(public_key: &str) -> Result<Self, ParseError> {
+        for curve in [Curve::Ed25519, Curve::Secp256k1, Curve::P256] {
+            if let Ok(bytes) = base58::decode(curve.public_key_prefix(), public_key) {
+                return Self::from_bytes(curve, &bytes);
+            }
+        }
+
+        let detail = format!("{} is not a supported public key", public_key);
+        Err(ParseError::ResponseParsingError(detail))
+    }
+
+    pub fn curve(&self) -> Curve {
+        match self {
+            Self::Ed25519(_) => Curve::Ed25519,
+            Self::Secp256k1(_) => Curve::Secp256k1,
+            Self::P256(_) => Curve::P256,
+        }
+    }
+
+    /// Raw bytes of the key, compressed for secp256k1 and P-256.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            Self::Ed25519(key) => key.to_bytes().to_vec(),
+            Self::Secp256k1(key) => key.to_encoded_point(true).as_bytes().to_vec(),
+            Self::P256(key) => key.to_encoded_point(true).as_bytes().to_vec(),
+        }
+    }
+
+    pub fn to_base58(&self) -> String {
+        base58::encode(self.curve().public_key_prefix(), &self.to_bytes())
+            .expect("public keys have the length of their prefix")
+    }
+
+    /// `tz1`, `tz2` or `tz3` address of the key.
+    pub fn public_key_hash(&self) -> String {
+        let key_hash = hash::blake2b_160(&self.to_bytes());
+        base58::encode(self.curve().public_key_hash_prefix(), &key_hash)
+            .expect("public key hashes are 20 bytes long")
+    }
+
+    /// Checks `signature` against the digest of the watermarked `bytes`.
+    pub fn verify(
+        &self,
+        watermark: Option<&Watermark>,
+        bytes: &[u8],
+        signature: &Signature,
+    ) -> Result<bool, ParseError> {
+        let digest = signing_digest(watermark, bytes)?;
+        Ok(self.verify_digest(&digest, signature))
+    }
+
+    /// Checks `signature` against an already computed signing digest.
+    ///
+    /// Like Octez, rejects secp256k1 signatures whose `s` is not in the lower
+    /// half of the curve order, the malleated form of a valid signature.
+    pub fn verify_digest(&self, digest: &[u8; 32], signature: &Signature) -> bool {
+        match self {
+            Self::Ed25519(key) => {
+                let signature = ed25519_dalek::Signature::from_bytes(signature.as_bytes());
+                key.verify(digest, &signature).is_ok()
+            }
+            Self::Secp256k1(key) => k256::ecdsa::Signature::from_slice(signature.as_bytes())
+                .map(|signature| key.verify_prehash(digest, &signature).is_ok())
+                .unwrap_or(false),
+            Self::P256(key) => p256::ecdsa::Signature::from_slice(signature.as_bytes())
+                .map(|signature| key.verify_prehash(digest, &signature).is_ok())
+                .unwrap_or(false),
+        }
+    }
+}
+
+impl FromStr for PublicKey {
+    type Err = ParseError;
+
+    fn from_str(public_key: &str) -> Result<Self, Self::Err> {
+        Self::from_base58(public_key)
+    }
+}
+
+impl fmt::Display for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_base58())
+    }
+}
+
+impl Serialize for PublicKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_base58())
+    }
+}
+
+impl<'de> Deserialize<'de> for PublicKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let public_key = String::deserialize(deserializer)?;
+        Self::from_base58(&public_key).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use k256::ecdsa::signature::hazmat::PrehashSigner;
+
+    const MESSAGE: &[u8] = b"block header";
+
+    fn watermark() -> Watermark {
+        Watermark::Block {
+            chain_id: "NetXdQprcVkpaWU".to_string(),
+        }
+    }
+
+    #[test]
+    fn public_key_hash_ok() {
+        let public_key =
+            PublicKey::from_base58("edpkuBknW28nW72KG6RoHtYW7p12T6GKc7nAbwYX5m8Wd9sDVC9yav")
+                .unwrap();
+
+        assert_eq!(public_key.curve(), Curve::Ed25519);
+        assert_eq!(
+            public_key.public_key_hash(),
+            "tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx"
+        );
+        assert_eq!(
+            public_key.to_string(),
+            "edpkuBknW28nW72KG6RoHtYW7p12T6GKc7nAbwYX5m8Wd9sDVC9yav"
+        );
+    }
+
+    #[test]
+    fn verify_ed25519_ok() {
+        let signing_key = ed25519_dalek::SigningKey::from_bytes(&[1; 32]);
+        let public_key = PublicKey::Ed25519(signing_key.verifying_key());
+        let digest = signing_digest(Some(&watermark()), MESSAGE).unwrap();
+        let signature =
+            Signature::from_bytes(&ed25519_dalek::Signer::sign(&signing_key, &digest).to_bytes())
+                .unwrap();
+
+        assert!(public_key
+            .verify(Some(&watermark()), MESSAGE, &signature)
+            .unwrap());
+        assert!(!public_key.verify(None, MESSAGE, &signature).unwrap());
+    }
+
+    #[test]
+    fn verify_secp256k1_and_p256_ok() {
+        let digest = signing_digest(Some(&watermark()), MESSAGE).unwrap();
+
+        let secp256k1_key = k256::ecdsa::SigningKey::from_slice(&[1; 32]).unwrap();
+        let secp256k1_signature: k256::ecdsa::Signature =
+            secp256k1_key.sign_prehash(&digest).unwrap();
+        let secp256k1_public_key = PublicKey::Secp256k1(*secp256k1_key.verifying_key());
+
+        let p256_key = p256::ecdsa::SigningKey::from_slice(&[1; 32]).unwrap();
+        let p256_signature: p256::ecdsa::Signature = p256_key.sign_prehash(&digest).unwrap();
+        let p256_public_key = PublicKey::P256(*p256_key.verifying_key());
+
+        let secp256k1_signature = Signature::from_bytes(&secp256k1_signature.to_bytes()).unwrap();
+        let p256_signature = Signature::from_bytes(&p256_signature.to_bytes()).unwrap();
+        assert!(secp256k1_public_key.verify_digest(&digest, &secp256k1_signature));
+        assert!(p256_public_key.verify_digest(&digest, &p256_signature));
+        assert!(!p256_public_key.verify_digest(&digest, &secp256k1_signature));
+        assert!(secp256k1_public_key.public_key_hash().starts_with("tz2"));
+        assert_eq!(
+            PublicKey::from_base58(&p256_public_key.to_base58()).unwrap(),
+            p256_public_key
+        );
+    }
+
+    #[test]
+    fn high_s_secp256k1_signature_fails() {
+        let digest = signing_digest(Some(&watermark()), MESSAGE).unwrap();
+        let signing_key = k256::ecdsa::SigningKey::from_slice(&[1; 32]).unwrap();
+        let public_key = PublicKey::Secp256k1(*signing_key.verifying_key());
+        let low_s: k256::ecdsa::Signature = signing_key.sign_prehash(&digest).unwrap();
+        let (r, s) = low_s.split_scalars();
+        let high_s = k256::ecdsa::Signature::from_scalars(r, -*s).unwrap();
+
+        let low_s = Signature::from_bytes(&low_s.to_bytes()).unwrap();
+        let high_s = Signature::from_bytes(&high_s.to_bytes()).unwrap();
+        assert!(public_key.verify_digest(&digest, &low_s));
+        assert!(!public_key.verify_digest(&digest, &high_s));
+    }
+
+    #[test]
+    fn invalid_public_key_fails() {
+        let not_on_curve = base58::encode(base58::SPPK, &[9; 33]).unwrap();
+        assert!(PublicKey::from_base58(&not_on_curve).is_err());
+        assert!(PublicKey::from_base58("tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx").is_err());
+    }
+}
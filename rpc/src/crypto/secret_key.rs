@@ -0,0 +1,226 @@
+use super::{signing_digest, Curve, PublicKey, Signature, Watermark};
+use crate::encoding::base58;
+use crate::errors::ParseError;
+use ed25519_dalek::Signer;
+use k256::ecdsa::signature::hazmat::PrehashSigner;
+use rand::rngs::OsRng;
+use rand::RngCore;
+use std::convert::TryInto;
+use std::fmt;
+use std::str::FromStr;
+
+/// A secret key of one of the curves Tezos supports.
+///
+/// The key is not printed by its `Debug` implementation, which only shows
+/// its address.
+#[derive(Clone)]
+pub enum SecretKey {
+    Ed25519(ed25519_dalek::SigningKey),
+    Secp256k1(k256::ecdsa::SigningKey),
+    P256(p256::ecdsa::SigningKey),
+}
+
+impl SecretKey {
+    /// Generates a key from the operating system's random number generator.
+    pub fn generate(curve: Curve) -> Self {
+        let mut bytes = [0; 32];
+        loop {
+            OsRng.fill_bytes(&mut bytes);
+            // Almost all 32 byte strings are valid ECDSA scalars.
+            if let Ok(secret_key) = Self::from_bytes(curve, &bytes) {
+                return secret_key;
+            }
+        }
+    }
+
+    /// Parses the raw 32 bytes of a key: the seed for Ed25519, the scalar for
+    /// secp256k1 and P-256.
+    pub fn from_bytes(curve: Curve, bytes: &[u8]) -> Result<Self, ParseError> {
+        let secret_key = match curve {
+            Curve::Ed25519 => bytes
+                .try_into()
+                .ok()
+                .map(|bytes| Self::Ed25519(ed25519_dalek::SigningKey::from_bytes(bytes))),
+            Curve::Secp256k1 => k256::ecdsa::SigningKey::from_slice(bytes)
+                .ok()
+                .map(Self::Secp256k1),
+            Curve::P256 => p256::ecdsa::SigningKey::from_slice(bytes)
+                .ok()
+                .map(Self::P256),
+        };
+
+        secret_key.ok_or_else(|| {
+            let detail = format!("invalid {} secret key", curve);
+            ParseError::RequestParsingError(detail)
+        })
+    }
+
+    /// Parses an unencrypted `edsk`, `spsk` or `p2sk` key. Ed25519 keys may
+    /// also be given in their 64 byte form, followed by the public key.
+    pub fn from_base58(secret_key: &str) -> Result<Self, ParseError> {
+        for curve in [Curve::Ed25519, Curve::Secp256k1, Curve::P256] {
+            if let Ok(bytes) = base58::decode(curve.secret_key_prefix(), secret_key) {
+                return Self::from_bytes(curve, &bytes);
+            }
+        }
+        if let Ok(bytes) = base58::decode(base58::EDSK64, secret_key) {
+            let key = Self::from_bytes(Curve::Ed25519, &bytes[..32])?;
+            if key.public_key().to_bytes() != bytes[32..] {
+                return Err(ParseError::RequestParsingError(
+                    "secret key does not match its public key".to_string(),
+                ));
+            }
+            return Ok(key);
+        }
+
+        Err(ParseError::RequestParsingError(
+            "not a supported unencrypted secret key".to_string(),
+        ))
+    }
+
+    pub fn curve(&self) -> Curve {
+        match self {
+            Self::Ed25519(_) => Curve::Ed25519,
+            Self::Secp256k1(_) => Curve::Secp256k1,
+            Self::P256(_) => Curve::P256,
+        }
+    }
+
+    /// Raw 32 bytes of the key, see [`from_bytes()`](Self::from_bytes()).
+    pub fn to_bytes(&self) -> [u8; 32] {
+        match self {
+            Self::Ed25519(key) => key.to_bytes(),
+            Self::Secp256k1(key) => key.to_bytes().into(),
+            Self::P256(key) => key.to_bytes().into(),
+        }
+    }
+
+    /// Unencrypted `edsk`, `spsk` or `p2sk` form of the key.
+    pub fn to_base58(&self) -> String {
+        base58::encode(self.curve().secret_key_prefix(), &self.to_bytes())
+            .expect("secret keys are 32 bytes long")
+    }
+
+    pub fn public_key(&self) -> PublicKey {
+        match self {
+            Self::Ed25519(key) => PublicKey::Ed25519(key.verifying_key()),
+            Self::Secp256k1(key) => PublicKey::Secp256k1(*key.verifying_key()),
+            Self::P256(key) => PublicKey::P256(*key.verifying_key()),
+        }
+    }
+
+    /// `tz1`, `tz2` or `tz3` address of the key.
+    pub fn public_key_hash(&self) -> String {
+        self.public_key().public_key_hash()
+    }
+
+    /// Signs the digest of the watermarked `bytes`.
+    pub fn sign(
+        &self,
+        watermark: Option<&Watermark>,
+        bytes: &[u8],
+    ) -> Result<Signature, ParseError> {
+        let digest = signing_digest(watermark, bytes)?;
+        self.sign_digest(&digest)
+    }
+
+    /// Signs an already computed signing digest.
+    pub fn sign_digest(&self, digest: &[u8; 32]) -> Result<Signature, ParseError> {
+        let signature = match self {
+            Self::Ed25519(key) => key.sign(digest).to_bytes(),
+            Self::Secp256k1(key) => {
+                let signature: k256::ecdsa::Signature =
+                    key.sign_prehash(digest).map_err(signing_error)?;
+                signature
+                    .normalize_s()
+                    .unwrap_or(signature)
+                    .to_bytes()
+                    .into()
+            }
+            Self::P256(key) => {
+                let signature: p256::ecdsa::Signature =
+                    key.sign_prehash(digest).map_err(signing_error)?;
+                signature.to_bytes().into()
+            }
+        };
+        Signature::from_bytes(&signature)
+    }
+}
+
+fn signing_error(error: k256::ecdsa::Error) -> ParseError {
+    ParseError::RequestParsingError(format!("signing failed: {}", error))
+}
+
+impl FromStr for SecretKey {
+    type Err = ParseError;
+
+    fn from_str(secret_key: &str) -> Result<Self, Self::Err> {
+        Self::from_base58(secret_key)
+    }
+}
+
+impl fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecretKey")
+            .field("curve", &self.curve())
+            .field("public_key_hash", &self.public_key_hash())
+            .finish()
+    }
+}
+
+impl PartialEq for SecretKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.curve() == other.curve() && self.to_bytes() == other.to_bytes()
+    }
+}
+
+impl Eq for SecretKey {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn base58_round_trip_ok() {
+        for curve in [Curve::Ed25519, Curve::Secp256k1, Curve::P256] {
+            let secret_key = SecretKey::generate(curve);
+            let encoded = secret_key.to_base58();
+
+            assert_eq!(SecretKey::from_base58(&encoded).unwrap(), secret_key);
+            assert!(!format!("{:?}", secret_key).contains(&encoded));
+        }
+        assert!(SecretKey::generate(Curve::Secp256k1)
+            .to_base58()
+            .starts_with("spsk"));
+    }
+
+    #[test]
+    fn sign_and_verify_ok() {
+        let watermark = Watermark::GenericOperation;
+        for curve in [Curve::Ed25519, Curve::Secp256k1, Curve::P256] {
+            let secret_key = SecretKey::from_bytes(curve, &[9; 32]).unwrap();
+            let signature = secret_key.sign(Some(&watermark), b"operation").unwrap();
+
+            assert!(secret_key
+                .public_key()
+                .verify(Some(&watermark), b"operation", &signature)
+                .unwrap());
+        }
+    }
+
+    #[test]
+    fn ed25519_address_ok() {
+        let secret_key =
+            SecretKey::from_base58("edsk3gUfUPyBSfrS9CCgmCiQsTCHGkviBDusMxDJstFtojtc1zcpsh")
+                .unwrap();
+
+        assert_eq!(
+            secret_key.public_key().to_base58(),
+            "edpkuBknW28nW72KG6RoHtYW7p12T6GKc7nAbwYX5m8Wd9sDVC9yav"
+        );
+        assert_eq!(
+            secret_key.public_key_hash(),
+            "tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx"
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use super::Curve;
+use crate::encoding::{base58, forged};
+use crate::errors::ParseError;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::TryInto;
+use std::fmt;
+use std::str::FromStr;
+
+/// A 64 byte Ed25519, secp256k1 or P-256 signature.
+///
+/// Signatures do not carry their curve in binary form, so they are displayed
+/// in the generic `sig...` form unless a curve is given to
+/// [`to_base58_for()`](Self::to_base58_for()).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Signature([u8; 64]);
+
+impl Signature {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ParseError> {
+        let bytes = bytes.try_into().map_err(|_| {
+            let detail = format!("signatures are 64 bytes long, got {} bytes", bytes.len());
+            ParseError::ResponseParsingError(detail)
+        })?;
+        Ok(Self(bytes))
+    }
+
+    /// Parses a generic (`sig`) or curve-specific (`edsig`, `spsig1`, `p2sig`)
+    /// signature.
+    pub fn from_base58(signature: &str) -> Result<Self, ParseError> {
+        Self::from_bytes(&forged::encode_signature(signature)?)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 64] {
+        &self.0
+    }
+
+    pub fn to_base58(&self) -> String {
+        base58::encode(base58::SIG, &self.0).expect("signatures are 64 bytes long")
+    }
+
+    pub fn to_base58_for(&self, curve: Curve) -> String {
+        base58::encode(curve.signature_prefix(), &self.0).expect("signatures are 64 bytes long")
+    }
+}
+
+impl FromStr for Signature {
+    type Err = ParseError;
+
+    fn from_str(signature: &str) -> Result<Self, Self::Err> {
+        Self::from_base58(signature)
+    }
+}
+
+impl fmt::Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_base58())
+    }
+}
+
+impl Serialize for Signature {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_base58())
+    }
+}
+
+impl<'de> Deserialize<'de> for Signature {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let signature = String::deserialize(deserializer)?;
+        Self::from_base58(&signature).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn curve_specific_signature_round_trip_ok() {
+        let signature = Signature::from_bytes(&[7; 64]).unwrap();
+        let edsig = signature.to_base58_for(Curve::Ed25519);
+
+        assert!(edsig.starts_with("edsig"));
+        assert!(signature.to_base58().starts_with("sig"));
+        assert_eq!(Signature::from_base58(&edsig).unwrap(), signature);
+    }
+
+    #[test]
+    fn wrong_signature_length_fails() {
+        assert!(Signature::from_bytes(&[7; 63]).is_err());
+    }
+}
@@ -0,0 +1,109 @@
+use crate::encoding::forged;
+use crate::errors::ParseError;
+use crate::types::Protocol;
+
+/// Prefix added to bytes before signing them, so that a signature of one
+/// kind of data cannot be replayed as another kind.
+///
+/// Consensus watermarks also commit to the chain the data belongs to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Watermark {
+    /// Block headers, up to Hangzhou.
+    LegacyBlock {
+        chain_id: String,
+    },
+    /// Endorsements, up to Hangzhou.
+    LegacyEndorsement {
+        chain_id: String,
+    },
+    /// Operations other than consensus operations.
+    GenericOperation,
+    /// Packed Michelson data, as checked by `CHECK_SIGNATURE`.
+    MichelsonData,
+    /// Block headers, from Ithaca on.
+    Block {
+        chain_id: String,
+    },
+    Preattestation {
+        chain_id: String,
+    },
+    Attestation {
+        chain_id: String,
+    },
+}
+
+impl Watermark {
+    /// Watermark of block headers produced with `protocol`, which must be
+    /// known: blocks of an [`Unknown`](Protocol::Unknown) protocol could use
+    /// either watermark.
+    pub fn block(protocol: &Protocol, chain_id: &str) -> Result<Self, ParseError> {
+        let chain_id = chain_id.to_string();
+        match protocol {
+            Protocol::Unknown(hash) => Err(ParseError::RequestParsingError(format!(
+                "unknown block watermark of protocol {}",
+                hash
+            ))),
+            protocol if *protocol < Protocol::Ithaca => Ok(Self::LegacyBlock { chain_id }),
+            _ => Ok(Self::Block { chain_id }),
+        }
+    }
+
+    pub fn tag(&self) -> u8 {
+        match self {
+            Self::LegacyBlock { .. } => 0x01,
+            Self::LegacyEndorsement { .. } => 0x02,
+            Self::GenericOperation => 0x03,
+            Self::MichelsonData => 0x05,
+            Self::Block { .. } => 0x11,
+            Self::Preattestation { .. } => 0x12,
+            Self::Attestation { .. } => 0x13,
+        }
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, ParseError> {
+        let mut bytes = vec![self.tag()];
+        match self {
+            Self::LegacyBlock { chain_id }
+            | Self::LegacyEndorsement { chain_id }
+            | Self::Block { chain_id }
+            | Self::Preattestation { chain_id }
+            | Self::Attestation { chain_id } => {
+                bytes.extend(forged::encode_chain_id(chain_id)?);
+            }
+            Self::GenericOperation | Self::MichelsonData => {}
+        }
+        Ok(bytes)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn block_watermark_ok() {
+        let legacy = Watermark::block(&Protocol::Hangzhou, "NetXdQprcVkpaWU").unwrap();
+        let tenderbake = Watermark::block(&Protocol::Paris, "NetXdQprcVkpaWU").unwrap();
+        let athens = Protocol::from_hash("Pt24m4xiPbLDhVgVfABUjirbmda3yohdN82Sp9FeuAXJ4eV9otd");
+
+        assert_eq!(
+            legacy.to_bytes().unwrap(),
+            vec![0x01, 0x7a, 0x06, 0xa7, 0x70]
+        );
+        assert_eq!(
+            tenderbake.to_bytes().unwrap(),
+            vec![0x11, 0x7a, 0x06, 0xa7, 0x70]
+        );
+        assert_eq!(Watermark::GenericOperation.to_bytes().unwrap(), vec![0x03]);
+        assert_eq!(
+            Watermark::block(&athens, "NetXdQprcVkpaWU").unwrap().tag(),
+            0x01
+        );
+    }
+
+    #[test]
+    fn unknown_protocol_block_watermark_fails() {
+        let protocol = Protocol::from_hash("PtNextProtocol");
+        assert!(Watermark::block(&protocol, "NetXdQprcVkpaWU").is_err());
+    }
+}
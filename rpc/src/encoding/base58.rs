@@ -0,0 +1,121 @@
+use crate::errors::ParseError;
+
+/// A Tezos base58check prefix, together with the length of the raw
+/// payload it is expected to wrap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Prefix {
+    pub bytes: &'static [u8],
+    pub payload_length: usize,
+}
+
+const fn prefix(bytes: &'static [u8], payload_length: usize) -> Prefix {
+    Prefix {
+        bytes,
+        payload_length,
+    }
+}
+
+pub const TZ1: Prefix = prefix(&[6, 161, 159], 20);
+pub const TZ2: Prefix = prefix(&[6, 161, 161], 20);
+pub const TZ3: Prefix = prefix(&[6, 161, 164], 20);
+pub const TZ4: Prefix = prefix(&[6, 161, 166], 20);
+pub const KT1: Prefix = prefix(&[2, 90, 121], 20);
+pub const TXR1: Prefix = prefix(&[1, 128, 120, 31], 20);
+pub const SR1: Prefix = prefix(&[6, 124, 117], 20);
+
+pub const EDPK: Prefix = prefix(&[13, 15, 37, 217], 32);
+pub const SPPK: Prefix = prefix(&[3, 254, 226, 86], 33);
+pub const P2PK: Prefix = prefix(&[3, 178, 139, 127], 33);
+pub const BLPK: Prefix = prefix(&[6, 149, 135, 204], 48);
+
+pub const EDSK: Prefix = prefix(&[13, 15, 58, 7], 32);
+/// Ed25519 secret key given with its public key, as older wallets export it.
+pub const EDSK64: Prefix = prefix(&[43, 246, 78, 7], 64);
+pub const SPSK: Prefix = prefix(&[17, 162, 224, 201], 32);
+pub const P2SK: Prefix = prefix(&[16, 81, 238, 189], 32);
+/// Password encrypted secret keys: 8 bytes of salt and the encrypted key.
+pub const EDESK: Prefix = prefix(&[7, 90, 60, 179, 41], 56);
+pub const SPESK: Prefix = prefix(&[9, 237, 241, 174, 150], 56);
+pub const P2ESK: Prefix = prefix(&[9, 48, 57, 115, 171], 56);
+
+pub const SIG: Prefix = prefix(&[4, 130, 43], 64);
+pub const EDSIG: Prefix = prefix(&[9, 245, 205, 134, 18], 64);
+pub const SPSIG: Prefix = prefix(&[13, 115, 101, 19, 63], 64);
+pub const P2SIG: Prefix = prefix(&[54, 240, 44, 52], 64);
+
+pub const CHAIN_ID: Prefix = prefix(&[87, 82, 0], 4);
+pub const BLOCK_HASH: Prefix = prefix(&[1, 52], 32);
+pub const OPERATION_HASH: Prefix = prefix(&[5, 116], 32);
+pub const OPERATION_LIST_LIST_HASH: Prefix = prefix(&[29, 159, 109], 32);
+pub const PROTOCOL_HASH: Prefix = prefix(&[2, 170], 32);
+pub const CONTEXT_HASH: Prefix = prefix(&[79, 199], 32);
+pub const BLOCK_PAYLOAD_HASH: Prefix = prefix(&[1, 106, 242], 32);
+pub const NONCE_HASH: Prefix = prefix(&[69, 220, 169], 32);
+pub const SCRIPT_EXPR_HASH: Prefix = prefix(&[13, 44, 64, 27], 32);
+
+/// Encodes `payload` as a base58check string starting with `prefix`.
+///
+/// Fails if the payload length does not match the one expected by the prefix.
+pub fn encode(prefix: Prefix, payload: &[u8]) -> Result<String, ParseError> {
+    if payload.len() != prefix.payload_length {
+        let detail = format!(
+            "expected a {} byte payload for base58 encoding, got {} bytes",
+            prefix.payload_length,
+            payload.len()
+        );
+        return Err(ParseError::RequestParsingError(detail));
+    }
+
+    let mut prefixed_payload = prefix.bytes.to_vec();
+    prefixed_payload.extend_from_slice(payload);
+    Ok(bs58::encode(prefixed_payload).with_check().into_string())
+}
+
+/// Decodes a base58check string, verifying its checksum and `prefix`,
+/// and returns the raw payload.
+pub fn decode(prefix: Prefix, encoded: &str) -> Result<Vec<u8>, ParseError> {
+    let decoded = bs58::decode(encoded)
+        .with_check(None)
+        .into_vec()
+        .map_err(|error| ParseError::ResponseParsingError(error.to_string()))?;
+
+    let expected_length = prefix.bytes.len() + prefix.payload_length;
+    if decoded.len() != expected_length || !decoded.starts_with(prefix.bytes) {
+        let detail = format!("{} is not a valid base58 string for this prefix", encoded);
+        return Err(ParseError::ResponseParsingError(detail));
+    }
+
+    Ok(decoded[prefix.bytes.len()..].to_vec())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn encode_zero_key_hash_ok() {
+        let encoded = encode(TZ1, &[0; 20]).unwrap();
+        assert_eq!(encoded, "tz1Ke2h7sDdakHJQh8WX4Z372du1KChsksyU");
+    }
+
+    #[test]
+    fn decode_round_trip_ok() {
+        let payload: Vec<u8> = (0..20).collect();
+        let encoded = encode(KT1, &payload).unwrap();
+        assert!(encoded.starts_with("KT1"));
+
+        let decoded = decode(KT1, &encoded).unwrap();
+        assert_eq!(decoded, payload);
+    }
+
+    #[test]
+    fn decode_with_wrong_prefix_fails() {
+        let encoded = encode(TZ1, &[0; 20]).unwrap();
+        assert!(decode(TZ2, &encoded).is_err());
+    }
+
+    #[test]
+    fn encode_wrong_payload_length_fails() {
+        assert!(encode(EDPK, &[0; 20]).is_err());
+    }
+}
@@ -0,0 +1,194 @@
+//! Conversions between the binary ("optimized") and base58 forms of
+//! addresses, keys and signatures, as used by the node when packing
+//! Michelson values and forging operations.
+
+use super::base58::{self, Prefix};
+use crate::errors::ParseError;
+
+const IMPLICIT_ADDRESS_PREFIXES: [Prefix; 4] = [base58::TZ1, base58::TZ2, base58::TZ3, base58::TZ4];
+const PUBLIC_KEY_PREFIXES: [Prefix; 4] = [base58::EDPK, base58::SPPK, base58::P2PK, base58::BLPK];
+
+/// Decodes a 21 byte tagged public key hash into a `tz*` address.
+pub fn decode_public_key_hash(bytes: &[u8]) -> Result<String, ParseError> {
+    let (tag, payload) = split_tag(bytes)?;
+    let prefix = lookup_prefix(&IMPLICIT_ADDRESS_PREFIXES, tag, "public key hash")?;
+    base58::encode(prefix, payload)
+}
+
+/// Encodes a `tz*` address into its 21 byte tagged binary form.
+pub fn encode_public_key_hash(public_key_hash: &str) -> Result<Vec<u8>, ParseError> {
+    encode_tagged(&IMPLICIT_ADDRESS_PREFIXES, public_key_hash)
+}
+
+/// Decodes a tagged public key into its `edpk`/`sppk`/`p2pk`/`BLpk` form.
+pub fn decode_public_key(bytes: &[u8]) -> Result<String, ParseError> {
+    let (tag, payload) = split_tag(bytes)?;
+    let prefix = lookup_prefix(&PUBLIC_KEY_PREFIXES, tag, "public key")?;
+    base58::encode(prefix, payload)
+}
+
+/// Encodes a base58 public key into its tagged binary form.
+pub fn encode_public_key(public_key: &str) -> Result<Vec<u8>, ParseError> {
+    encode_tagged(&PUBLIC_KEY_PREFIXES, public_key)
+}
+
+/// Decodes a 22 byte contract id, optionally followed by an entrypoint name,
+/// into a `tz*`, `KT1`, `txr1` or `sr1` address (with a `%entrypoint` suffix
+/// when present).
+pub fn decode_address(bytes: &[u8]) -> Result<String, ParseError> {
+    if bytes.len() < 22 {
+        let detail = format!("address must be at least 22 bytes, got {}", bytes.len());
+        return Err(ParseError::ResponseParsingError(detail));
+    }
+
+    let (contract_id, entrypoint) = bytes.split_at(22);
+    let address = match contract_id[0] {
+        0 => decode_public_key_hash(&contract_id[1..])?,
+        tag @ 1..=3 => {
+            let prefix = match tag {
+                1 => base58::KT1,
+                2 => base58::TXR1,
+                _ => base58::SR1,
+            };
+            base58::encode(prefix, &contract_id[1..21])?
+        }
+        tag => {
+            let detail = format!("unknown address tag {}", tag);
+            return Err(ParseError::ResponseParsingError(detail));
+        }
+    };
+
+    if entrypoint.is_empty() {
+        return Ok(address);
+    }
+    let entrypoint = String::from_utf8(entrypoint.to_vec())
+        .map_err(|error| ParseError::ResponseParsingError(error.to_string()))?;
+    Ok(format!("{}%{}", address, entrypoint))
+}
+
+/// Encodes an address, optionally suffixed with `%entrypoint`, into its
+/// binary form.
+pub fn encode_address(address: &str) -> Result<Vec<u8>, ParseError> {
+    let (contract_id, entrypoint) = match address.split_once('%') {
+        Some((contract_id, entrypoint)) => (contract_id, Some(entrypoint)),
+        None => (address, None),
+    };
+
+    let mut bytes = if contract_id.starts_with("tz") {
+        let mut bytes = vec![0];
+        bytes.extend(encode_public_key_hash(contract_id)?);
+        bytes
+    } else {
+        let (tag, prefix) = if contract_id.starts_with("KT1") {
+            (1, base58::KT1)
+        } else if contract_id.starts_with("txr1") {
+            (2, base58::TXR1)
+        } else {
+            (3, base58::SR1)
+        };
+        let mut bytes = vec![tag];
+        bytes.extend(base58::decode(prefix, contract_id)?);
+        bytes.push(0);
+        bytes
+    };
+
+    if let Some(entrypoint) = entrypoint {
+        bytes.extend_from_slice(entrypoint.as_bytes());
+    }
+    Ok(bytes)
+}
+
+/// Decodes a raw 64 byte signature into its generic `sig` form.
+pub fn decode_signature(bytes: &[u8]) -> Result<String, ParseError> {
+    base58::encode(base58::SIG, bytes)
+}
+
+/// Encodes a generic (`sig`) or curve-specific (`edsig`, `spsig1`, `p2sig`)
+/// signature into its raw 64 byte form.
+pub fn encode_signature(signature: &str) -> Result<Vec<u8>, ParseError> {
+    let prefixes = [base58::SIG, base58::EDSIG, base58::SPSIG, base58::P2SIG];
+    prefixes
+        .iter()
+        .find_map(|prefix| base58::decode(*prefix, signature).ok())
+        .ok_or_else(|| {
+            let detail = format!("{} is not a valid signature", signature);
+            ParseError::RequestParsingError(detail)
+        })
+}
+
+/// Decodes a raw 4 byte chain id into its `Net` form.
+pub fn decode_chain_id(bytes: &[u8]) -> Result<String, ParseError> {
+    base58::encode(base58::CHAIN_ID, bytes)
+}
+
+/// Encodes a `Net` chain id into its raw 4 byte form.
+pub fn encode_chain_id(chain_id: &str) -> Result<Vec<u8>, ParseError> {
+    base58::decode(base58::CHAIN_ID, chain_id)
+}
+
+fn split_tag(bytes: &[u8]) -> Result<(u8, &[u8]), ParseError> {
+    match bytes.split_first() {
+        Some((tag, payload)) => Ok((*tag, payload)),
+        None => Err(ParseError::ResponseParsingError(
+            "cannot decode tagged value from empty bytes".to_string(),
+        )),
+    }
+}
+
+fn lookup_prefix(prefixes: &[Prefix], tag: u8, kind: &str) -> Result<Prefix, ParseError> {
+    prefixes.get(tag as usize).copied().ok_or_else(|| {
+        let detail = format!("unknown {} tag {}", kind, tag);
+        ParseError::ResponseParsingError(detail)
+    })
+}
+
+fn encode_tagged(prefixes: &[Prefix], encoded: &str) -> Result<Vec<u8>, ParseError> {
+    for (tag, prefix) in prefixes.iter().enumerate() {
+        if let Ok(payload) = base58::decode(*prefix, encoded) {
+            let mut bytes = vec![tag as u8];
+            bytes.extend(payload);
+            return Ok(bytes);
+        }
+    }
+
+    let detail = format!("{} has an unsupported base58 prefix", encoded);
+    Err(ParseError::RequestParsingError(detail))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn implicit_address_round_trip_ok() {
+        let address = "tz1VkjQjvH3NTVdFtGz9tWutgxLKwnhnMe2x";
+        let bytes = encode_address(address).unwrap();
+        assert_eq!(bytes.len(), 22);
+        assert_eq!(decode_address(&bytes).unwrap(), address);
+    }
+
+    #[test]
+    fn originated_address_with_entrypoint_round_trip_ok() {
+        let address = base58::encode(base58::KT1, &[7; 20]).unwrap();
+        let with_entrypoint = format!("{}%transfer", address);
+
+        let bytes = encode_address(&with_entrypoint).unwrap();
+        assert_eq!(bytes[0], 1);
+        assert_eq!(bytes[21], 0);
+        assert_eq!(decode_address(&bytes).unwrap(), with_entrypoint);
+    }
+
+    #[test]
+    fn public_key_round_trip_ok() {
+        let public_key = base58::encode(base58::SPPK, &[2; 33]).unwrap();
+        let bytes = encode_public_key(&public_key).unwrap();
+        assert_eq!(bytes[0], 1);
+        assert_eq!(decode_public_key(&bytes).unwrap(), public_key);
+    }
+
+    #[test]
+    fn unknown_address_tag_fails() {
+        let bytes = [9; 22];
+        assert!(decode_address(&bytes).is_err());
+    }
+}
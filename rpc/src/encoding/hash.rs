@@ -0,0 +1,26 @@
+use blake2::digest::consts::{U20, U32};
+use blake2::{Blake2b, Digest};
+
+/// Blake2b digest with a 32 byte output, used for block, operation and
+/// script expression hashes.
+pub fn blake2b_256(data: &[u8]) -> [u8; 32] {
+    Blake2b::<U32>::digest(data).into()
+}
+
+/// Blake2b digest with a 20 byte output, used for public key hashes.
+pub fn blake2b_160(data: &[u8]) -> [u8; 20] {
+    Blake2b::<U20>::digest(data).into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn blake2b_256_of_empty_input_ok() {
+        assert_eq!(
+            hex::encode(blake2b_256(b"")),
+            "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8"
+        );
+    }
+}
@@ -0,0 +1,4 @@
+pub mod base58;
+pub mod forged;
+pub mod hash;
+pub(crate) mod reader;
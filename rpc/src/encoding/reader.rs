@@ -0,0 +1,110 @@
+//! Cursor over binary data forged by the node, such as block headers and
+//! operations.
+
+use crate::errors::ParseError;
+use std::convert::TryInto;
+
+pub(crate) struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, offset: 0 }
+    }
+
+    pub(crate) fn read(&mut self, length: usize) -> Result<&'a [u8], ParseError> {
+        let end = self.offset + length;
+        let read = self.bytes.get(self.offset..end).ok_or_else(|| {
+            let detail = format!(
+                "expected {} more bytes at offset {}, binary data is truncated",
+                length, self.offset
+            );
+            ParseError::ResponseParsingError(detail)
+        })?;
+        self.offset = end;
+        Ok(read)
+    }
+
+    pub(crate) fn read_array<const N: usize>(&mut self) -> Result<[u8; N], ParseError> {
+        Ok(self.read(N)?.try_into().expect("read returns N bytes"))
+    }
+
+    pub(crate) fn read_u8(&mut self) -> Result<u8, ParseError> {
+        Ok(self.read(1)?[0])
+    }
+
+    /// Reads bytes prefixed with their length, on 4 bytes.
+    pub(crate) fn read_dynamic(&mut self) -> Result<&'a [u8], ParseError> {
+        let length = u32::from_be_bytes(self.read_array()?);
+        self.read(length as usize)
+    }
+
+    /// Reads a natural number in the variable length "zarith" format, where
+    /// each byte holds seven bits and has its top bit set when more follow.
+    ///
+    /// Like Octez, rejects numbers padded with trailing zero bytes, so that
+    /// each number has a single binary form.
+    pub(crate) fn read_natural(&mut self) -> Result<u64, ParseError> {
+        let mut natural = 0_u64;
+        let mut shift = 0;
+        loop {
+            let byte = self.read_u8()?;
+            let bits = u64::from(byte & 0x7f);
+            if shift >= 64 || (shift > 0 && bits >> (64 - shift) != 0) {
+                return Err(ParseError::ResponseParsingError(
+                    "natural number does not fit in 64 bits".to_string(),
+                ));
+            }
+            natural |= bits << shift;
+            if byte == 0x00 && shift > 0 {
+                return Err(ParseError::ResponseParsingError(
+                    "natural number has trailing zero bytes".to_string(),
+                ));
+            }
+            if byte & 0x80 == 0 {
+                return Ok(natural);
+            }
+            shift += 7;
+        }
+    }
+
+    pub(crate) fn remaining(&self) -> usize {
+        self.bytes.len().saturating_sub(self.offset)
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.remaining() == 0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn read_natural_ok() {
+        let mut reader = Reader::new(&[0x00, 0x7f, 0x80, 0x01, 0xc0, 0x84, 0x3d]);
+
+        assert_eq!(reader.read_natural().unwrap(), 0);
+        assert_eq!(reader.read_natural().unwrap(), 127);
+        assert_eq!(reader.read_natural().unwrap(), 128);
+        assert_eq!(reader.read_natural().unwrap(), 1_000_000);
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn non_canonical_natural_fails() {
+        assert!(Reader::new(&[0x80, 0x00]).read_natural().is_err());
+        assert!(Reader::new(&[0x83, 0x80, 0x00]).read_natural().is_err());
+    }
+
+    #[test]
+    fn truncated_data_fails() {
+        let mut reader = Reader::new(&[0x00, 0x00, 0x00, 0x02, 0x01]);
+
+        assert!(reader.read_dynamic().is_err());
+        assert!(Reader::new(&[0x80]).read_natural().is_err());
+    }
+}
@@ -0,0 +1,39 @@
+use std::{error::Error, fmt};
+#[derive(Debug)]
+pub enum KeystoreError {
+    IoError(std::io::Error),
+    ParsingError(super::ParseError),
+    UnknownAlias(String),
+    Other(String),
+}
+
+impl From<super::ParseError> for KeystoreError {
+    fn from(parse_error: super::ParseError) -> Self {
+        Self::ParsingError(parse_error)
+    }
+}
+
+impl From<std::io::Error> for KeystoreError {
+    fn from(error: std::io::Error) -> Self {
+        Self::IoError(error)
+    }
+}
+
+impl From<serde_json::Error> for KeystoreError {
+    fn from(serde_error: serde_json::Error) -> Self {
+        Self::ParsingError(super::ParseError::from(serde_error))
+    }
+}
+
+impl Error for KeystoreError {}
+impl fmt::Display for KeystoreError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let error_string = match &self {
+            Self::IoError(io_error) => io_error.to_string(),
+            Self::ParsingError(parse_error) => parse_error.to_string(),
+            Self::UnknownAlias(alias) => format!("no key is known as {}", alias),
+            Self::Other(error_string) => error_string.to_owned(),
+        };
+        write!(f, "failed in keystore operation. detail: {}", &error_string)
+    }
+}
@@ -1,4 +1,6 @@
+mod keystore_error;
 mod parse_error;
 mod rpc_error;
+pub use keystore_error::KeystoreError;
 pub use parse_error::ParseError;
 pub use rpc_error::RpcError;
@@ -3,6 +3,9 @@ use std::{error::Error, fmt};
 pub enum RpcError {
     RequestError(reqwest::Error),
     ParsingError(super::ParseError),
+    /// The node answered with a non-2xx status, along with the response
+    /// body, usually a JSON list of errors.
+    StatusError(reqwest::StatusCode, String),
     Other(String),
 }
 
@@ -31,6 +34,7 @@ impl fmt::Display for RpcError {
         let error_string = match &self {
             Self::RequestError(request_error) => request_error.to_string(),
             Self::ParsingError(parse_error) => parse_error.to_string(),
+            Self::StatusError(status, body) => format!("node responded with {}: {}", status, body),
             Self::Other(error_string) => error_string.to_owned(),
         };
         write!(f, "failed in rpc call. detail: {}", &error_string)
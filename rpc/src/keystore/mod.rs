@@ -0,0 +1,415 @@
+//! Keys stored by octez-client in its base directory (`~/.tezos-client` by
+//! default), in the `secret_keys`, `public_keys` and `public_key_hashs`
+//! files.
+//!
+//! Each file is a JSON list of `{"name": alias, "value": ...}` entries.
+//! Secret keys are URIs such as `unencrypted:edsk...` or
+//! `encrypted:edesk...`; keys held by a remote signer or a Ledger have
+//! other schemes and cannot be loaded in-process.
+
+use crate::crypto::{PublicKey, SecretKey};
+use crate::errors::KeystoreError;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::fs;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+const SECRET_KEYS_FILE: &str = "secret_keys";
+const PUBLIC_KEYS_FILE: &str = "public_keys";
+const PUBLIC_KEY_HASHES_FILE: &str = "public_key_hashs";
+
+/// Location of a secret key, as stored in the `secret_keys` file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SecretKeyUri {
+    Unencrypted(Box<SecretKey>),
+    /// `edesk`, `spesk` or `p2esk` key, to be decrypted with a password.
+    Encrypted(String),
+    /// Key held outside of the client, such as by a remote signer
+    /// (`tcp://`, `http://`...) or a Ledger (`ledger://`).
+    External(String),
+}
+
+impl SecretKeyUri {
+    pub fn parse(uri: &str) -> Result<Self, KeystoreError> {
+        let parsed = if let Some(secret_key) = uri.strip_prefix("unencrypted:") {
+            Self::Unencrypted(Box::new(SecretKey::from_base58(secret_key)?))
+        } else if let Some(secret_key) = uri.strip_prefix("encrypted:") {
+            Self::Encrypted(secret_key.to_string())
+        } else {
+            Self::External(uri.to_string())
+        };
+        Ok(parsed)
+    }
+
+    pub fn to_uri_string(&self) -> String {
+        match self {
+            Self::Unencrypted(secret_key) => format!("unencrypted:{}", secret_key.to_base58()),
+            Self::Encrypted(secret_key) => format!("encrypted:{}", secret_key),
+            Self::External(uri) => uri.clone(),
+        }
+    }
+
+    /// Loads the key, decrypting it with `password` if it is encrypted.
+    pub fn secret_key(&self, password: Option<&str>) -> Result<SecretKey, KeystoreError> {
+        match (self, password) {
+            (Self::Unencrypted(secret_key), _) => Ok(*secret_key.clone()),
+            (Self::Encrypted(secret_key), Some(password)) => {
+                Ok(SecretKey::from_encrypted(secret_key, password)?)
+            }
+            (Self::Encrypted(_), None) => Err(KeystoreError::Other(
+                "a password is required to decrypt the key".to_string(),
+            )),
+            (Self::External(uri), _) => Err(KeystoreError::Other(format!(
+                "key at {} is held outside of the client",
+                uri
+            ))),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct Entry<T> {
+    name: String,
+    value: T,
+}
+
+/// The keys of an octez-client base directory.
+///
+/// Changes are kept in memory until [`save()`](Self::save()) is called.
+#[derive(Debug)]
+pub struct ClientKeystore {
+    base_dir: PathBuf,
+    secret_keys: Vec<Entry<String>>,
+    /// Either a public key URI, or `{"locator": uri, "key": public_key}` in
+    /// recent clients.
+    public_keys: Vec<Entry<Value>>,
+    public_key_hashes: Vec<Entry<String>>,
+}
+
+impl ClientKeystore {
+    /// Reads the keys of `base_dir`, missing files being treated as empty.
+    pub fn open<P: AsRef<Path>>(base_dir: P) -> Result<Self, KeystoreError> {
+        let base_dir = base_dir.as_ref().to_path_buf();
+        Ok(Self {
+            secret_keys: read_entries(&base_dir.join(SECRET_KEYS_FILE))?,
+            public_keys: read_entries(&base_dir.join(PUBLIC_KEYS_FILE))?,
+            public_key_hashes: read_entries(&base_dir.join(PUBLIC_KEY_HASHES_FILE))?,
+            base_dir,
+        })
+    }
+
+    /// Aliases of all known addresses, including those without keys.
+    pub fn aliases(&self) -> Vec<&str> {
+        self.public_key_hashes
+            .iter()
+            .map(|entry| entry.name.as_str())
+            .collect()
+    }
+
+    pub fn public_key_hash(&self, alias: &str) -> Option<&str> {
+        find(&self.public_key_hashes, alias).map(String::as_str)
+    }
+
+    pub fn public_key(&self, alias: &str) -> Result<Option<PublicKey>, KeystoreError> {
+        let public_key = match find(&self.public_keys, alias) {
+            Some(Value::Object(object)) => object.get("key").and_then(Value::as_str),
+            Some(Value::String(uri)) => uri.strip_prefix("unencrypted:"),
+            _ => None,
+        };
+        Ok(public_key.map(PublicKey::from_base58).transpose()?)
+    }
+
+    pub fn secret_key_uri(&self, alias: &str) -> Result<SecretKeyUri, KeystoreError> {
+        let uri = find(&self.secret_keys, alias)
+            .ok_or_else(|| KeystoreError::UnknownAlias(alias.to_string()))?;
+        SecretKeyUri::parse(uri)
+    }
+
+    /// Loads the secret key of `alias`, decrypting it with `password` if it
+    /// is encrypted.
+    pub fn secret_key(
+        &self,
+        alias: &str,
+        password: Option<&str>,
+    ) -> Result<SecretKey, KeystoreError> {
+        self.secret_key_uri(alias)?.secret_key(password)
+    }
+
+    /// Adds a key under `alias`, encrypted with `password` if one is given.
+    ///
+    /// Fails if the alias is taken, unless `force` is set, as with
+    /// `octez-client import secret key --force`.
+    pub fn add(
+        &mut self,
+        alias: &str,
+        secret_key: &SecretKey,
+        password: Option<&str>,
+        force: bool,
+    ) -> Result<(), KeystoreError> {
+        if !force && self.aliases().contains(&alias) {
+            return Err(KeystoreError::Other(format!(
+                "alias {} already exists",
+                alias
+            )));
+        }
+
+        let secret_key_uri = match password {
+            Some(password) => SecretKeyUri::Encrypted(secret_key.encrypt(password)),
+            None => SecretKeyUri::Unencrypted(Box::new(secret_key.clone())),
+        };
+        let public_key = secret_key.public_key().to_base58();
+        let public_key_value = json!({
+            "locator": format!("unencrypted:{}", public_key),
+            "key": public_key,
+        });
+
+        upsert(&mut self.secret_keys, alias, secret_key_uri.to_uri_string());
+        upsert(&mut self.public_keys, alias, public_key_value);
+        upsert(
+            &mut self.public_key_hashes,
+            alias,
+            secret_key.public_key_hash(),
+        );
+        Ok(())
+    }
+
+    /// Forgets all the keys of `alias`, returning whether it was known.
+    pub fn remove(&mut self, alias: &str) -> bool {
+        let known = self.aliases().contains(&alias);
+        self.secret_keys.retain(|entry| entry.name != alias);
+        self.public_keys.retain(|entry| entry.name != alias);
+        self.public_key_hashes.retain(|entry| entry.name != alias);
+        known
+    }
+
+    /// Writes the keys back to the base directory, creating it if needed.
+    ///
+    /// The directory and each file are only accessible by their owner, and
+    /// every file is replaced atomically.
+    pub fn save(&self) -> Result<(), KeystoreError> {
+        let mut builder = fs::DirBuilder::new();
+        builder.recursive(true);
+        #[cfg(unix)]
+        std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+        builder.create(&self.base_dir)?;
+        write_entries(&self.base_dir.join(SECRET_KEYS_FILE), &self.secret_keys)?;
+        write_entries(&self.base_dir.join(PUBLIC_KEYS_FILE), &self.public_keys)?;
+        write_entries(
+            &self.base_dir.join(PUBLIC_KEY_HASHES_FILE),
+            &self.public_key_hashes,
+        )
+    }
+}
+
+fn find<'a, T>(entries: &'a [Entry<T>], alias: &str) -> Option<&'a T> {
+    entries
+        .iter()
+        .find(|entry| entry.name == alias)
+        .map(|entry| &entry.value)
+}
+
+fn upsert<T>(entries: &mut Vec<Entry<T>>, alias: &str, value: T) {
+    match entries.iter_mut().find(|entry| entry.name == alias) {
+        Some(entry) => entry.value = value,
+        None => entries.push(Entry {
+            name: alias.to_string(),
+            value,
+        }),
+    }
+}
+
+fn read_entries<T: for<'de> Deserialize<'de>>(path: &Path) -> Result<Vec<Entry<T>>, KeystoreError> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(serde_json::from_str(&contents)?),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(Vec::new()),
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// Writes `entries` to a temporary file next to `path`, then renames it over
+/// `path`, so that readers never see a partially written file.
+fn write_entries<T: Serialize>(path: &Path, entries: &[Entry<T>]) -> Result<(), KeystoreError> {
+    let contents = serde_json::to_string_pretty(entries)?;
+    let mut temp_name = path.file_name().unwrap_or_default().to_os_string();
+    temp_name.push(".tmp");
+    let temp_path = path.with_file_name(temp_name);
+
+    // A leftover temp file would keep its old permissions, so start afresh.
+    match fs::remove_file(&temp_path) {
+        Err(error) if error.kind() != ErrorKind::NotFound => return Err(error.into()),
+        _ => {}
+    }
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let written = options.open(&temp_path).and_then(|mut file| {
+        file.write_all(contents.as_bytes())?;
+        file.sync_all()
+    });
+    if let Err(error) = written.and_then(|_| fs::rename(&temp_path, path)) {
+        let _ = fs::remove_file(&temp_path);
+        return Err(error.into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::crypto::Curve;
+
+    const BOOTSTRAP1_SECRET_KEY: &str = "edsk3gUfUPyBSfrS9CCgmCiQsTCHGkviBDusMxDJstFtojtc1zcpsh";
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("rpc-keystore-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn read_client_files_ok() {
+        let dir = temp_dir("read");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join(SECRET_KEYS_FILE),
+            format!(
+                r#"[{{"name":"bootstrap1","value":"unencrypted:{}"}},
+                    {{"name":"baker","value":"tcp://localhost:7732/tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx"}}]"#,
+                BOOTSTRAP1_SECRET_KEY
+            ),
+        )
+        .unwrap();
+        fs::write(
+            dir.join(PUBLIC_KEYS_FILE),
+            r#"[{"name":"bootstrap1","value":{"locator":"unencrypted:edpkuBknW28nW72KG6RoHtYW7p12T6GKc7nAbwYX5m8Wd9sDVC9yav","key":"edpkuBknW28nW72KG6RoHtYW7p12T6GKc7nAbwYX5m8Wd9sDVC9yav"}},
+                {"name":"baker","value":"unencrypted:edpkuBknW28nW72KG6RoHtYW7p12T6GKc7nAbwYX5m8Wd9sDVC9yav"}]"#,
+        )
+        .unwrap();
+        fs::write(
+            dir.join(PUBLIC_KEY_HASHES_FILE),
+            r#"[{"name":"bootstrap1","value":"tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx"},
+                {"name":"baker","value":"tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx"}]"#,
+        )
+        .unwrap();
+
+        let keystore = ClientKeystore::open(&dir).unwrap();
+        let secret_key = keystore.secret_key("bootstrap1", None).unwrap();
+        assert_eq!(keystore.aliases(), vec!["bootstrap1", "baker"]);
+        assert_eq!(
+            keystore.public_key_hash("bootstrap1"),
+            Some(secret_key.public_key_hash().as_str())
+        );
+        assert_eq!(
+            keystore.public_key("baker").unwrap(),
+            Some(secret_key.public_key())
+        );
+        assert!(matches!(
+            keystore.secret_key_uri("baker").unwrap(),
+            SecretKeyUri::External(_)
+        ));
+        assert!(keystore.secret_key("baker", None).is_err());
+        assert!(matches!(
+            keystore.secret_key("nobody", None),
+            Err(KeystoreError::UnknownAlias(_))
+        ));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn add_encrypted_key_and_save_ok() {
+        let dir = temp_dir("write");
+        let secret_key = SecretKey::from_bytes(Curve::Secp256k1, &[8; 32]).unwrap();
+
+        let mut keystore = ClientKeystore::open(&dir).unwrap();
+        keystore
+            .add("payouts", &secret_key, Some("hunter2"), false)
+            .unwrap();
+        assert!(keystore.add("payouts", &secret_key, None, false).is_err());
+        keystore.save().unwrap();
+
+        let keystore = ClientKeystore::open(&dir).unwrap();
+        assert!(matches!(
+            keystore.secret_key_uri("payouts").unwrap(),
+            SecretKeyUri::Encrypted(key) if key.starts_with("spesk")
+        ));
+        assert!(keystore.secret_key("payouts", None).is_err());
+        assert_eq!(
+            keystore.secret_key("payouts", Some("hunter2")).unwrap(),
+            secret_key
+        );
+        assert_eq!(
+            keystore.public_key_hash("payouts"),
+            Some(secret_key.public_key_hash().as_str())
+        );
+        assert!(!dir.join("secret_keys.tmp").exists());
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let metadata = fs::metadata(&dir).unwrap();
+            assert_eq!(metadata.permissions().mode() & 0o777, 0o700);
+            let metadata = fs::metadata(dir.join(SECRET_KEYS_FILE)).unwrap();
+            assert_eq!(metadata.permissions().mode() & 0o777, 0o600);
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn save_replaces_stale_temp_file_ok() {
+        let dir = temp_dir("stale");
+        fs::create_dir_all(&dir).unwrap();
+        let stale = dir.join("secret_keys.tmp");
+        fs::write(&stale, "stale").unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&stale, fs::Permissions::from_mode(0o644)).unwrap();
+        }
+
+        let secret_key = SecretKey::from_bytes(Curve::Ed25519, &[9; 32]).unwrap();
+        let mut keystore = ClientKeystore::open(&dir).unwrap();
+        keystore.add("baker", &secret_key, None, false).unwrap();
+        keystore.save().unwrap();
+
+        assert!(!stale.exists());
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let metadata = fs::metadata(dir.join(SECRET_KEYS_FILE)).unwrap();
+            assert_eq!(metadata.permissions().mode() & 0o777, 0o600);
+        }
+        let keystore = ClientKeystore::open(&dir).unwrap();
+        assert_eq!(keystore.secret_key("baker", None).unwrap(), secret_key);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    /// Keys encrypted by another implementation, taken from Taquito's signer
+    /// tests, with the password `test`.
+    #[test]
+    fn decrypt_external_encrypted_keys_ok() {
+        let dir = temp_dir("external");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join(SECRET_KEYS_FILE),
+            r#"[{"name":"tz2","value":"encrypted:spesk24UQkAiJk8X6AufNtRv1WWPp2BAssEgmijCTQPMgUXweSKPmLdbyAjPmCG1pR2dC9P5UZZVeZcb7zVodUHZ"},
+                {"name":"tz3","value":"encrypted:p2esk28hoUE2J88QNFj2aDX2pjzL7wcVh2g8tkEwtWWguby9M3FHUgSbzvF2Sd7wQ4Kd8crFwvto6gF3otcBuo4T"}]"#,
+        )
+        .unwrap();
+
+        let keystore = ClientKeystore::open(&dir).unwrap();
+        let tz2 = keystore.secret_key("tz2", Some("test")).unwrap();
+        let tz3 = keystore.secret_key("tz3", Some("test")).unwrap();
+        assert_eq!(
+            tz2.public_key_hash(),
+            "tz2HT7VLPySSMUm9bPtDDTSQJczuZxAgt1yj"
+        );
+        assert_eq!(
+            tz3.public_key_hash(),
+            "tz3be5v4ZWL3zQYUZoLWJQy8P3H6RJryVVXn"
+        );
+        assert!(keystore.secret_key("tz2", Some("test1234")).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -11,10 +11,22 @@
 //! - Highly configurable for different Tezos use cases
 
 pub mod commands;
+pub mod crypto;
+pub mod encoding;
 pub mod errors;
+pub mod keystore;
+pub mod metadata;
+pub mod michelson;
+pub mod operations;
 pub mod responses;
+pub mod signer;
+#[cfg(test)]
+mod test_server;
+pub mod tokens;
 pub mod types;
+use commands::chains::blocks::get_block_protocols::GetBlockProtocols;
 use commands::RpcClientCommand;
+use types::{Block, Chain, Protocol};
 use url::Url;
 
 /// Client wrapper and executor for making RPC calls to the Tezos net.
@@ -61,8 +73,15 @@ impl RpcClient {
     /// Makes the JSON RPC request to the endpoint specified by the
     /// [`command`](RpcClientCommand) passed in.
     ///
+    /// The request uses the command's [`HTTP method`](RpcClientCommand::get_http_method),
+    /// `GET`, `POST`, `PATCH` or `DELETE`, and sends its
+    /// [`body`](RpcClientCommand::get_request_body) as JSON when it has one.
+    ///
     /// Returns a response object parsed by the incoming command from the
-    /// raw server response.
+    /// raw server response, or [`RpcError::StatusError`](errors::RpcError::StatusError)
+    /// when the node answers with a non-2xx status, whatever the body.
+    /// Commands whose resource may be missing can turn an empty `404` into
+    /// a response with [`not_found_response()`](RpcClientCommand::not_found_response).
     pub async fn execute<T: RpcClientCommand>(
         &self,
         command: &T,
@@ -70,9 +89,115 @@ impl RpcClient {
         let raw_endpoint_url = format!("{}{}", self.tezos_node_url, command.get_url_string());
         let endpoint_url = reqwest::Url::parse(&raw_endpoint_url)?;
 
-        let request = self.client.request(command.get_http_method(), endpoint_url);
-        let response_str = request.send().await?.text().await?;
+        let mut request = self.client.request(command.get_http_method(), endpoint_url);
+        if let Some(body) = command.get_request_body() {
+            request = request
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(body);
+        }
+        let response = request.send().await?;
+        let status = response.status();
+        let response_str = response.text().await?;
+        if status == reqwest::StatusCode::NOT_FOUND && response_str.trim().is_empty() {
+            if let Some(not_found_response) = command.not_found_response() {
+                return Ok(not_found_response);
+            }
+        }
+        if !status.is_success() {
+            return Err(errors::RpcError::StatusError(status, response_str));
+        }
 
         Ok(command.from_response_str(&response_str)?)
     }
+
+    /// Fetches the protocol `block_id` was produced with.
+    ///
+    /// Pass the result to
+    /// [`WithProtocol`](commands::protocols::with_protocol::WithProtocol)
+    /// to decode a command's response with that protocol's schema.
+    pub async fn detect_protocol(
+        &self,
+        chain_id: &Chain,
+        block_id: &Block,
+    ) -> Result<Protocol, errors::RpcError> {
+        let command = GetBlockProtocols {
+            chain_id: chain_id.clone(),
+            block_id: block_id.clone(),
+        };
+
+        Ok(self.execute(&command).await?.protocol)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use commands::chains::blocks::delete_invalid_block::DeleteInvalidBlock;
+    use test_server::serve;
+
+    /// Stand-in node answering every request with `status` and `body`.
+    async fn serve_node(status: &'static str, body: &'static str) -> Url {
+        serve(move |_| (status, body.to_string())).await
+    }
+
+    fn delete_invalid_block() -> DeleteInvalidBlock {
+        DeleteInvalidBlock {
+            chain_id: Chain::Main,
+            block_hash: "BLockGenesisGenesisGenesisGenesisGenesisf79b5d1CoW2".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_success_status_ok() {
+        let url = serve(|request| {
+            let expected_path = "/chains/main/invalid_blocks/\
+                                 BLockGenesisGenesisGenesisGenesisGenesisf79b5d1CoW2";
+            if request.method == "DELETE"
+                && request.target.path() == expected_path
+                && request.body.is_empty()
+            {
+                ("200 OK", "{}".to_string())
+            } else {
+                ("400 Bad Request", String::new())
+            }
+        })
+        .await;
+
+        let client = RpcClient::new(url);
+        assert!(client.execute(&delete_invalid_block()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn execute_error_status_with_empty_body_fails() {
+        for (status, body) in [("404 Not Found", ""), ("500 Internal Server Error", "{}")] {
+            let client = RpcClient::new(serve_node(status, body).await);
+            match client.execute(&delete_invalid_block()).await {
+                Err(errors::RpcError::StatusError(status, response_body)) => {
+                    assert!(!status.is_success());
+                    assert_eq!(response_body, body);
+                }
+                other => panic!("expected a status error, got {:?}", other),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_not_found_as_missing_big_map_value_ok() {
+        use commands::chains::blocks::get_big_map_value::GetBigMapValue;
+        let command = GetBigMapValue {
+            chain_id: Chain::Main,
+            block_id: Block::Head,
+            big_map_id: "12".to_string(),
+            script_expr: "exprtZBwZUeYYYfUs9B9Rg2ywHezVHnCCnmF9WsDQVrs582dSK63dC".to_string(),
+        };
+
+        let client = RpcClient::new(serve_node("404 Not Found", "").await);
+        assert_eq!(client.execute(&command).await.unwrap().value, None);
+
+        let client = RpcClient::new(serve_node("404 Not Found", "Did not find service").await);
+        assert!(client.execute(&command).await.is_err());
+
+        let client = RpcClient::new(serve_node("500 Internal Server Error", "").await);
+        assert!(client.execute(&command).await.is_err());
+    }
 }
@@ -0,0 +1,57 @@
+use crate::errors::RpcError;
+use async_trait::async_trait;
+
+const DEFAULT_IPFS_GATEWAY: &str = "https://ipfs.io/ipfs/";
+
+/// Retrieves the content of metadata URIs stored outside of the chain.
+///
+/// Implement this to control how (or whether) `https://` and `ipfs://`
+/// URIs are dereferenced, e.g. to use a local cache or a trusted gateway.
+#[async_trait]
+pub trait UriFetcher: Send + Sync {
+    async fn fetch(&self, uri: &str) -> Result<Vec<u8>, RpcError>;
+}
+
+/// [`UriFetcher`] for `http://`, `https://` and `ipfs://` URIs, the latter
+/// going through an HTTP gateway.
+pub struct HttpFetcher {
+    client: reqwest::Client,
+    ipfs_gateway: String,
+}
+
+impl HttpFetcher {
+    pub fn new() -> Self {
+        Self::with_ipfs_gateway(DEFAULT_IPFS_GATEWAY.to_string())
+    }
+
+    /// `ipfs_gateway` is prepended to the CID and path of `ipfs://` URIs,
+    /// e.g. `https://ipfs.io/ipfs/`.
+    pub fn with_ipfs_gateway(ipfs_gateway: String) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            ipfs_gateway,
+        }
+    }
+}
+
+impl Default for HttpFetcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl UriFetcher for HttpFetcher {
+    async fn fetch(&self, uri: &str) -> Result<Vec<u8>, RpcError> {
+        let url = if let Some(path) = uri.strip_prefix("ipfs://") {
+            format!("{}{}", self.ipfs_gateway, path)
+        } else if uri.starts_with("http://") || uri.starts_with("https://") {
+            uri.to_string()
+        } else {
+            return Err(RpcError::Other(format!("unsupported metadata URI {}", uri)));
+        };
+
+        let response = self.client.get(&url).send().await?.error_for_status()?;
+        Ok(response.bytes().await?.to_vec())
+    }
+}
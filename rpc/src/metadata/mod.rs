@@ -0,0 +1,196 @@
+//! TZIP-16 contract metadata: the typed metadata document, the URIs
+//! pointing to it and their resolution.
+
+mod fetcher;
+mod resolver;
+mod uri;
+pub use fetcher::{HttpFetcher, UriFetcher};
+pub use resolver::MetadataResolver;
+pub use uri::MetadataUri;
+
+use crate::errors::ParseError;
+use crate::michelson::Micheline;
+use crate::responses::Response;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::fmt;
+
+/// A TZIP-16 metadata document.
+///
+/// Fields not defined by TZIP-16 are kept in `extra`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct ContractMetadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license: Option<License>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub authors: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub homepage: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<Source>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub interfaces: Vec<String>,
+    /// Error translations, left as JSON.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<Value>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub views: Vec<OffChainView>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl ContractMetadata {
+    pub fn view(&self, name: &str) -> Option<&OffChainView> {
+        self.views.iter().find(|view| view.name == name)
+    }
+}
+
+impl Response for ContractMetadata {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        Ok(serde_json::from_str(response)?)
+    }
+}
+
+impl fmt::Display for ContractMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct License {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Source {
+    #[serde(default)]
+    pub tools: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct OffChainView {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Whether the view's result only depends on the contract's storage.
+    #[serde(default)]
+    pub pure: bool,
+    pub implementations: Vec<ViewImplementation>,
+}
+
+impl OffChainView {
+    /// Returns the first Michelson implementation of the view, if any.
+    pub fn michelson_storage_view(&self) -> Option<&MichelsonStorageView> {
+        self.implementations
+            .iter()
+            .find_map(|implementation| match implementation {
+                ViewImplementation::MichelsonStorageView(view) => Some(view),
+                ViewImplementation::RestApiQuery(_) => None,
+            })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ViewImplementation {
+    MichelsonStorageView(MichelsonStorageView),
+    RestApiQuery(RestApiQuery),
+}
+
+/// A view run against the contract's storage, taking `parameter` (if any)
+/// and returning a value of `return_type`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct MichelsonStorageView {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parameter: Option<Micheline>,
+    pub return_type: Micheline,
+    pub code: Micheline,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<ViewAnnotation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ViewAnnotation {
+    pub name: String,
+    pub description: String,
+}
+
+/// A view served by an off-chain REST API described by an OpenAPI
+/// specification.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RestApiQuery {
+    pub specification_uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_uri: Option<String>,
+    pub path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_metadata_with_views_ok() {
+        let mock_response = r#"{
+            "name": "FA2 NFT",
+            "version": "1.0.0",
+            "license": {"name": "MIT"},
+            "authors": ["Example <dev@example.com>"],
+            "interfaces": ["TZIP-012", "TZIP-016"],
+            "views": [
+                {
+                    "name": "get_balance",
+                    "pure": true,
+                    "implementations": [
+                        {"michelsonStorageView": {
+                            "parameter": {"prim": "pair", "args": [{"prim": "address"}, {"prim": "nat"}]},
+                            "returnType": {"prim": "nat"},
+                            "code": [{"prim": "CAR"}]
+                        }},
+                        {"restApiQuery": {"specificationUri": "https://example.com/api.json", "path": "/balance"}}
+                    ]
+                }
+            ],
+            "tokenCategory": "collectibles"
+        }"#;
+
+        let metadata = ContractMetadata::from_response_str(mock_response).unwrap();
+        assert_eq!(metadata.name.as_deref(), Some("FA2 NFT"));
+        assert_eq!(metadata.license.as_ref().unwrap().name, "MIT");
+        assert_eq!(metadata.interfaces.len(), 2);
+        assert_eq!(metadata.extra["tokenCategory"], "collectibles");
+
+        let view = metadata.view("get_balance").unwrap();
+        assert!(view.pure);
+        assert_eq!(view.implementations.len(), 2);
+        let storage_view = view.michelson_storage_view().unwrap();
+        assert_eq!(
+            storage_view.return_type,
+            Micheline::prim("nat", vec![], vec![])
+        );
+    }
+
+    #[test]
+    fn deserialize_invalid_view_fails() {
+        let mock_response =
+            r#"{"views": [{"name": "broken", "implementations": [{"unknown": {}}]}]}"#;
+        assert!(ContractMetadata::from_response_str(mock_response).is_err());
+    }
+}
@@ -0,0 +1,318 @@
+use super::{ContractMetadata, MetadataUri, UriFetcher};
+use crate::commands::chains::blocks::{
+    get_big_map_value::GetBigMapValue, get_chain_id::GetChainId,
+    get_contract_script::GetContractScript,
+};
+use crate::errors::{ParseError, RpcError};
+use crate::michelson::{script_expr_hash, Micheline};
+use crate::responses::Response;
+use crate::tokens::BigMapLocation;
+use crate::types::{Block, Chain};
+use crate::RpcClient;
+use sha2::{Digest, Sha256};
+
+const METADATA_BIG_MAP: &str = "metadata";
+const ROOT_KEY: &str = "";
+/// Chain ids of the networks `tezos-storage:` URIs may name instead of
+/// giving a chain id.
+const NETWORK_CHAIN_IDS: [(&str, &str); 2] = [
+    ("mainnet", "NetXdQprcVkpaWU"),
+    ("ghostnet", "NetXnHfVqm9iesp"),
+];
+
+/// Resolves the TZIP-16 metadata of contracts at a given block.
+///
+/// The root URI is read from the empty key of the contract's `%metadata`
+/// big map. `tezos-storage:` URIs are read from the node, `sha256://` URIs
+/// are checked against their hash, and other URIs are handed to the
+/// [`UriFetcher`].
+///
+/// Cross-contract `tezos-storage:` URIs naming a network are only followed
+/// when it is the network of `chain_id`, since they are read from the node.
+pub struct MetadataResolver<'a, F> {
+    client: &'a RpcClient,
+    fetcher: F,
+    chain_id: Chain,
+    block_id: Block,
+}
+
+impl<'a, F: UriFetcher> MetadataResolver<'a, F> {
+    pub fn new(client: &'a RpcClient, fetcher: F, chain_id: Chain, block_id: Block) -> Self {
+        Self {
+            client,
+            fetcher,
+            chain_id,
+            block_id,
+        }
+    }
+
+    pub async fn resolve(&self, contract: &str) -> Result<ContractMetadata, RpcError> {
+        let root_uri = self.read_storage(contract, ROOT_KEY).await?;
+        let root_uri = String::from_utf8(root_uri).map_err(|error| {
+            RpcError::ParsingError(ParseError::ResponseParsingError(error.to_string()))
+        })?;
+
+        let content = self.fetch_uri(contract, &root_uri).await?;
+        let content = std::str::from_utf8(&content).map_err(|error| {
+            RpcError::ParsingError(ParseError::ResponseParsingError(error.to_string()))
+        })?;
+        Ok(ContractMetadata::from_response_str(content)?)
+    }
+
+    /// Returns the raw content `uri` points to, `contract` being the one
+    /// `tezos-storage:` URIs without an address refer to.
+    pub async fn fetch_uri(&self, contract: &str, uri: &str) -> Result<Vec<u8>, RpcError> {
+        let mut uri = MetadataUri::parse(uri)?;
+        let mut expected_hashes = Vec::new();
+        let content = loop {
+            match uri {
+                MetadataUri::Sha256 {
+                    hash,
+                    uri: inner_uri,
+                } => {
+                    expected_hashes.push(hash);
+                    uri = *inner_uri;
+                }
+                MetadataUri::TezosStorage {
+                    contract: other_contract,
+                    network,
+                    key,
+                } => {
+                    if let Some(network) = network {
+                        self.check_network(&network).await?;
+                    }
+                    let storage_contract = other_contract.as_deref().unwrap_or(contract);
+                    break self.read_storage(storage_contract, &key).await?;
+                }
+                MetadataUri::External(uri) => break self.fetcher.fetch(&uri).await?,
+            }
+        };
+
+        let content_hash = Sha256::digest(&content);
+        if let Some(hash) = expected_hashes
+            .iter()
+            .find(|hash| **hash != content_hash[..])
+        {
+            let detail = format!(
+                "metadata content hashes to 0x{}, expected 0x{}",
+                hex::encode(content_hash),
+                hex::encode(hash)
+            );
+            return Err(RpcError::Other(detail));
+        }
+
+        Ok(content)
+    }
+
+    /// Fails unless `network`, a chain id or a well-known network name, is
+    /// the network of `chain_id`.
+    async fn check_network(&self, network: &str) -> Result<(), RpcError> {
+        let expected_chain_id = NETWORK_CHAIN_IDS
+            .iter()
+            .find(|(name, _)| *name == network)
+            .map_or(network, |(_, chain_id)| chain_id);
+
+        let command = GetChainId {
+            chain_id: self.chain_id.clone(),
+        };
+        let chain_id = self.client.execute(&command).await?.chain_id;
+        if chain_id != expected_chain_id {
+            return Err(RpcError::Other(format!(
+                "metadata is stored on network {}, but the node is on {}",
+                network, chain_id
+            )));
+        }
+        Ok(())
+    }
+
+    async fn read_storage(&self, contract: &str, key: &str) -> Result<Vec<u8>, RpcError> {
+        let script_command = GetContractScript {
+            chain_id: self.chain_id.clone(),
+            block_id: self.block_id.clone(),
+            contract_id: contract.to_string(),
+        };
+        let script = self.client.execute(&script_command).await?;
+        let location = BigMapLocation::find(&script, METADATA_BIG_MAP)?
+            .ok_or_else(|| RpcError::Other(format!("{} has no %metadata big map", contract)))?;
+
+        let command = GetBigMapValue {
+            chain_id: self.chain_id.clone(),
+            block_id: self.block_id.clone(),
+            big_map_id: location.big_map_id.to_string(),
+            script_expr: script_expr_hash(&location.key_type, &Micheline::string(key))?,
+        };
+        let value = self.client.execute(&command).await?.value;
+
+        value.as_ref().and_then(Micheline::as_bytes).ok_or_else(|| {
+            RpcError::Other(format!("{} has no metadata under key {:?}", contract, key))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_server;
+    use async_trait::async_trait;
+    use serde_json::json;
+    use std::collections::HashMap;
+    use url::Url;
+
+    struct LocalFetcher {
+        files: HashMap<String, Vec<u8>>,
+    }
+
+    #[async_trait]
+    impl UriFetcher for LocalFetcher {
+        async fn fetch(&self, uri: &str) -> Result<Vec<u8>, RpcError> {
+            self.files
+                .get(uri)
+                .cloned()
+                .ok_or_else(|| RpcError::Other(format!("{} not found", uri)))
+        }
+    }
+
+    const METADATA: &str = r#"{"name":"Test contract","interfaces":["TZIP-016"]}"#;
+    const CONTRACT: &str = "KT1Hkg5qeNhfwpKW4fXvq7HGZB9z2EnmCCA9";
+    const OTHER_CONTRACT: &str = "KT1PWx2mnDueood7fEmfbBDKx1D9BAnnXitn";
+
+    /// Local stand-in of a mainnet node, serving the script and `%metadata`
+    /// big map of contracts. Other paths are answered with an empty `404`.
+    struct LocalNode {
+        responses: HashMap<String, String>,
+    }
+
+    impl LocalNode {
+        fn new() -> Self {
+            let mut responses = HashMap::new();
+            responses.insert(
+                "/chains/main/chain_id".to_string(),
+                r#""NetXdQprcVkpaWU""#.to_string(),
+            );
+            Self { responses }
+        }
+
+        /// Adds a contract whose `%metadata` big map, `big_map_id`, binds
+        /// `entries`.
+        fn with_contract(
+            mut self,
+            contract: &str,
+            big_map_id: u32,
+            entries: &[(&str, &[u8])],
+        ) -> Self {
+            let script = format!(
+                r#"{{"code":[{{"prim":"parameter","args":[{{"prim":"unit"}}]}},
+                    {{"prim":"storage","args":[{{"prim":"pair","args":[
+                        {{"prim":"big_map","args":[{{"prim":"string"}},{{"prim":"bytes"}}],"annots":["%metadata"]}},
+                        {{"prim":"nat","annots":["%counter"]}}]}}]}},
+                    {{"prim":"code","args":[[]]}}],
+                "storage":{{"prim":"Pair","args":[{{"int":"{}"}},{{"int":"0"}}]}}}}"#,
+                big_map_id
+            );
+            let script_path = format!(
+                "/chains/main/blocks/head/context/contracts/{}/script",
+                contract
+            );
+            self.responses.insert(script_path, script);
+
+            let key_type = Micheline::prim("string", vec![], vec![]);
+            for (key, value) in entries {
+                let script_expr = script_expr_hash(&key_type, &Micheline::string(*key)).unwrap();
+                let path = format!(
+                    "/chains/main/blocks/head/context/big_maps/{}/{}",
+                    big_map_id, script_expr
+                );
+                self.responses
+                    .insert(path, json!(Micheline::bytes(value)).to_string());
+            }
+            self
+        }
+
+        /// Serves the node on a random local port, returning its URL.
+        async fn serve(self) -> Url {
+            test_server::serve(
+                move |request| match self.responses.get(request.target.path()) {
+                    Some(body) => ("200 OK", body.clone()),
+                    None => ("404 Not Found", String::new()),
+                },
+            )
+            .await
+        }
+    }
+
+    fn resolver(client: &RpcClient) -> MetadataResolver<'_, LocalFetcher> {
+        let mut files = HashMap::new();
+        files.insert(
+            "ipfs://QmTestMetadata".to_string(),
+            METADATA.as_bytes().to_vec(),
+        );
+        MetadataResolver::new(client, LocalFetcher { files }, Chain::Main, Block::Head)
+    }
+
+    #[tokio::test]
+    async fn fetch_sha256_checked_uri_ok() {
+        let client = RpcClient::new(Url::parse("http://localhost:8732/").unwrap());
+        let hash = hex::encode(Sha256::digest(METADATA.as_bytes()));
+        let uri = format!("sha256://0x{}/ipfs:%2F%2FQmTestMetadata", hash);
+
+        let content = resolver(&client).fetch_uri("KT1", &uri).await.unwrap();
+        let metadata = ContractMetadata::from_response_str(std::str::from_utf8(&content).unwrap());
+        assert_eq!(metadata.unwrap().name.unwrap(), "Test contract");
+    }
+
+    #[tokio::test]
+    async fn fetch_sha256_uri_with_wrong_hash_fails() {
+        let client = RpcClient::new(Url::parse("http://localhost:8732/").unwrap());
+        let uri = format!("sha256://0x{}/ipfs:%2F%2FQmTestMetadata", "00".repeat(32));
+
+        assert!(resolver(&client).fetch_uri("KT1", &uri).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn resolve_tezos_storage_uri_ok() {
+        let url = LocalNode::new()
+            .with_contract(
+                CONTRACT,
+                10,
+                &[("", b"tezos-storage:here"), ("here", METADATA.as_bytes())],
+            )
+            .serve()
+            .await;
+        let client = RpcClient::new(url);
+
+        let metadata = resolver(&client).resolve(CONTRACT).await.unwrap();
+        assert_eq!(metadata.name.unwrap(), "Test contract");
+    }
+
+    #[tokio::test]
+    async fn resolve_cross_contract_tezos_storage_uri_ok() {
+        for network in ["", ".NetXdQprcVkpaWU", ".mainnet"] {
+            let root_uri = format!("tezos-storage://{}{}/%2Fdata", OTHER_CONTRACT, network);
+            let url = LocalNode::new()
+                .with_contract(CONTRACT, 10, &[("", root_uri.as_bytes())])
+                .with_contract(OTHER_CONTRACT, 11, &[("/data", METADATA.as_bytes())])
+                .serve()
+                .await;
+            let client = RpcClient::new(url);
+
+            let metadata = resolver(&client).resolve(CONTRACT).await.unwrap();
+            assert_eq!(metadata.name.unwrap(), "Test contract");
+        }
+    }
+
+    #[tokio::test]
+    async fn resolve_tezos_storage_uri_of_other_network_fails() {
+        for network in [".NetXnHfVqm9iesp", ".ghostnet"] {
+            let root_uri = format!("tezos-storage://{}{}/data", OTHER_CONTRACT, network);
+            let url = LocalNode::new()
+                .with_contract(CONTRACT, 10, &[("", root_uri.as_bytes())])
+                .with_contract(OTHER_CONTRACT, 11, &[("data", METADATA.as_bytes())])
+                .serve()
+                .await;
+            let client = RpcClient::new(url);
+
+            let error = resolver(&client).resolve(CONTRACT).await.unwrap_err();
+            assert!(error.to_string().contains("stored on network"));
+        }
+    }
+}
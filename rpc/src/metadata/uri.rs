@@ -0,0 +1,131 @@
+use crate::errors::ParseError;
+use percent_encoding::percent_decode_str;
+
+const TEZOS_STORAGE_SCHEME: &str = "tezos-storage:";
+const SHA256_SCHEME: &str = "sha256://0x";
+
+/// A TZIP-16 metadata URI, as found under the empty key of a contract's
+/// `%metadata` big map.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MetadataUri {
+    /// `tezos-storage:[//<contract>[.<network>]/]<key>`: a value of the
+    /// `%metadata` big map of `contract`, or of the current contract when
+    /// `None`.
+    TezosStorage {
+        contract: Option<String>,
+        network: Option<String>,
+        key: String,
+    },
+    /// `sha256://0x<hash>/<uri>`: content of `uri`, which must hash to
+    /// `hash`.
+    Sha256 {
+        hash: Vec<u8>,
+        uri: Box<MetadataUri>,
+    },
+    /// Any other URI (`https://`, `ipfs://`...), left to a
+    /// [`UriFetcher`](super::UriFetcher).
+    External(String),
+}
+
+impl MetadataUri {
+    pub fn parse(uri: &str) -> Result<Self, ParseError> {
+        if let Some(location) = uri.strip_prefix(TEZOS_STORAGE_SCHEME) {
+            parse_tezos_storage(location)
+        } else if let Some(location) = uri.strip_prefix(SHA256_SCHEME) {
+            let (hash, inner_uri) = location.split_once('/').ok_or_else(|| invalid_uri(uri))?;
+            let hash = hex::decode(hash).map_err(|_| invalid_uri(uri))?;
+            let inner_uri = percent_decode(inner_uri)?;
+            Ok(Self::Sha256 {
+                hash,
+                uri: Box::new(Self::parse(&inner_uri)?),
+            })
+        } else if uri.contains(':') {
+            Ok(Self::External(uri.to_string()))
+        } else {
+            Err(invalid_uri(uri))
+        }
+    }
+}
+
+fn parse_tezos_storage(location: &str) -> Result<MetadataUri, ParseError> {
+    let (host, key) = match location.strip_prefix("//") {
+        Some(authority_and_path) => {
+            let (host, path) = authority_and_path
+                .split_once('/')
+                .ok_or_else(|| invalid_uri(location))?;
+            (Some(host), path)
+        }
+        None => (None, location),
+    };
+
+    let (contract, network) = match host.map(|host| host.split_once('.')) {
+        Some(Some((contract, network))) => (Some(contract.to_string()), Some(network.to_string())),
+        Some(None) => (host.map(String::from), None),
+        None => (None, None),
+    };
+
+    Ok(MetadataUri::TezosStorage {
+        contract,
+        network,
+        key: percent_decode(key)?,
+    })
+}
+
+fn percent_decode(encoded: &str) -> Result<String, ParseError> {
+    percent_decode_str(encoded)
+        .decode_utf8()
+        .map(|decoded| decoded.into_owned())
+        .map_err(|error| ParseError::ResponseParsingError(error.to_string()))
+}
+
+fn invalid_uri(uri: &str) -> ParseError {
+    ParseError::ResponseParsingError(format!("{} is not a valid TZIP-16 metadata URI", uri))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_local_tezos_storage_uri_ok() {
+        assert_eq!(
+            MetadataUri::parse("tezos-storage:here").unwrap(),
+            MetadataUri::TezosStorage {
+                contract: None,
+                network: None,
+                key: "here".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_remote_tezos_storage_uri_ok() {
+        let uri = "tezos-storage://KT1QDFEu8JijYbsJqzoXq7mKvfaQQamHD1kX.NetXdQprcVkpaWU/%2Ffoo";
+        assert_eq!(
+            MetadataUri::parse(uri).unwrap(),
+            MetadataUri::TezosStorage {
+                contract: Some("KT1QDFEu8JijYbsJqzoXq7mKvfaQQamHD1kX".to_string()),
+                network: Some("NetXdQprcVkpaWU".to_string()),
+                key: "/foo".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_sha256_uri_ok() {
+        let uri = "sha256://0xeaa42ea06b95d7917d22135a630e65352cfd0a721ae88155a1512468a95cb750/https:%2F%2Ftezos.com";
+        match MetadataUri::parse(uri).unwrap() {
+            MetadataUri::Sha256 { hash, uri } => {
+                assert_eq!(hash.len(), 32);
+                assert_eq!(*uri, MetadataUri::External("https://tezos.com".to_string()));
+            }
+            other => panic!("unexpected uri {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_uri_without_scheme_fails() {
+        assert!(MetadataUri::parse("metadata.json").is_err());
+        assert!(MetadataUri::parse("sha256://0xzz/https:%2F%2Ftezos.com").is_err());
+    }
+}
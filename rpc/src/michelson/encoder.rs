@@ -0,0 +1,541 @@
+use super::micheline::{Micheline, MichelinePrim};
+use super::pack::to_optimized;
+use super::value::{count_variants, split_comb, type_arg, MichelsonValue};
+use crate::errors::ParseError;
+use num_bigint::BigInt;
+use serde_json::Value;
+use std::cmp::Ordering;
+
+/// Encodes a JSON-like argument into a Micheline value of type `ty`.
+///
+/// This is the inverse of [`MichelsonValue::to_json`]: records are given as
+/// objects keyed by field annotation (or as arrays for unannotated pairs),
+/// variants as single-key objects, units as `{}` (or `null`), options as
+/// `null` or their inner value (`{"Some": ..}` when nested),
+/// maps as `[{ "key": .., "value": .. }]` or as objects with string keys,
+/// and numbers either as JSON numbers or decimal strings.
+pub fn encode_json(ty: &Micheline, argument: &Value) -> Result<Micheline, ParseError> {
+    let ty_prim = ty
+        .as_prim()
+        .ok_or_else(|| encode_error("type expression is not a primitive", argument))?;
+
+    let encoded = match ty_prim.prim.as_str() {
+        "unit" => match argument {
+            Value::Null => Micheline::prim("Unit", vec![], vec![]),
+            Value::Object(object) if object.is_empty() => Micheline::prim("Unit", vec![], vec![]),
+            _ => return Err(encode_error("expected unit", argument)),
+        },
+        "bool" => match argument {
+            Value::Bool(true) => Micheline::prim("True", vec![], vec![]),
+            Value::Bool(false) => Micheline::prim("False", vec![], vec![]),
+            _ => return Err(encode_error("expected a boolean", argument)),
+        },
+        "int" => Micheline::int(expect_int(argument)?),
+        "nat" | "mutez" => {
+            let natural = expect_int(argument)?;
+            if natural < BigInt::from(0) {
+                return Err(encode_error("expected a non-negative integer", argument));
+            }
+            if ty_prim.prim == "mutez" && natural > BigInt::from(i64::MAX) {
+                return Err(encode_error("mutez amount is too large", argument));
+            }
+            Micheline::int(natural)
+        }
+        "string" | "address" | "contract" | "key" | "key_hash" | "signature" | "chain_id" => {
+            Micheline::string(expect_str(argument)?)
+        }
+        "bytes" | "bls12_381_g1" | "bls12_381_g2" | "bls12_381_fr" | "chest" | "chest_key" => {
+            let hex_str = expect_str(argument)?;
+            let bytes = hex::decode(hex_str.trim_start_matches("0x"))
+                .map_err(|_| encode_error("expected a hex string", argument))?;
+            Micheline::bytes(&bytes)
+        }
+        "timestamp" => match argument {
+            Value::String(timestamp) => Micheline::string(timestamp.as_str()),
+            _ => Micheline::int(expect_int(argument)?),
+        },
+        "option" => {
+            let inner_ty = type_arg(ty_prim, 0)?;
+            let inner_argument = match argument {
+                Value::Null => None,
+                Value::Object(object) if inner_ty.is_prim("option") => {
+                    let inner_argument = object
+                        .get("Some")
+                        .filter(|_| object.len() == 1)
+                        .ok_or_else(|| encode_error("expected null or Some", argument))?;
+                    Some(inner_argument)
+                }
+                _ => Some(argument),
+            };
+            match inner_argument {
+                Some(inner_argument) => {
+                    let inner = encode_json(inner_ty, inner_argument)?;
+                    Micheline::prim("Some", vec![inner], vec![])
+                }
+                None => Micheline::prim("None", vec![], vec![]),
+            }
+        }
+        "pair" => encode_pair(ty_prim, argument)?,
+        "or" => encode_or(ty_prim, argument)?,
+        "list" | "set" => {
+            let item_ty = type_arg(ty_prim, 0)?;
+            let items = expect_array(argument)?
+                .iter()
+                .map(|item| Ok((encode_json(item_ty, item)?, ())))
+                .collect::<Result<Vec<_>, ParseError>>()?;
+            let items = if ty_prim.prim == "set" {
+                sort_by_key(item_ty, items)?
+            } else {
+                items
+            };
+            Micheline::Seq(items.into_iter().map(|(item, _)| item).collect())
+        }
+        "map" | "big_map" => match argument {
+            Value::Number(_) | Value::String(_) if ty_prim.prim == "big_map" => {
+                Micheline::int(expect_int(argument)?)
+            }
+            _ => encode_map(ty_prim, argument)?,
+        },
+        _ => serde_json::from_value(argument.clone())
+            .map_err(|_| encode_error("expected a raw Micheline expression", argument))?,
+    };
+
+    Ok(encoded)
+}
+
+impl MichelsonValue {
+    /// Encodes a typed value back into a Micheline value of type `ty`.
+    pub fn encode(&self, ty: &Micheline) -> Result<Micheline, ParseError> {
+        encode_json(ty, &self.to_json())
+    }
+}
+
+fn encode_pair(ty: &MichelinePrim, argument: &Value) -> Result<Micheline, ParseError> {
+    let mut fields = Vec::new();
+    collect_pair_field_types(ty, &mut fields)?;
+
+    let values = match argument {
+        Value::Array(values) if values.len() == fields.len() => values.iter().collect(),
+        Value::Object(object) => fields
+            .iter()
+            .map(|(name, _)| {
+                name.and_then(|name| object.get(name))
+                    .ok_or_else(|| encode_error("record is missing a field", argument))
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        _ => {
+            return Err(encode_error(
+                "expected an object or array for pair",
+                argument,
+            ))
+        }
+    };
+
+    let mut values = values.into_iter();
+    build_pair(ty, &mut values)
+}
+
+fn collect_pair_field_types<'a>(
+    ty: &'a MichelinePrim,
+    fields: &mut Vec<(Option<&'a str>, &'a Micheline)>,
+) -> Result<(), ParseError> {
+    let comb_error =
+        || ParseError::RequestParsingError("pair type needs two arguments".to_string());
+    let (left, rest) = ty.args.split_first().ok_or_else(comb_error)?;
+    if rest.is_empty() {
+        return Err(comb_error());
+    }
+
+    for side_ty in std::iter::once(left).chain(rest.iter()) {
+        match side_ty.as_prim() {
+            Some(prim) if prim.prim == "pair" && prim.field_annot().is_none() => {
+                collect_pair_field_types(prim, fields)?
+            }
+            Some(prim) => fields.push((prim.field_annot(), side_ty)),
+            None => {
+                let detail = "type expression is not a primitive".to_string();
+                return Err(ParseError::RequestParsingError(detail));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn build_pair<'a, I: Iterator<Item = &'a Value>>(
+    ty: &MichelinePrim,
+    values: &mut I,
+) -> Result<Micheline, ParseError> {
+    let (left_ty, right_ty) = split_comb(&ty.args, "pair").ok_or_else(|| {
+        ParseError::RequestParsingError("pair type needs two arguments".to_string())
+    })?;
+
+    let mut components = Vec::new();
+    for side_ty in [left_ty, right_ty].iter() {
+        let component = match side_ty.as_prim() {
+            Some(prim) if prim.prim == "pair" && prim.field_annot().is_none() => {
+                build_pair(prim, values)?
+            }
+            _ => {
+                let value = values.next().ok_or_else(|| {
+                    ParseError::RequestParsingError("not enough values for pair".to_string())
+                })?;
+                encode_json(side_ty, value)?
+            }
+        };
+        components.push(component);
+    }
+
+    Ok(Micheline::prim("Pair", components, vec![]))
+}
+
+fn encode_or(ty: &MichelinePrim, argument: &Value) -> Result<Micheline, ParseError> {
+    let (name, inner_argument) = match argument {
+        Value::Object(object) if object.len() == 1 => object.iter().next().unwrap(),
+        _ => {
+            return Err(encode_error(
+                "expected a single-key object for or",
+                argument,
+            ))
+        }
+    };
+
+    let (path, branch_ty) =
+        find_variant(ty, name, 0).ok_or_else(|| encode_error("unknown variant", argument))?;
+    let value = encode_json(branch_ty, inner_argument)?;
+    Ok(wrap_in_branches(value, &path))
+}
+
+/// Finds the `or` branch named `name`, returning the `Left`/`Right` path to
+/// it (`true` meaning `Left`) and its type.
+fn find_variant<'a>(
+    ty: &'a MichelinePrim,
+    name: &str,
+    first_index: usize,
+) -> Option<(Vec<bool>, &'a Micheline)> {
+    let left_ty = ty.args.first()?;
+    let right_ty = ty.args.get(1)?;
+    let right_index = first_index + count_variants(left_ty);
+
+    for (is_left, branch_ty, index) in
+        [(true, left_ty, first_index), (false, right_ty, right_index)]
+            .iter()
+            .copied()
+    {
+        let branch_prim = branch_ty.as_prim()?;
+        let found = if branch_prim.prim == "or" && branch_prim.field_annot().is_none() {
+            find_variant(branch_prim, name, index)
+        } else {
+            match branch_prim.field_annot() {
+                Some(annot) if annot == name => Some((vec![], branch_ty)),
+                None if index.to_string() == name => Some((vec![], branch_ty)),
+                _ => None,
+            }
+        };
+
+        if let Some((mut path, found_ty)) = found {
+            path.insert(0, is_left);
+            return Some((path, found_ty));
+        }
+    }
+    None
+}
+
+/// Wraps `value` in `Left`/`Right` constructors following `path`, outermost
+/// branch first.
+pub(super) fn wrap_in_branches(value: Micheline, path: &[bool]) -> Micheline {
+    path.iter().rev().fold(value, |wrapped, is_left| {
+        let prim = if *is_left { "Left" } else { "Right" };
+        Micheline::prim(prim, vec![wrapped], vec![])
+    })
+}
+
+fn encode_map(ty: &MichelinePrim, argument: &Value) -> Result<Micheline, ParseError> {
+    let key_ty = type_arg(ty, 0)?;
+    let value_ty = type_arg(ty, 1)?;
+
+    let entries = match argument {
+        Value::Array(entries) => entries
+            .iter()
+            .map(|entry| match (entry.get("key"), entry.get("value")) {
+                (Some(key), Some(value)) => {
+                    Ok((encode_json(key_ty, key)?, encode_json(value_ty, value)?))
+                }
+                _ => Err(encode_error("expected a key and a value", entry)),
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        Value::Object(object) => object
+            .iter()
+            .map(|(key, value)| {
+                let key = encode_json(key_ty, &Value::String(key.clone()))?;
+                Ok((key, encode_json(value_ty, value)?))
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?,
+        _ => {
+            return Err(encode_error(
+                "expected an array or object for map",
+                argument,
+            ))
+        }
+    };
+
+    let elts = sort_by_key(key_ty, entries)?
+        .into_iter()
+        .map(|(key, value)| Micheline::prim("Elt", vec![key, value], vec![]))
+        .collect();
+    Ok(Micheline::Seq(elts))
+}
+
+/// Sorts `(key, item)` pairs by key, in the order Michelson requires for map
+/// keys and set elements: the keys' optimized forms, as used by `PACK`,
+/// compared according to the comparable type `key_ty`.
+///
+/// Comparing optimized forms puts implicit accounts before originated
+/// contracts and orders timestamps by time rather than by their text.
+fn sort_by_key<T>(
+    key_ty: &Micheline,
+    pairs: Vec<(Micheline, T)>,
+) -> Result<Vec<(Micheline, T)>, ParseError> {
+    let mut keyed = pairs
+        .into_iter()
+        .map(|(key, item)| Ok((to_optimized(key_ty, &key)?, key, item)))
+        .collect::<Result<Vec<_>, ParseError>>()?;
+    keyed.sort_by(|(left, _, _), (right, _, _)| compare_values(key_ty, left, right));
+    Ok(keyed
+        .into_iter()
+        .map(|(_, key, item)| (key, item))
+        .collect())
+}
+
+/// Compares two optimized values of the comparable type `ty`: pairs
+/// lexicographically, `None < Some`, `Left < Right`, `False < True`, and
+/// other values by number, string or bytes.
+fn compare_values(ty: &Micheline, left: &Micheline, right: &Micheline) -> Ordering {
+    let ty_prim = match ty.as_prim() {
+        Some(ty_prim) => ty_prim,
+        None => return Ordering::Equal,
+    };
+
+    match (ty_prim.prim.as_str(), left, right) {
+        ("pair", _, _) => {
+            let components = |value: &Micheline| match value {
+                Micheline::Prim(prim) if prim.prim == "Pair" => split_comb(&prim.args, "Pair"),
+                Micheline::Seq(items) => split_comb(items, "Pair"),
+                _ => None,
+            };
+            match (
+                split_comb(&ty_prim.args, "pair"),
+                components(left),
+                components(right),
+            ) {
+                (Some((left_ty, right_ty)), Some((left_1, left_2)), Some((right_1, right_2))) => {
+                    compare_values(&left_ty, &left_1, &right_1)
+                        .then_with(|| compare_values(&right_ty, &left_2, &right_2))
+                }
+                _ => Ordering::Equal,
+            }
+        }
+        ("option", Micheline::Prim(left), Micheline::Prim(right))
+        | ("or", Micheline::Prim(left), Micheline::Prim(right))
+        | ("bool", Micheline::Prim(left), Micheline::Prim(right)) => {
+            let is_greater_constructor = |prim: &str| matches!(prim, "Some" | "Right" | "True");
+            let branch = if left.prim == "Right" { 1 } else { 0 };
+            is_greater_constructor(&left.prim)
+                .cmp(&is_greater_constructor(&right.prim))
+                .then_with(|| match (left.args.first(), right.args.first()) {
+                    (Some(left_arg), Some(right_arg)) => match type_arg(ty_prim, branch) {
+                        Ok(arg_ty) => compare_values(arg_ty, left_arg, right_arg),
+                        Err(_) => Ordering::Equal,
+                    },
+                    _ => Ordering::Equal,
+                })
+        }
+        (_, Micheline::Int { .. }, Micheline::Int { .. }) => left.as_int().cmp(&right.as_int()),
+        (_, Micheline::String { string: left }, Micheline::String { string: right }) => {
+            left.cmp(right)
+        }
+        (_, Micheline::Bytes { .. }, Micheline::Bytes { .. }) => {
+            left.as_bytes().cmp(&right.as_bytes())
+        }
+        _ => Ordering::Equal,
+    }
+}
+
+fn expect_int(argument: &Value) -> Result<BigInt, ParseError> {
+    let parsed = match argument {
+        Value::Number(number) => number.to_string().parse().ok(),
+        Value::String(string) => string.parse().ok(),
+        _ => None,
+    };
+    parsed.ok_or_else(|| encode_error("expected an integer", argument))
+}
+
+fn expect_str(argument: &Value) -> Result<&str, ParseError> {
+    argument
+        .as_str()
+        .ok_or_else(|| encode_error("expected a string", argument))
+}
+
+fn expect_array(argument: &Value) -> Result<&Vec<Value>, ParseError> {
+    argument
+        .as_array()
+        .ok_or_else(|| encode_error("expected an array", argument))
+}
+
+fn encode_error(detail: &str, argument: &Value) -> ParseError {
+    let detail = format!("{}, found {}", detail, argument);
+    ParseError::RequestParsingError(detail)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    fn parse(micheline_str: &str) -> Micheline {
+        serde_json::from_str(micheline_str).unwrap()
+    }
+
+    #[test]
+    fn encode_record_into_right_comb_ok() {
+        let ty = parse(
+            r#"{"prim":"pair","args":[
+                {"prim":"address","annots":["%from_"]},
+                {"prim":"list","args":[{"prim":"pair","args":[
+                    {"prim":"address","annots":["%to_"]},
+                    {"prim":"nat","annots":["%token_id"]},
+                    {"prim":"nat","annots":["%amount"]}
+                ]}],"annots":["%txs"]}
+            ]}"#,
+        );
+        let argument = json!({
+            "from_": "tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx",
+            "txs": [{"to_": "tz1VkjQjvH3NTVdFtGz9tWutgxLKwnhnMe2x", "token_id": 0, "amount": "10"}]
+        });
+
+        let encoded = encode_json(&ty, &argument).unwrap();
+        let expected = parse(
+            r#"{"prim":"Pair","args":[
+                {"string":"tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx"},
+                [{"prim":"Pair","args":[
+                    {"string":"tz1VkjQjvH3NTVdFtGz9tWutgxLKwnhnMe2x"},
+                    {"prim":"Pair","args":[{"int":"0"},{"int":"10"}]}
+                ]}]
+            ]}"#,
+        );
+        assert_eq!(encoded, expected);
+    }
+
+    #[test]
+    fn encode_variant_wraps_branches_ok() {
+        let ty = parse(
+            r#"{"prim":"or","args":[
+                {"prim":"unit","annots":["%pause"]},
+                {"prim":"or","args":[{"prim":"nat","annots":["%mint"]},{"prim":"nat","annots":["%burn"]}]}
+            ]}"#,
+        );
+
+        let encoded = encode_json(&ty, &json!({"burn": 5})).unwrap();
+        assert_eq!(
+            encoded,
+            parse(r#"{"prim":"Right","args":[{"prim":"Right","args":[{"int":"5"}]}]}"#)
+        );
+    }
+
+    #[test]
+    fn encode_negative_nat_and_mutez_fails() {
+        let nat = parse(r#"{"prim":"nat"}"#);
+        let mutez = parse(r#"{"prim":"mutez"}"#);
+
+        assert!(encode_json(&nat, &json!(-1)).is_err());
+        assert!(encode_json(&mutez, &json!("-5")).is_err());
+        assert!(encode_json(&mutez, &json!("9223372036854775808")).is_err());
+        assert_eq!(encode_json(&nat, &json!(0)).unwrap(), Micheline::int(0));
+        assert!(encode_json(&parse(r#"{"prim":"int"}"#), &json!(-1)).is_ok());
+    }
+
+    #[test]
+    fn encode_map_sorts_keys_ok() {
+        let ty = parse(r#"{"prim":"map","args":[{"prim":"string"},{"prim":"nat"}]}"#);
+
+        let encoded = encode_json(&ty, &json!({"b": 2, "a": 1})).unwrap();
+        assert_eq!(
+            encoded,
+            parse(
+                r#"[{"prim":"Elt","args":[{"string":"a"},{"int":"1"}]},{"prim":"Elt","args":[{"string":"b"},{"int":"2"}]}]"#
+            )
+        );
+    }
+
+    #[test]
+    fn encode_map_sorts_addresses_by_binary_form_ok() {
+        let ty = parse(r#"{"prim":"map","args":[{"prim":"address"},{"prim":"nat"}]}"#);
+        let argument = json!({
+            "KT1Hkg5qeNhfwpKW4fXvq7HGZB9z2EnmCCA9": 1,
+            "tz1VkjQjvH3NTVdFtGz9tWutgxLKwnhnMe2x": 2,
+            "tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx": 3,
+        });
+
+        let encoded = encode_json(&ty, &argument).unwrap();
+        let keys: Vec<String> = match encoded {
+            Micheline::Seq(elts) => elts
+                .iter()
+                .map(|elt| elt.as_prim().unwrap().args[0].as_str().unwrap().to_string())
+                .collect(),
+            _ => panic!("expected a sequence"),
+        };
+        assert_eq!(
+            keys,
+            [
+                "tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx",
+                "tz1VkjQjvH3NTVdFtGz9tWutgxLKwnhnMe2x",
+                "KT1Hkg5qeNhfwpKW4fXvq7HGZB9z2EnmCCA9",
+            ]
+        );
+    }
+
+    #[test]
+    fn encode_set_sorts_pairs_and_timestamps_ok() {
+        let ty = parse(
+            r#"{"prim":"set","args":[{"prim":"pair","args":[{"prim":"nat"},{"prim":"option","args":[{"prim":"timestamp"}]}]}]}"#,
+        );
+        let argument = json!([
+            [10, "1970-01-01T00:00:02Z"],
+            [2, "1970-01-01T00:00:10Z"],
+            [2, "1970-01-01T00:00:09+00:00"],
+            [2, null],
+        ]);
+
+        let encoded = encode_json(&ty, &argument).unwrap();
+        let expected = parse(
+            r#"[
+                {"prim":"Pair","args":[{"int":"2"},{"prim":"None"}]},
+                {"prim":"Pair","args":[{"int":"2"},{"prim":"Some","args":[{"string":"1970-01-01T00:00:09+00:00"}]}]},
+                {"prim":"Pair","args":[{"int":"2"},{"prim":"Some","args":[{"string":"1970-01-01T00:00:10Z"}]}]},
+                {"prim":"Pair","args":[{"int":"10"},{"prim":"Some","args":[{"string":"1970-01-01T00:00:02Z"}]}]}
+            ]"#,
+        );
+        assert_eq!(encoded, expected);
+    }
+
+    #[test]
+    fn decode_then_encode_round_trip_ok() {
+        let ty = parse(
+            r#"{"prim":"pair","args":[{"prim":"option","args":[{"prim":"nat"}],"annots":["%limit"]},{"prim":"bool","annots":["%paused"]}]}"#,
+        );
+        let value = parse(
+            r#"{"prim":"Pair","args":[{"prim":"Some","args":[{"int":"3"}]},{"prim":"False"}]}"#,
+        );
+
+        let decoded = MichelsonValue::decode(&ty, &value).unwrap();
+        assert_eq!(decoded.encode(&ty).unwrap(), value);
+    }
+
+    #[test]
+    fn encode_missing_record_field_fails() {
+        let ty = parse(
+            r#"{"prim":"pair","args":[{"prim":"nat","annots":["%a"]},{"prim":"nat","annots":["%b"]}]}"#,
+        );
+
+        assert!(encode_json(&ty, &json!({"a": 1})).is_err());
+    }
+}
@@ -0,0 +1,249 @@
+use super::encoder::{encode_json, wrap_in_branches};
+use super::micheline::Micheline;
+use super::value::MichelsonValue;
+use crate::errors::ParseError;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::fmt;
+
+const DEFAULT_ENTRYPOINT: &str = "default";
+
+/// The `parameters` field of a transaction: the entrypoint to call and the
+/// Micheline argument passed to it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TransactionParameters {
+    pub entrypoint: String,
+    pub value: Micheline,
+}
+
+impl fmt::Display for TransactionParameters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+/// An entrypoint found in a contract's parameter type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entrypoint {
+    pub name: String,
+    pub parameter_type: Micheline,
+    /// `Left`/`Right` branches leading to this entrypoint from the root of
+    /// the parameter type, `true` meaning `Left`.
+    pub path: Vec<bool>,
+}
+
+/// Builds [`TransactionParameters`] for calls to a contract, from the
+/// contract's `parameter` type.
+///
+/// Entrypoints are the `%annotated` branches of the parameter's `or` tree.
+/// `%default` resolves to the branch annotated as such if there is one, and
+/// to the whole parameter otherwise.
+pub struct ParameterBuilder {
+    entrypoints: Vec<Entrypoint>,
+}
+
+impl ParameterBuilder {
+    pub fn new(parameter_type: &Micheline) -> Self {
+        let mut entrypoints = Vec::new();
+        collect_entrypoints(parameter_type, &mut Vec::new(), &mut entrypoints);
+
+        let has_default = entrypoints
+            .iter()
+            .any(|entrypoint| entrypoint.name == DEFAULT_ENTRYPOINT);
+        if !has_default {
+            entrypoints.push(Entrypoint {
+                name: DEFAULT_ENTRYPOINT.to_string(),
+                parameter_type: parameter_type.clone(),
+                path: vec![],
+            });
+        }
+
+        Self { entrypoints }
+    }
+
+    pub fn entrypoints(&self) -> &[Entrypoint] {
+        &self.entrypoints
+    }
+
+    pub fn entrypoint(&self, name: &str) -> Option<&Entrypoint> {
+        let name = name.strip_prefix('%').unwrap_or(name);
+        self.entrypoints
+            .iter()
+            .find(|entrypoint| entrypoint.name == name)
+    }
+
+    /// Builds the parameters calling `entrypoint` by name, encoding the
+    /// JSON-like `argument` against the entrypoint's type.
+    pub fn build(
+        &self,
+        entrypoint: &str,
+        argument: &Value,
+    ) -> Result<TransactionParameters, ParseError> {
+        let found = self.find(entrypoint)?;
+        Ok(TransactionParameters {
+            entrypoint: found.name.clone(),
+            value: encode_json(&found.parameter_type, argument)?,
+        })
+    }
+
+    /// Same as [`build`](Self::build), from an already typed argument.
+    pub fn build_typed(
+        &self,
+        entrypoint: &str,
+        argument: &MichelsonValue,
+    ) -> Result<TransactionParameters, ParseError> {
+        self.build(entrypoint, &argument.to_json())
+    }
+
+    /// Builds parameters calling `%default` with the argument wrapped in the
+    /// `Left`/`Right` branches leading to `entrypoint`, for callers that
+    /// cannot target entrypoints by name.
+    pub fn build_wrapped(
+        &self,
+        entrypoint: &str,
+        argument: &Value,
+    ) -> Result<TransactionParameters, ParseError> {
+        let found = self.find(entrypoint)?;
+        let value = encode_json(&found.parameter_type, argument)?;
+
+        let default_path = self
+            .entrypoint(DEFAULT_ENTRYPOINT)
+            .map(|default| default.path.as_slice())
+            .unwrap_or_default();
+        let relative_path = found.path.strip_prefix(default_path).ok_or_else(|| {
+            let detail = format!("entrypoint {} is not reachable from %default", found.name);
+            ParseError::RequestParsingError(detail)
+        })?;
+
+        Ok(TransactionParameters {
+            entrypoint: DEFAULT_ENTRYPOINT.to_string(),
+            value: wrap_in_branches(value, relative_path),
+        })
+    }
+
+    fn find(&self, entrypoint: &str) -> Result<&Entrypoint, ParseError> {
+        self.entrypoint(entrypoint).ok_or_else(|| {
+            let detail = format!("contract has no entrypoint named {}", entrypoint);
+            ParseError::RequestParsingError(detail)
+        })
+    }
+}
+
+fn collect_entrypoints(ty: &Micheline, path: &mut Vec<bool>, entrypoints: &mut Vec<Entrypoint>) {
+    let prim = match ty.as_prim() {
+        Some(prim) => prim,
+        None => return,
+    };
+
+    if let Some(name) = prim.field_annot() {
+        entrypoints.push(Entrypoint {
+            name: name.to_string(),
+            parameter_type: ty.clone(),
+            path: path.clone(),
+        });
+    }
+
+    if prim.prim == "or" {
+        for (is_left, branch_ty) in [true, false].iter().zip(prim.args.iter()) {
+            path.push(*is_left);
+            collect_entrypoints(branch_ty, path, entrypoints);
+            path.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn fa2_parameter_type() -> Micheline {
+        serde_json::from_str(
+            r#"{"prim":"or","args":[
+                {"prim":"or","args":[
+                    {"prim":"pair","args":[
+                        {"prim":"list","args":[{"prim":"pair","args":[{"prim":"address","annots":["%owner"]},{"prim":"nat","annots":["%token_id"]}]}],"annots":["%requests"]},
+                        {"prim":"contract","args":[{"prim":"list","args":[{"prim":"pair","args":[{"prim":"pair","args":[{"prim":"address","annots":["%owner"]},{"prim":"nat","annots":["%token_id"]}],"annots":["%request"]},{"prim":"nat","annots":["%balance"]}]}]}],"annots":["%callback"]}
+                    ],"annots":["%balance_of"]},
+                    {"prim":"list","args":[{"prim":"pair","args":[
+                        {"prim":"address","annots":["%from_"]},
+                        {"prim":"list","args":[{"prim":"pair","args":[{"prim":"address","annots":["%to_"]},{"prim":"nat","annots":["%token_id"]},{"prim":"nat","annots":["%amount"]}]}],"annots":["%txs"]}
+                    ]}],"annots":["%transfer"]}
+                ]},
+                {"prim":"list","args":[{"prim":"or","args":[
+                    {"prim":"pair","args":[{"prim":"address","annots":["%owner"]},{"prim":"address","annots":["%operator"]},{"prim":"nat","annots":["%token_id"]}],"annots":["%add_operator"]},
+                    {"prim":"pair","args":[{"prim":"address","annots":["%owner"]},{"prim":"address","annots":["%operator"]},{"prim":"nat","annots":["%token_id"]}],"annots":["%remove_operator"]}
+                ]}],"annots":["%update_operators"]}
+            ]}"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn list_fa2_entrypoints_ok() {
+        let builder = ParameterBuilder::new(&fa2_parameter_type());
+
+        let names: Vec<&str> = builder
+            .entrypoints()
+            .iter()
+            .map(|entrypoint| entrypoint.name.as_str())
+            .collect();
+        assert_eq!(
+            names,
+            ["balance_of", "transfer", "update_operators", "default"]
+        );
+        assert_eq!(builder.entrypoint("%transfer").unwrap().path, [true, false]);
+    }
+
+    #[test]
+    fn build_update_operators_by_name_ok() {
+        let builder = ParameterBuilder::new(&fa2_parameter_type());
+        let argument = json!([{"add_operator": {
+            "owner": "tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx",
+            "operator": "tz1VkjQjvH3NTVdFtGz9tWutgxLKwnhnMe2x",
+            "token_id": 0
+        }}]);
+
+        let parameters = builder.build("update_operators", &argument).unwrap();
+        assert_eq!(parameters.entrypoint, "update_operators");
+
+        let expected: Micheline = serde_json::from_str(
+            r#"[{"prim":"Left","args":[{"prim":"Pair","args":[
+                {"string":"tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx"},
+                {"prim":"Pair","args":[{"string":"tz1VkjQjvH3NTVdFtGz9tWutgxLKwnhnMe2x"},{"int":"0"}]}
+            ]}]}]"#,
+        )
+        .unwrap();
+        assert_eq!(parameters.value, expected);
+    }
+
+    #[test]
+    fn build_wrapped_transfer_ok() {
+        let builder = ParameterBuilder::new(&fa2_parameter_type());
+
+        let parameters = builder.build_wrapped("transfer", &json!([])).unwrap();
+        assert_eq!(parameters.entrypoint, "default");
+        assert_eq!(
+            parameters.value.to_string(),
+            r#"{"prim":"Left","args":[{"prim":"Right","args":[[]]}]}"#
+        );
+    }
+
+    #[test]
+    fn explicit_default_entrypoint_is_used_ok() {
+        let ty: Micheline = serde_json::from_str(
+            r#"{"prim":"or","args":[{"prim":"unit","annots":["%default"]},{"prim":"nat","annots":["%set"]}]}"#,
+        )
+        .unwrap();
+        let builder = ParameterBuilder::new(&ty);
+
+        let parameters = builder.build("default", &Value::Null).unwrap();
+        assert_eq!(parameters.value.to_string(), r#"{"prim":"Unit"}"#);
+        assert!(builder.build_wrapped("set", &json!(1)).is_err());
+    }
+
+    #[test]
+    fn build_unknown_entrypoint_fails() {
+        let builder = ParameterBuilder::new(&fa2_parameter_type());
+        assert!(builder.build("mint", &json!(1)).is_err());
+    }
+}
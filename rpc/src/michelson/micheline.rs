@@ -0,0 +1,160 @@
+use crate::errors::ParseError;
+use crate::responses::Response;
+use num_bigint::BigInt;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::fmt;
+
+/// A Micheline expression, in the JSON representation used by the node.
+///
+/// This is the untyped syntax tree shared by Michelson types, values and
+/// code. See [`MichelsonValue`](super::MichelsonValue) to decode a value
+/// against its type.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum Micheline {
+    Int { int: String },
+    String { string: String },
+    Bytes { bytes: String },
+    Prim(MichelinePrim),
+    Seq(Vec<Micheline>),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct MichelinePrim {
+    pub prim: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<Micheline>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annots: Vec<String>,
+}
+
+impl Micheline {
+    pub fn int<T: Into<BigInt>>(int: T) -> Self {
+        Self::Int {
+            int: int.into().to_string(),
+        }
+    }
+
+    pub fn string<T: Into<String>>(string: T) -> Self {
+        Self::String {
+            string: string.into(),
+        }
+    }
+
+    pub fn bytes(bytes: &[u8]) -> Self {
+        Self::Bytes {
+            bytes: hex::encode(bytes),
+        }
+    }
+
+    pub fn prim(prim: &str, args: Vec<Micheline>, annots: Vec<String>) -> Self {
+        Self::Prim(MichelinePrim {
+            prim: prim.to_string(),
+            args,
+            annots,
+        })
+    }
+
+    pub fn as_prim(&self) -> Option<&MichelinePrim> {
+        match self {
+            Self::Prim(prim) => Some(prim),
+            _ => None,
+        }
+    }
+
+    pub fn as_int(&self) -> Option<BigInt> {
+        match self {
+            Self::Int { int } => int.parse().ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String { string } => Some(string),
+            _ => None,
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<Vec<u8>> {
+        match self {
+            Self::Bytes { bytes } => hex::decode(bytes).ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if this is the primitive `prim`.
+    pub fn is_prim(&self, prim: &str) -> bool {
+        matches!(self, Self::Prim(inner) if inner.prim == prim)
+    }
+}
+
+impl MichelinePrim {
+    /// Returns the first annotation starting with `%`, without the `%`.
+    pub fn field_annot(&self) -> Option<&str> {
+        self.annot_with_prefix('%')
+    }
+
+    /// Returns the first annotation starting with `:`, without the `:`.
+    pub fn type_annot(&self) -> Option<&str> {
+        self.annot_with_prefix(':')
+    }
+
+    fn annot_with_prefix(&self, prefix: char) -> Option<&str> {
+        self.annots
+            .iter()
+            .find_map(|annot| annot.strip_prefix(prefix))
+            .filter(|annot| !annot.is_empty())
+    }
+}
+
+impl fmt::Display for Micheline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for Micheline {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        Ok(serde_json::from_str(response)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_nested_expression_ok() {
+        let mock_response = r#"{"prim":"pair","args":[{"prim":"nat","annots":["%count"]},[{"int":"1"},{"string":"foo"},{"bytes":"00ff"}]]}"#;
+
+        let micheline = Micheline::from_response_str(mock_response).unwrap();
+        let pair = micheline.as_prim().unwrap();
+        assert_eq!(pair.prim, "pair");
+        assert!(pair.annots.is_empty());
+
+        let nat = pair.args[0].as_prim().unwrap();
+        assert_eq!(nat.field_annot(), Some("count"));
+
+        let expected_seq = Micheline::Seq(vec![
+            Micheline::int(1),
+            Micheline::string("foo"),
+            Micheline::bytes(&[0, 255]),
+        ]);
+        assert_eq!(pair.args[1], expected_seq);
+    }
+
+    #[test]
+    fn serialize_round_trip_ok() {
+        let mock_response = r#"{"prim":"Pair","args":[{"int":"-5"},{"prim":"Unit"}]}"#;
+
+        let micheline = Micheline::from_response_str(mock_response).unwrap();
+        assert_eq!(micheline.to_string(), mock_response);
+    }
+
+    #[test]
+    fn parse_non_micheline_fails() {
+        assert!(Micheline::from_response_str(r#"{"foo":"bar"}"#).is_err());
+    }
+}
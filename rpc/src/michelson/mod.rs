@@ -0,0 +1,10 @@
+pub mod encoder;
+pub mod entrypoints;
+pub mod micheline;
+pub mod pack;
+pub mod value;
+pub use encoder::encode_json;
+pub use entrypoints::{Entrypoint, ParameterBuilder, TransactionParameters};
+pub use micheline::{Micheline, MichelinePrim};
+pub use pack::{pack, script_expr_hash};
+pub use value::{BigMapValue, MichelsonValue};
@@ -0,0 +1,575 @@
+//! Binary encoding of Micheline expressions, as done by the `PACK`
+//! instruction and when forging operations.
+
+use super::micheline::{Micheline, MichelinePrim};
+use super::value::{split_comb, type_arg};
+use crate::encoding::reader::Reader;
+use crate::encoding::{base58, forged, hash};
+use crate::errors::ParseError;
+use chrono::DateTime;
+use num_bigint::{BigInt, BigUint, Sign};
+
+/// Michelson primitives, in the order defining their binary opcode.
+pub const PRIMITIVES: [&str; 157] = [
+    "parameter",
+    "storage",
+    "code",
+    "False",
+    "Elt",
+    "Left",
+    "None",
+    "Pair",
+    "Right",
+    "Some",
+    "True",
+    "Unit",
+    "PACK",
+    "UNPACK",
+    "BLAKE2B",
+    "SHA256",
+    "SHA512",
+    "ABS",
+    "ADD",
+    "AMOUNT",
+    "AND",
+    "BALANCE",
+    "CAR",
+    "CDR",
+    "CHECK_SIGNATURE",
+    "COMPARE",
+    "CONCAT",
+    "CONS",
+    "CREATE_ACCOUNT",
+    "CREATE_CONTRACT",
+    "IMPLICIT_ACCOUNT",
+    "DIP",
+    "DROP",
+    "DUP",
+    "EDIV",
+    "EMPTY_MAP",
+    "EMPTY_SET",
+    "EQ",
+    "EXEC",
+    "FAILWITH",
+    "GE",
+    "GET",
+    "GT",
+    "HASH_KEY",
+    "IF",
+    "IF_CONS",
+    "IF_LEFT",
+    "IF_NONE",
+    "INT",
+    "LAMBDA",
+    "LE",
+    "LEFT",
+    "LOOP",
+    "LSL",
+    "LSR",
+    "LT",
+    "MAP",
+    "MEM",
+    "MUL",
+    "NEG",
+    "NEQ",
+    "NIL",
+    "NONE",
+    "NOT",
+    "NOW",
+    "OR",
+    "PAIR",
+    "PUSH",
+    "RIGHT",
+    "SIZE",
+    "SOME",
+    "SOURCE",
+    "SENDER",
+    "SELF",
+    "STEPS_TO_QUOTA",
+    "SUB",
+    "SWAP",
+    "TRANSFER_TOKENS",
+    "SET_DELEGATE",
+    "UNIT",
+    "UPDATE",
+    "XOR",
+    "ITER",
+    "LOOP_LEFT",
+    "ADDRESS",
+    "CONTRACT",
+    "ISNAT",
+    "CAST",
+    "RENAME",
+    "bool",
+    "contract",
+    "int",
+    "key",
+    "key_hash",
+    "lambda",
+    "list",
+    "map",
+    "big_map",
+    "nat",
+    "option",
+    "or",
+    "pair",
+    "set",
+    "signature",
+    "string",
+    "bytes",
+    "mutez",
+    "timestamp",
+    "unit",
+    "operation",
+    "address",
+    "SLICE",
+    "DIG",
+    "DUG",
+    "EMPTY_BIG_MAP",
+    "APPLY",
+    "chain_id",
+    "CHAIN_ID",
+    "LEVEL",
+    "SELF_ADDRESS",
+    "never",
+    "NEVER",
+    "UNPAIR",
+    "VOTING_POWER",
+    "TOTAL_VOTING_POWER",
+    "KECCAK",
+    "SHA3",
+    "PAIRING_CHECK",
+    "bls12_381_g1",
+    "bls12_381_g2",
+    "bls12_381_fr",
+    "sapling_state",
+    "sapling_transaction_deprecated",
+    "SAPLING_EMPTY_STATE",
+    "SAPLING_VERIFY_UPDATE",
+    "ticket",
+    "TICKET_DEPRECATED",
+    "READ_TICKET",
+    "SPLIT_TICKET",
+    "JOIN_TICKETS",
+    "GET_AND_UPDATE",
+    "chest",
+    "chest_key",
+    "OPEN_CHEST",
+    "VIEW",
+    "view",
+    "constant",
+    "SUB_MUTEZ",
+    "tx_rollup_l2_address",
+    "MIN_BLOCK_TIME",
+    "sapling_transaction",
+    "EMIT",
+    "Lambda_rec",
+    "LAMBDA_REC",
+    "TICKET",
+    "BYTES",
+    "NAT",
+];
+
+const PACK_PREFIX: u8 = 0x05;
+
+/// Serializes `value`, of type `ty`, the way the `PACK` instruction does:
+/// readable addresses, keys, signatures and timestamps are first converted
+/// to their optimized form.
+pub fn pack(ty: &Micheline, value: &Micheline) -> Result<Vec<u8>, ParseError> {
+    let optimized = to_optimized(ty, value)?;
+    let mut packed = vec![PACK_PREFIX];
+    packed.extend(forge_micheline(&optimized)?);
+    Ok(packed)
+}
+
+/// Computes the `expr...` hash of a packed value, which is how big map keys
+/// are addressed by the `big_maps/{big_map_id}/{script_expr}` endpoint.
+pub fn script_expr_hash(ty: &Micheline, value: &Micheline) -> Result<String, ParseError> {
+    let packed = pack(ty, value)?;
+    base58::encode(base58::SCRIPT_EXPR_HASH, &hash::blake2b_256(&packed))
+}
+
+/// Serializes a Micheline expression into its binary form, without any
+/// type-directed conversion.
+pub fn forge_micheline(micheline: &Micheline) -> Result<Vec<u8>, ParseError> {
+    let mut forged = Vec::new();
+    write_micheline(micheline, &mut forged)?;
+    Ok(forged)
+}
+
+fn write_micheline(micheline: &Micheline, out: &mut Vec<u8>) -> Result<(), ParseError> {
+    match micheline {
+        Micheline::Int { .. } => {
+            let int = micheline.as_int().ok_or_else(|| {
+                ParseError::RequestParsingError(format!("invalid int {}", micheline))
+            })?;
+            out.push(0x00);
+            out.extend(forge_zarith(&int));
+        }
+        Micheline::String { string } => {
+            out.push(0x01);
+            write_length_prefixed(string.as_bytes(), out);
+        }
+        Micheline::Seq(items) => {
+            out.push(0x02);
+            let mut forged_items = Vec::new();
+            for item in items {
+                write_micheline(item, &mut forged_items)?;
+            }
+            write_length_prefixed(&forged_items, out);
+        }
+        Micheline::Prim(prim) => write_prim(prim, out)?,
+        Micheline::Bytes { .. } => {
+            let bytes = micheline.as_bytes().ok_or_else(|| {
+                ParseError::RequestParsingError(format!("invalid bytes {}", micheline))
+            })?;
+            out.push(0x0a);
+            write_length_prefixed(&bytes, out);
+        }
+    }
+    Ok(())
+}
+
+fn write_prim(prim: &MichelinePrim, out: &mut Vec<u8>) -> Result<(), ParseError> {
+    let opcode = PRIMITIVES
+        .iter()
+        .position(|name| *name == prim.prim)
+        .ok_or_else(|| {
+            ParseError::RequestParsingError(format!("unknown primitive {}", prim.prim))
+        })?;
+
+    let has_annots = !prim.annots.is_empty();
+    let tag = match (prim.args.len(), has_annots) {
+        (0, false) => 0x03,
+        (0, true) => 0x04,
+        (1, false) => 0x05,
+        (1, true) => 0x06,
+        (2, false) => 0x07,
+        (2, true) => 0x08,
+        _ => 0x09,
+    };
+    out.push(tag);
+    out.push(opcode as u8);
+
+    if tag == 0x09 {
+        let mut forged_args = Vec::new();
+        for arg in &prim.args {
+            write_micheline(arg, &mut forged_args)?;
+        }
+        write_length_prefixed(&forged_args, out);
+    } else {
+        for arg in &prim.args {
+            write_micheline(arg, out)?;
+        }
+    }
+
+    if has_annots || tag == 0x09 {
+        write_length_prefixed(prim.annots.join(" ").as_bytes(), out);
+    }
+    Ok(())
+}
+
+fn write_length_prefixed(bytes: &[u8], out: &mut Vec<u8>) {
+    out.extend(&(bytes.len() as u32).to_be_bytes());
+    out.extend(bytes);
+}
+
+/// Deserializes a Micheline expression from its binary form, the inverse of
+/// [`forge_micheline()`].
+pub fn unforge_micheline(bytes: &[u8]) -> Result<Micheline, ParseError> {
+    let mut reader = Reader::new(bytes);
+    let micheline = read_micheline(&mut reader)?;
+    if !reader.is_empty() {
+        let detail = format!("{} trailing bytes after micheline", reader.remaining());
+        return Err(ParseError::ResponseParsingError(detail));
+    }
+    Ok(micheline)
+}
+
+pub(crate) fn read_micheline(reader: &mut Reader) -> Result<Micheline, ParseError> {
+    let tag = reader.read_u8()?;
+    let micheline = match tag {
+        0x00 => Micheline::int(read_zarith(reader)?),
+        0x01 => Micheline::string(read_utf8(reader.read_dynamic()?)?),
+        0x02 => {
+            let mut items_reader = Reader::new(reader.read_dynamic()?);
+            let mut items = Vec::new();
+            while !items_reader.is_empty() {
+                items.push(read_micheline(&mut items_reader)?);
+            }
+            Micheline::Seq(items)
+        }
+        0x03..=0x09 => {
+            let opcode = reader.read_u8()?;
+            let prim = PRIMITIVES.get(opcode as usize).ok_or_else(|| {
+                ParseError::ResponseParsingError(format!("unknown primitive opcode {}", opcode))
+            })?;
+            let args = match tag {
+                0x09 => {
+                    let mut args_reader = Reader::new(reader.read_dynamic()?);
+                    let mut args = Vec::new();
+                    while !args_reader.is_empty() {
+                        args.push(read_micheline(&mut args_reader)?);
+                    }
+                    args
+                }
+                _ => (0..(tag - 0x03) / 2)
+                    .map(|_| read_micheline(reader))
+                    .collect::<Result<_, _>>()?,
+            };
+            let has_annots = tag == 0x09 || tag % 2 == 0;
+            let annots = if has_annots {
+                read_utf8(reader.read_dynamic()?)?
+                    .split_whitespace()
+                    .map(String::from)
+                    .collect()
+            } else {
+                Vec::new()
+            };
+            Micheline::prim(prim, args, annots)
+        }
+        0x0a => Micheline::bytes(reader.read_dynamic()?),
+        tag => {
+            let detail = format!("unknown micheline tag {}", tag);
+            return Err(ParseError::ResponseParsingError(detail));
+        }
+    };
+    Ok(micheline)
+}
+
+fn read_utf8(bytes: &[u8]) -> Result<String, ParseError> {
+    String::from_utf8(bytes.to_vec())
+        .map_err(|error| ParseError::ResponseParsingError(error.to_string()))
+}
+
+/// Encodes a signed integer in the variable length "zarith" format: the
+/// first byte holds a sign bit and six bits of the absolute value, and each
+/// byte has its top bit set when more bytes follow.
+pub fn forge_zarith(int: &BigInt) -> Vec<u8> {
+    let mut abs_bytes = int.magnitude().to_bytes_le();
+    let mut forged = Vec::new();
+
+    let mut low_byte = abs_bytes[0] & 0x3f;
+    if int.sign() == Sign::Minus {
+        low_byte |= 0x40;
+    }
+    shift_right(&mut abs_bytes, 6);
+
+    let mut current = low_byte;
+    while abs_bytes.iter().any(|byte| *byte != 0) {
+        forged.push(current | 0x80);
+        current = abs_bytes[0] & 0x7f;
+        shift_right(&mut abs_bytes, 7);
+    }
+    forged.push(current);
+    forged
+}
+
+/// Decodes a signed integer in the "zarith" format, the inverse of
+/// [`forge_zarith()`].
+pub(crate) fn read_zarith(reader: &mut Reader) -> Result<BigInt, ParseError> {
+    let first = reader.read_u8()?;
+    let sign = if first & 0x40 != 0 {
+        Sign::Minus
+    } else {
+        Sign::Plus
+    };
+    let mut magnitude = BigUint::from(first & 0x3f);
+    let mut shift = 6;
+    let mut byte = first;
+    while byte & 0x80 != 0 {
+        byte = reader.read_u8()?;
+        magnitude |= BigUint::from(byte & 0x7f) << shift;
+        shift += 7;
+    }
+    Ok(BigInt::from_biguint(sign, magnitude))
+}
+
+/// Shifts a little-endian byte string right by `bits` (less than 8).
+fn shift_right(bytes: &mut [u8], bits: u32) {
+    for index in 0..bytes.len() {
+        let next = bytes.get(index + 1).copied().unwrap_or(0);
+        bytes[index] = (bytes[index] >> bits) | (next << (8 - bits));
+    }
+}
+
+/// Converts `value` into the `Optimized_legacy` representation used by
+/// `PACK`: binary pairs and binary forms of addresses, keys, signatures,
+/// chain ids and timestamps.
+pub(super) fn to_optimized(ty: &Micheline, value: &Micheline) -> Result<Micheline, ParseError> {
+    let ty_prim = match ty.as_prim() {
+        Some(prim) => prim,
+        None => return Ok(value.clone()),
+    };
+
+    let converted = match (ty_prim.prim.as_str(), value) {
+        ("address", Micheline::String { string }) | ("contract", Micheline::String { string }) => {
+            Micheline::bytes(&forged::encode_address(string)?)
+        }
+        ("key_hash", Micheline::String { string }) => {
+            Micheline::bytes(&forged::encode_public_key_hash(string)?)
+        }
+        ("key", Micheline::String { string }) => {
+            Micheline::bytes(&forged::encode_public_key(string)?)
+        }
+        ("signature", Micheline::String { string }) => {
+            Micheline::bytes(&forged::encode_signature(string)?)
+        }
+        ("chain_id", Micheline::String { string }) => {
+            Micheline::bytes(&forged::encode_chain_id(string)?)
+        }
+        ("timestamp", Micheline::String { string }) => {
+            let timestamp = DateTime::parse_from_rfc3339(string).map_err(|_| {
+                ParseError::RequestParsingError(format!("invalid timestamp {}", string))
+            })?;
+            Micheline::int(timestamp.timestamp())
+        }
+        ("pair", _) => {
+            let components = match value {
+                Micheline::Prim(prim) if prim.prim == "Pair" => &prim.args,
+                Micheline::Seq(items) => items,
+                _ => return Err(pack_error("expected a pair", value)),
+            };
+            let (left_ty, right_ty) = split_comb(&ty_prim.args, "pair")
+                .ok_or_else(|| pack_error("pair type needs two arguments", ty))?;
+            let (left, right) = split_comb(components, "Pair")
+                .ok_or_else(|| pack_error("pair needs two components", value))?;
+            let args = vec![
+                to_optimized(&left_ty, &left)?,
+                to_optimized(&right_ty, &right)?,
+            ];
+            Micheline::prim("Pair", args, vec![])
+        }
+        ("option", Micheline::Prim(prim)) if prim.prim == "Some" && prim.args.len() == 1 => {
+            let inner = to_optimized(type_arg(ty_prim, 0)?, &prim.args[0])?;
+            Micheline::prim("Some", vec![inner], vec![])
+        }
+        ("or", Micheline::Prim(prim))
+            if (prim.prim == "Left" || prim.prim == "Right") && prim.args.len() == 1 =>
+        {
+            let branch = if prim.prim == "Left" { 0 } else { 1 };
+            let inner = to_optimized(type_arg(ty_prim, branch)?, &prim.args[0])?;
+            Micheline::prim(&prim.prim, vec![inner], vec![])
+        }
+        ("list", Micheline::Seq(items)) | ("set", Micheline::Seq(items)) => {
+            let item_ty = type_arg(ty_prim, 0)?;
+            let items = items
+                .iter()
+                .map(|item| to_optimized(item_ty, item))
+                .collect::<Result<Vec<_>, _>>()?;
+            Micheline::Seq(items)
+        }
+        ("map", Micheline::Seq(items)) | ("big_map", Micheline::Seq(items)) => {
+            let key_ty = type_arg(ty_prim, 0)?;
+            let value_ty = type_arg(ty_prim, 1)?;
+            let items = items
+                .iter()
+                .map(|item| match item.as_prim() {
+                    Some(elt) if elt.prim == "Elt" && elt.args.len() == 2 => {
+                        let key = to_optimized(key_ty, &elt.args[0])?;
+                        let value = to_optimized(value_ty, &elt.args[1])?;
+                        Ok(Micheline::prim("Elt", vec![key, value], vec![]))
+                    }
+                    _ => Err(pack_error("expected Elt", item)),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            Micheline::Seq(items)
+        }
+        _ => value.clone(),
+    };
+
+    Ok(converted)
+}
+
+fn pack_error(detail: &str, micheline: &Micheline) -> ParseError {
+    ParseError::RequestParsingError(format!("{}, found {}", detail, micheline))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(micheline_str: &str) -> Micheline {
+        serde_json::from_str(micheline_str).unwrap()
+    }
+
+    #[test]
+    fn forge_zarith_ok() {
+        assert_eq!(forge_zarith(&BigInt::from(0)), [0x00]);
+        assert_eq!(forge_zarith(&BigInt::from(-1)), [0x41]);
+        assert_eq!(forge_zarith(&BigInt::from(64)), [0x80, 0x01]);
+        assert_eq!(forge_zarith(&BigInt::from(-64)), [0xc0, 0x01]);
+        assert_eq!(forge_zarith(&BigInt::from(1_000_000)), [0x80, 0x89, 0x7a]);
+    }
+
+    #[test]
+    fn read_zarith_ok() {
+        for int in [0_i64, -1, 64, -64, 1_000_000, -123_456_789_012] {
+            let int = BigInt::from(int);
+            let forged = forge_zarith(&int);
+            assert_eq!(read_zarith(&mut Reader::new(&forged)).unwrap(), int);
+        }
+    }
+
+    #[test]
+    fn unforge_micheline_round_trip_ok() {
+        let micheline = parse(
+            r#"[{"prim":"parameter","args":[{"prim":"nat","annots":["%counter"]}]},
+                {"prim":"storage","args":[{"prim":"pair","args":[{"int":"-42"},{"string":"foo"},{"bytes":"cafe"}]}]},
+                {"prim":"code","args":[[{"prim":"CDR"},{"prim":"NIL","args":[{"prim":"operation"}]},{"prim":"PAIR"}]]}]"#,
+        );
+
+        let forged = forge_micheline(&micheline).unwrap();
+        assert_eq!(unforge_micheline(&forged).unwrap(), micheline);
+    }
+
+    #[test]
+    fn unforge_micheline_with_trailing_bytes_fails() {
+        assert!(unforge_micheline(&[0x00, 0x01, 0x00]).is_err());
+        assert!(unforge_micheline(&[0x0b]).is_err());
+    }
+
+    #[test]
+    fn pack_string_ok() {
+        let packed = pack(&parse(r#"{"prim":"string"}"#), &Micheline::string("foo")).unwrap();
+        assert_eq!(hex::encode(packed), "050100000003666f6f");
+    }
+
+    #[test]
+    fn pack_pair_of_nat_and_bool_ok() {
+        let ty = parse(r#"{"prim":"pair","args":[{"prim":"nat"},{"prim":"bool"}]}"#);
+        let value = parse(r#"{"prim":"Pair","args":[{"int":"1"},{"prim":"True"}]}"#);
+
+        let packed = pack(&ty, &value).unwrap();
+        assert_eq!(hex::encode(packed), "050707000103 0a".replace(' ', ""));
+    }
+
+    #[test]
+    fn pack_address_uses_optimized_form_ok() {
+        let ty = parse(r#"{"prim":"address"}"#);
+        let value = Micheline::string("tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx");
+
+        let packed = pack(&ty, &value).unwrap();
+        assert_eq!(
+            hex::encode(packed),
+            "050a00000016000002298c03ed7d454a101eb7022bc95f7e5f41ac78"
+        );
+    }
+
+    #[test]
+    fn script_expr_hash_of_nat_ok() {
+        let hash = script_expr_hash(&parse(r#"{"prim":"nat"}"#), &Micheline::int(0)).unwrap();
+        assert_eq!(
+            hash,
+            "exprtZBwZUeYYYfUs9B9Rg2ywHezVHnCCnmF9WsDQVrs582dSK63dC"
+        );
+    }
+
+    #[test]
+    fn pack_unknown_primitive_fails() {
+        let ty = parse(r#"{"prim":"unit"}"#);
+        let value = parse(r#"{"prim":"NotAPrimitive"}"#);
+
+        assert!(pack(&ty, &value).is_err());
+    }
+}
@@ -0,0 +1,621 @@
+use super::micheline::{Micheline, MichelinePrim};
+use crate::encoding::forged;
+use crate::errors::ParseError;
+use crate::types::Mutez;
+use chrono::{DateTime, Utc};
+use num_bigint::{BigInt, Sign};
+use serde::{Serialize, Serializer};
+use serde_json::{json, Map, Value};
+use std::convert::TryFrom;
+use std::fmt;
+
+/// A Michelson value decoded against its type expression.
+///
+/// Right-comb pairs are flattened into a [`Record`](Self::Record) when every
+/// field carries a `%field` annotation (or a [`Tuple`](Self::Tuple)
+/// otherwise), and nested `or` types are flattened into a single
+/// [`Variant`](Self::Variant) named after the branch annotation, or after its
+/// position when the branch is not annotated.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MichelsonValue {
+    Unit,
+    Bool(bool),
+    Int(BigInt),
+    Nat(BigInt),
+    String(String),
+    Bytes(Vec<u8>),
+    Mutez(Mutez),
+    Timestamp(DateTime<Utc>),
+    Address(String),
+    Contract(String),
+    KeyHash(String),
+    Key(String),
+    Signature(String),
+    ChainId(String),
+    Option(Option<Box<MichelsonValue>>),
+    Record(Vec<(String, MichelsonValue)>),
+    Tuple(Vec<MichelsonValue>),
+    Variant {
+        name: String,
+        value: Box<MichelsonValue>,
+    },
+    List(Vec<MichelsonValue>),
+    Set(Vec<MichelsonValue>),
+    Map(Vec<(MichelsonValue, MichelsonValue)>),
+    BigMap(BigMapValue),
+    Ticket {
+        ticketer: String,
+        value: Box<MichelsonValue>,
+        amount: BigInt,
+    },
+    /// Values that have no structured representation, such as lambdas.
+    Raw(Micheline),
+}
+
+/// A `big_map` is returned by the node either as its id, or inline as a
+/// list of entries when it has not been allocated yet.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BigMapValue {
+    Id(BigInt),
+    Entries(Vec<(MichelsonValue, MichelsonValue)>),
+}
+
+impl MichelsonValue {
+    /// Decodes `value` against the Michelson type expression `ty`, for
+    /// example a contract's `storage` type and its current storage.
+    pub fn decode(ty: &Micheline, value: &Micheline) -> Result<Self, ParseError> {
+        let ty_prim = ty
+            .as_prim()
+            .ok_or_else(|| decode_error("type expression is not a primitive", ty))?;
+        let args = &ty_prim.args;
+
+        let decoded = match ty_prim.prim.as_str() {
+            "unit" => {
+                expect_prim(value, "Unit", 0)?;
+                Self::Unit
+            }
+            "bool" => match value.as_prim().map(|prim| prim.prim.as_str()) {
+                Some("True") => Self::Bool(true),
+                Some("False") => Self::Bool(false),
+                _ => return Err(decode_error("expected a boolean", value)),
+            },
+            "int" => Self::Int(expect_int(value)?),
+            "nat" => Self::Nat(expect_nat(value)?),
+            "mutez" => {
+                let mutez = expect_nat(value)?;
+                if mutez > BigInt::from(i64::MAX) {
+                    return Err(decode_error("mutez out of range", value));
+                }
+                Self::Mutez(mutez.to_string().parse()?)
+            }
+            "string" => Self::String(expect_str(value)?.to_string()),
+            "bytes" | "bls12_381_g1" | "bls12_381_g2" | "bls12_381_fr" | "chest" | "chest_key" => {
+                Self::Bytes(expect_bytes(value)?)
+            }
+            "timestamp" => Self::Timestamp(decode_timestamp(value)?),
+            "address" => Self::Address(decode_optimized(value, forged::decode_address)?),
+            "contract" => Self::Contract(decode_optimized(value, forged::decode_address)?),
+            "key_hash" => Self::KeyHash(decode_optimized(value, forged::decode_public_key_hash)?),
+            "key" => Self::Key(decode_optimized(value, forged::decode_public_key)?),
+            "signature" => Self::Signature(decode_optimized(value, forged::decode_signature)?),
+            "chain_id" => Self::ChainId(decode_optimized(value, forged::decode_chain_id)?),
+            "option" => match value.as_prim().map(|prim| prim.prim.as_str()) {
+                Some("None") => Self::Option(None),
+                Some("Some") => {
+                    let inner = &expect_prim(value, "Some", 1)?.args[0];
+                    let decoded = Self::decode(type_arg(ty_prim, 0)?, inner)?;
+                    Self::Option(Some(Box::new(decoded)))
+                }
+                _ => return Err(decode_error("expected an option", value)),
+            },
+            "pair" => decode_pair(ty_prim, value)?,
+            "or" => decode_or(ty_prim, value)?,
+            "list" => Self::List(decode_seq(type_arg(ty_prim, 0)?, value)?),
+            "set" => Self::Set(decode_seq(type_arg(ty_prim, 0)?, value)?),
+            "map" => Self::Map(decode_map(ty_prim, value)?),
+            "big_map" => match value {
+                Micheline::Int { .. } => Self::BigMap(BigMapValue::Id(expect_int(value)?)),
+                _ => Self::BigMap(BigMapValue::Entries(decode_map(ty_prim, value)?)),
+            },
+            "ticket" if args.len() == 1 => decode_ticket(&args[0], value)?,
+            _ => Self::Raw(value.clone()),
+        };
+
+        Ok(decoded)
+    }
+
+    /// Converts the decoded value into plain JSON, for consumers that do not
+    /// know about Michelson.
+    ///
+    /// `Unit` is `{}` and `None` is `null`, so that `Some Unit` and `None`
+    /// stay distinct. `Some` is omitted, except around nested options where
+    /// `Some None` would otherwise read as `None`: those are `{"Some": ..}`.
+    pub fn to_json(&self) -> Value {
+        match self {
+            Self::Unit => json!({}),
+            Self::Bool(boolean) => json!(boolean),
+            Self::Int(int) | Self::Nat(int) => json!(int.to_string()),
+            Self::String(string)
+            | Self::Address(string)
+            | Self::Contract(string)
+            | Self::KeyHash(string)
+            | Self::Key(string)
+            | Self::Signature(string)
+            | Self::ChainId(string) => json!(string),
+            Self::Bytes(bytes) => json!(hex::encode(bytes)),
+            Self::Mutez(mutez) => json!(mutez),
+            Self::Timestamp(timestamp) => json!(timestamp.to_rfc3339()),
+            Self::Option(None) => Value::Null,
+            Self::Option(Some(value)) => match value.as_ref() {
+                Self::Option(_) => json!({ "Some": value.to_json() }),
+                _ => value.to_json(),
+            },
+            Self::Record(fields) => {
+                let object: Map<String, Value> = fields
+                    .iter()
+                    .map(|(name, value)| (name.clone(), value.to_json()))
+                    .collect();
+                Value::Object(object)
+            }
+            Self::Tuple(values) | Self::List(values) | Self::Set(values) => {
+                Value::Array(values.iter().map(Self::to_json).collect())
+            }
+            Self::Variant { name, value } => json!({ name.clone(): value.to_json() }),
+            Self::Map(entries) | Self::BigMap(BigMapValue::Entries(entries)) => {
+                entries_to_json(entries)
+            }
+            Self::BigMap(BigMapValue::Id(id)) => json!(id.to_string()),
+            Self::Ticket {
+                ticketer,
+                value,
+                amount,
+            } => json!({
+                "ticketer": ticketer,
+                "value": value.to_json(),
+                "amount": amount.to_string(),
+            }),
+            Self::Raw(micheline) => json!(micheline),
+        }
+    }
+
+    /// Looks up a field of a [`Record`](Self::Record) by name.
+    pub fn field(&self, name: &str) -> Option<&MichelsonValue> {
+        match self {
+            Self::Record(fields) => fields
+                .iter()
+                .find(|(field_name, _)| field_name == name)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+}
+
+impl Serialize for MichelsonValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_json().serialize(serializer)
+    }
+}
+
+impl fmt::Display for MichelsonValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_json())
+    }
+}
+
+fn decode_pair(ty: &MichelinePrim, value: &Micheline) -> Result<MichelsonValue, ParseError> {
+    let mut fields = Vec::new();
+    collect_pair_fields(ty, value, &mut fields)?;
+
+    let names: Vec<&String> = fields
+        .iter()
+        .filter_map(|(name, _)| name.as_ref())
+        .collect();
+    let names_are_unique = names
+        .iter()
+        .enumerate()
+        .all(|(index, name)| !names[..index].contains(name));
+
+    if names.len() == fields.len() && names_are_unique {
+        let record = fields
+            .into_iter()
+            .map(|(name, value)| (name.unwrap_or_default(), value))
+            .collect();
+        Ok(MichelsonValue::Record(record))
+    } else {
+        let tuple = fields.into_iter().map(|(_, value)| value).collect();
+        Ok(MichelsonValue::Tuple(tuple))
+    }
+}
+
+fn collect_pair_fields(
+    ty: &MichelinePrim,
+    value: &Micheline,
+    fields: &mut Vec<(Option<String>, MichelsonValue)>,
+) -> Result<(), ParseError> {
+    let (left_ty, right_ty) = split_comb(&ty.args, "pair").ok_or_else(|| {
+        decode_error(
+            "pair type needs at least two arguments",
+            &Micheline::Prim(ty.clone()),
+        )
+    })?;
+
+    let value_components = match value {
+        Micheline::Prim(prim) if prim.prim == "Pair" => &prim.args,
+        Micheline::Seq(items) => items,
+        _ => return Err(decode_error("expected a pair", value)),
+    };
+    let (left_value, right_value) = split_comb(value_components, "Pair")
+        .ok_or_else(|| decode_error("pair needs at least two components", value))?;
+
+    for (side_ty, side_value) in [(left_ty, left_value), (right_ty, right_value)] {
+        match side_ty.as_prim() {
+            Some(prim) if prim.prim == "pair" && prim.field_annot().is_none() => {
+                collect_pair_fields(prim, &side_value, fields)?
+            }
+            Some(prim) => {
+                let name = prim.field_annot().map(str::to_string);
+                fields.push((name, MichelsonValue::decode(&side_ty, &side_value)?));
+            }
+            None => return Err(decode_error("type expression is not a primitive", &side_ty)),
+        }
+    }
+    Ok(())
+}
+
+/// Splits the arguments of an n-ary comb into its first element and the
+/// (possibly re-nested) rest.
+pub(super) fn split_comb(args: &[Micheline], prim: &str) -> Option<(Micheline, Micheline)> {
+    match args {
+        [] | [_] => None,
+        [left, right] => Some((left.clone(), right.clone())),
+        [left, rest @ ..] => Some((left.clone(), Micheline::prim(prim, rest.to_vec(), vec![]))),
+    }
+}
+
+fn decode_or(ty: &MichelinePrim, value: &Micheline) -> Result<MichelsonValue, ParseError> {
+    let mut branch_ty = ty;
+    let mut branch_value = value;
+    let mut index = 0;
+
+    loop {
+        let (is_left, inner_value) = match branch_value.as_prim() {
+            Some(prim) if prim.prim == "Left" && prim.args.len() == 1 => (true, &prim.args[0]),
+            Some(prim) if prim.prim == "Right" && prim.args.len() == 1 => (false, &prim.args[0]),
+            _ => return Err(decode_error("expected Left or Right", branch_value)),
+        };
+
+        let left_ty = type_arg(branch_ty, 0)?;
+        let inner_ty = if is_left {
+            left_ty
+        } else {
+            index += count_variants(left_ty);
+            type_arg(branch_ty, 1)?
+        };
+
+        match inner_ty.as_prim() {
+            Some(prim) if prim.prim == "or" && prim.field_annot().is_none() => {
+                branch_ty = prim;
+                branch_value = inner_value;
+            }
+            Some(prim) => {
+                let name = match prim.field_annot() {
+                    Some(annot) => annot.to_string(),
+                    None => index.to_string(),
+                };
+                let value = Box::new(MichelsonValue::decode(inner_ty, inner_value)?);
+                return Ok(MichelsonValue::Variant { name, value });
+            }
+            None => return Err(decode_error("type expression is not a primitive", inner_ty)),
+        }
+    }
+}
+
+/// Counts the branches an `or` type is flattened into.
+pub(super) fn count_variants(ty: &Micheline) -> usize {
+    match ty.as_prim() {
+        Some(prim) if prim.prim == "or" && prim.field_annot().is_none() => {
+            prim.args.iter().map(count_variants).sum()
+        }
+        _ => 1,
+    }
+}
+
+fn decode_seq(item_ty: &Micheline, value: &Micheline) -> Result<Vec<MichelsonValue>, ParseError> {
+    match value {
+        Micheline::Seq(items) => items
+            .iter()
+            .map(|item| MichelsonValue::decode(item_ty, item))
+            .collect(),
+        _ => Err(decode_error("expected a sequence", value)),
+    }
+}
+
+fn decode_map(
+    ty: &MichelinePrim,
+    value: &Micheline,
+) -> Result<Vec<(MichelsonValue, MichelsonValue)>, ParseError> {
+    let key_ty = type_arg(ty, 0)?;
+    let value_ty = type_arg(ty, 1)?;
+
+    match value {
+        Micheline::Seq(items) => items
+            .iter()
+            .map(|item| {
+                let elt = expect_prim(item, "Elt", 2)?;
+                let key = MichelsonValue::decode(key_ty, &elt.args[0])?;
+                let value = MichelsonValue::decode(value_ty, &elt.args[1])?;
+                Ok((key, value))
+            })
+            .collect(),
+        _ => Err(decode_error("expected a sequence of Elt", value)),
+    }
+}
+
+fn decode_ticket(content_ty: &Micheline, value: &Micheline) -> Result<MichelsonValue, ParseError> {
+    let ticket_ty = Micheline::prim(
+        "pair",
+        vec![
+            Micheline::prim("address", vec![], vec![]),
+            content_ty.clone(),
+            Micheline::prim("nat", vec![], vec![]),
+        ],
+        vec![],
+    );
+
+    match MichelsonValue::decode(&ticket_ty, value)? {
+        MichelsonValue::Tuple(fields) => match <[MichelsonValue; 3]>::try_from(fields) {
+            Ok([MichelsonValue::Address(ticketer), content, MichelsonValue::Nat(amount)]) => {
+                Ok(MichelsonValue::Ticket {
+                    ticketer,
+                    value: Box::new(content),
+                    amount,
+                })
+            }
+            _ => Err(decode_error("expected a ticket", value)),
+        },
+        _ => Err(decode_error("expected a ticket", value)),
+    }
+}
+
+fn decode_timestamp(value: &Micheline) -> Result<DateTime<Utc>, ParseError> {
+    match value {
+        Micheline::String { string } => DateTime::parse_from_rfc3339(string)
+            .map(|timestamp| timestamp.with_timezone(&Utc))
+            .map_err(|_| decode_error("invalid RFC 3339 timestamp", value)),
+        Micheline::Int { .. } => expect_int(value)?
+            .to_string()
+            .parse()
+            .ok()
+            .and_then(|seconds| DateTime::from_timestamp(seconds, 0))
+            .ok_or_else(|| decode_error("timestamp out of range", value)),
+        _ => Err(decode_error("expected a timestamp", value)),
+    }
+}
+
+/// Decodes values that the node returns either in readable (base58 string)
+/// or optimized (bytes) form.
+fn decode_optimized(
+    value: &Micheline,
+    decode_bytes: fn(&[u8]) -> Result<String, ParseError>,
+) -> Result<String, ParseError> {
+    match value {
+        Micheline::String { string } => Ok(string.clone()),
+        Micheline::Bytes { .. } => decode_bytes(&expect_bytes(value)?),
+        _ => Err(decode_error("expected a string or bytes", value)),
+    }
+}
+
+pub(super) fn type_arg(ty: &MichelinePrim, index: usize) -> Result<&Micheline, ParseError> {
+    ty.args.get(index).ok_or_else(|| {
+        let detail = format!("type {} is missing argument {}", ty.prim, index);
+        ParseError::ResponseParsingError(detail)
+    })
+}
+
+fn expect_prim<'a>(
+    value: &'a Micheline,
+    prim: &str,
+    arg_count: usize,
+) -> Result<&'a MichelinePrim, ParseError> {
+    match value.as_prim() {
+        Some(inner) if inner.prim == prim && inner.args.len() == arg_count => Ok(inner),
+        _ => Err(decode_error(&format!("expected {}", prim), value)),
+    }
+}
+
+fn expect_int(value: &Micheline) -> Result<BigInt, ParseError> {
+    value
+        .as_int()
+        .ok_or_else(|| decode_error("expected an int", value))
+}
+
+fn expect_nat(value: &Micheline) -> Result<BigInt, ParseError> {
+    let nat = expect_int(value)?;
+    if nat.sign() == Sign::Minus {
+        return Err(decode_error("expected a natural number", value));
+    }
+    Ok(nat)
+}
+
+fn expect_str(value: &Micheline) -> Result<&str, ParseError> {
+    value
+        .as_str()
+        .ok_or_else(|| decode_error("expected a string", value))
+}
+
+fn expect_bytes(value: &Micheline) -> Result<Vec<u8>, ParseError> {
+    value
+        .as_bytes()
+        .ok_or_else(|| decode_error("expected bytes", value))
+}
+
+fn entries_to_json(entries: &[(MichelsonValue, MichelsonValue)]) -> Value {
+    let entries = entries
+        .iter()
+        .map(|(key, value)| json!({ "key": key.to_json(), "value": value.to_json() }))
+        .collect();
+    Value::Array(entries)
+}
+
+fn decode_error(detail: &str, value: &Micheline) -> ParseError {
+    let detail = format!("{}, found {}", detail, value);
+    ParseError::ResponseParsingError(detail)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(micheline_str: &str) -> Micheline {
+        serde_json::from_str(micheline_str).unwrap()
+    }
+
+    #[test]
+    fn decode_annotated_right_comb_into_record_ok() {
+        let ty = parse(
+            r#"{"prim":"pair","args":[
+                {"prim":"address","annots":["%admin"]},
+                {"prim":"pair","args":[
+                    {"prim":"big_map","args":[{"prim":"address"},{"prim":"nat"}],"annots":["%ledger"]},
+                    {"prim":"mutez","annots":["%fee"]},
+                    {"prim":"timestamp","annots":["%deadline"]}
+                ]}
+            ]}"#,
+        );
+        let value = parse(
+            r#"{"prim":"Pair","args":[
+                {"bytes":"000002298c03ed7d454a101eb7022bc95f7e5f41ac78"},
+                {"int":"42"},
+                {"int":"1500"},
+                {"string":"2021-06-01T00:00:00Z"}
+            ]}"#,
+        );
+
+        let decoded = MichelsonValue::decode(&ty, &value).unwrap();
+
+        assert_eq!(
+            decoded.field("admin"),
+            Some(&MichelsonValue::Address(
+                "tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx".to_string()
+            ))
+        );
+        assert_eq!(
+            decoded.field("ledger"),
+            Some(&MichelsonValue::BigMap(BigMapValue::Id(BigInt::from(42))))
+        );
+        assert_eq!(
+            decoded.field("fee"),
+            Some(&MichelsonValue::Mutez(Mutez::new(1500)))
+        );
+
+        let json = decoded.to_json();
+        assert_eq!(json["deadline"], "2021-06-01T00:00:00+00:00");
+        assert_eq!(json["ledger"], "42");
+    }
+
+    #[test]
+    fn decode_unannotated_pair_into_tuple_ok() {
+        let ty = parse(r#"{"prim":"pair","args":[{"prim":"nat"},{"prim":"string"}]}"#);
+        let value = parse(r#"[{"int":"1"},{"string":"one"}]"#);
+
+        let decoded = MichelsonValue::decode(&ty, &value).unwrap();
+        assert_eq!(
+            decoded,
+            MichelsonValue::Tuple(vec![
+                MichelsonValue::Nat(BigInt::from(1)),
+                MichelsonValue::String("one".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn decode_nested_or_into_named_variant_ok() {
+        let ty = parse(
+            r#"{"prim":"or","args":[
+                {"prim":"unit","annots":["%pause"]},
+                {"prim":"or","args":[
+                    {"prim":"nat","annots":["%mint"]},
+                    {"prim":"option","args":[{"prim":"key_hash"}],"annots":["%set_delegate"]}
+                ]}
+            ]}"#,
+        );
+        let value = parse(
+            r#"{"prim":"Right","args":[{"prim":"Right","args":[{"prim":"Some","args":[{"string":"tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx"}]}]}]}"#,
+        );
+
+        let decoded = MichelsonValue::decode(&ty, &value).unwrap();
+        match decoded {
+            MichelsonValue::Variant { name, value } => {
+                assert_eq!(name, "set_delegate");
+                assert_eq!(
+                    *value,
+                    MichelsonValue::Option(Some(Box::new(MichelsonValue::KeyHash(
+                        "tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx".to_string()
+                    ))))
+                );
+            }
+            _ => panic!("expected a variant"),
+        }
+    }
+
+    #[test]
+    fn decode_unannotated_or_uses_position_ok() {
+        let ty = parse(
+            r#"{"prim":"or","args":[{"prim":"or","args":[{"prim":"nat"},{"prim":"int"}]},{"prim":"string"}]}"#,
+        );
+        let value = parse(r#"{"prim":"Right","args":[{"string":"third"}]}"#);
+
+        let decoded = MichelsonValue::decode(&ty, &value).unwrap();
+        assert_eq!(decoded.to_json(), json!({"2": "third"}));
+    }
+
+    #[test]
+    fn decode_map_ok() {
+        let ty = parse(r#"{"prim":"map","args":[{"prim":"string"},{"prim":"bool"}]}"#);
+        let value = parse(
+            r#"[{"prim":"Elt","args":[{"string":"a"},{"prim":"True"}]},{"prim":"Elt","args":[{"string":"b"},{"prim":"False"}]}]"#,
+        );
+
+        let decoded = MichelsonValue::decode(&ty, &value).unwrap();
+        assert_eq!(
+            decoded.to_json(),
+            json!([{"key": "a", "value": true}, {"key": "b", "value": false}])
+        );
+    }
+
+    #[test]
+    fn decode_mismatched_value_fails() {
+        let ty = parse(r#"{"prim":"nat"}"#);
+        let value = parse(r#"{"string":"not a nat"}"#);
+
+        assert!(MichelsonValue::decode(&ty, &value).is_err());
+    }
+
+    #[test]
+    fn decode_negative_nat_and_mutez_fails() {
+        let value = parse(r#"{"int":"-1"}"#);
+
+        assert!(MichelsonValue::decode(&parse(r#"{"prim":"nat"}"#), &value).is_err());
+        assert!(MichelsonValue::decode(&parse(r#"{"prim":"mutez"}"#), &value).is_err());
+        assert!(MichelsonValue::decode(&parse(r#"{"prim":"int"}"#), &value).is_ok());
+    }
+
+    #[test]
+    fn options_of_unit_and_options_to_json_ok() {
+        let ty = parse(r#"{"prim":"option","args":[{"prim":"unit"}]}"#);
+        let some_unit =
+            MichelsonValue::decode(&ty, &parse(r#"{"prim":"Some","args":[{"prim":"Unit"}]}"#));
+        let none = MichelsonValue::decode(&ty, &parse(r#"{"prim":"None"}"#));
+
+        assert_eq!(some_unit.unwrap().to_json(), json!({}));
+        assert_eq!(none.unwrap().to_json(), Value::Null);
+
+        let nested_ty =
+            parse(r#"{"prim":"option","args":[{"prim":"option","args":[{"prim":"nat"}]}]}"#);
+        let some_none = parse(r#"{"prim":"Some","args":[{"prim":"None"}]}"#);
+        let decoded = MichelsonValue::decode(&nested_ty, &some_none).unwrap();
+
+        assert_eq!(decoded.to_json(), json!({"Some": null}));
+        assert_eq!(decoded.encode(&nested_ty).unwrap(), some_none);
+    }
+}
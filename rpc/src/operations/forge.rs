@@ -0,0 +1,161 @@
+use super::{ManagerFields, Operation, OperationContent, Parameters};
+use crate::encoding::{base58, forged};
+use crate::errors::ParseError;
+use crate::michelson::pack::forge_micheline;
+use crate::types::Ballot;
+
+pub(super) const PROPOSALS_TAG: u8 = 5;
+pub(super) const BALLOT_TAG: u8 = 6;
+pub(super) const REVEAL_TAG: u8 = 107;
+pub(super) const TRANSACTION_TAG: u8 = 108;
+pub(super) const ORIGINATION_TAG: u8 = 109;
+pub(super) const DELEGATION_TAG: u8 = 110;
+
+/// Entrypoints encoded on a single byte, by tag; others are tagged `255` and
+/// followed by their name.
+pub(super) const ENTRYPOINTS: [&str; 10] = [
+    "default",
+    "root",
+    "do",
+    "set_delegate",
+    "remove_delegate",
+    "deposit",
+    "stake",
+    "unstake",
+    "finalize_unstake",
+    "set_delegate_parameters",
+];
+pub(super) const NAMED_ENTRYPOINT_TAG: u8 = 255;
+
+pub(super) fn forge_operation(operation: &Operation) -> Result<Vec<u8>, ParseError> {
+    let mut forged = base58::decode(base58::BLOCK_HASH, &operation.branch)?;
+    for content in &operation.contents {
+        write_content(content, &mut forged)?;
+    }
+    Ok(forged)
+}
+
+fn write_content(content: &OperationContent, out: &mut Vec<u8>) -> Result<(), ParseError> {
+    match content {
+        OperationContent::Proposals {
+            source,
+            period,
+            proposals,
+        } => {
+            out.push(PROPOSALS_TAG);
+            out.extend(forged::encode_public_key_hash(source)?);
+            out.extend(&period.to_be_bytes());
+            let mut forged_proposals = Vec::new();
+            for proposal in proposals {
+                forged_proposals.extend(base58::decode(base58::PROTOCOL_HASH, proposal)?);
+            }
+            write_dynamic(&forged_proposals, out);
+        }
+        OperationContent::Ballot {
+            source,
+            period,
+            proposal,
+            ballot,
+        } => {
+            out.push(BALLOT_TAG);
+            out.extend(forged::encode_public_key_hash(source)?);
+            out.extend(&period.to_be_bytes());
+            out.extend(base58::decode(base58::PROTOCOL_HASH, proposal)?);
+            out.push(match ballot {
+                Ballot::Yay => 0,
+                Ballot::Nay => 1,
+                Ballot::Pass => 2,
+            });
+        }
+        OperationContent::Reveal(reveal) => {
+            out.push(REVEAL_TAG);
+            write_manager_fields(&reveal.manager, out)?;
+            out.extend(forged::encode_public_key(&reveal.public_key)?);
+        }
+        OperationContent::Transaction(transaction) => {
+            out.push(TRANSACTION_TAG);
+            write_manager_fields(&transaction.manager, out)?;
+            write_natural(transaction.amount.as_u64(), out);
+            out.extend(forged::encode_address(&transaction.destination)?);
+            match &transaction.parameters {
+                Some(parameters) => {
+                    out.push(0xff);
+                    write_parameters(parameters, out)?;
+                }
+                None => out.push(0x00),
+            }
+        }
+        OperationContent::Origination(origination) => {
+            out.push(ORIGINATION_TAG);
+            write_manager_fields(&origination.manager, out)?;
+            write_natural(origination.balance.as_u64(), out);
+            write_optional_public_key_hash(origination.delegate.as_deref(), out)?;
+            write_dynamic(&forge_micheline(&origination.script.code)?, out);
+            write_dynamic(&forge_micheline(&origination.script.storage)?, out);
+        }
+        OperationContent::Delegation(delegation) => {
+            out.push(DELEGATION_TAG);
+            write_manager_fields(&delegation.manager, out)?;
+            write_optional_public_key_hash(delegation.delegate.as_deref(), out)?;
+        }
+    }
+    Ok(())
+}
+
+fn write_manager_fields(manager: &ManagerFields, out: &mut Vec<u8>) -> Result<(), ParseError> {
+    out.extend(forged::encode_public_key_hash(&manager.source)?);
+    write_natural(manager.fee.as_u64(), out);
+    write_natural(manager.counter, out);
+    write_natural(manager.gas_limit, out);
+    write_natural(manager.storage_limit, out);
+    Ok(())
+}
+
+fn write_parameters(parameters: &Parameters, out: &mut Vec<u8>) -> Result<(), ParseError> {
+    match ENTRYPOINTS
+        .iter()
+        .position(|entrypoint| *entrypoint == parameters.entrypoint)
+    {
+        Some(tag) => out.push(tag as u8),
+        None => {
+            let name = parameters.entrypoint.as_bytes();
+            if name.len() > 31 {
+                let detail = format!("entrypoint name {} is too long", parameters.entrypoint);
+                return Err(ParseError::RequestParsingError(detail));
+            }
+            out.push(NAMED_ENTRYPOINT_TAG);
+            out.push(name.len() as u8);
+            out.extend(name);
+        }
+    }
+    write_dynamic(&forge_micheline(&parameters.value)?, out);
+    Ok(())
+}
+
+fn write_optional_public_key_hash(
+    public_key_hash: Option<&str>,
+    out: &mut Vec<u8>,
+) -> Result<(), ParseError> {
+    match public_key_hash {
+        Some(public_key_hash) => {
+            out.push(0xff);
+            out.extend(forged::encode_public_key_hash(public_key_hash)?);
+        }
+        None => out.push(0x00),
+    }
+    Ok(())
+}
+
+/// Encodes a natural number in the "zarith" format, seven bits per byte.
+fn write_natural(mut natural: u64, out: &mut Vec<u8>) {
+    while natural >= 0x80 {
+        out.push((natural & 0x7f) as u8 | 0x80);
+        natural >>= 7;
+    }
+    out.push(natural as u8);
+}
+
+fn write_dynamic(bytes: &[u8], out: &mut Vec<u8>) {
+    out.extend(&(bytes.len() as u32).to_be_bytes());
+    out.extend(bytes);
+}
@@ -0,0 +1,458 @@
+//! Operations as built and signed by wallets, and their binary ("forged")
+//! form.
+//!
+//! Only the operations a wallet signs are supported: manager operations
+//! (reveals, transactions, originations and delegations) and votes. The
+//! binary layout is the one of the protocols since Kathmandu.
+
+mod forge;
+mod unforge;
+
+use crate::crypto::{PublicKey, Signature, Watermark};
+use crate::encoding::{base58, hash};
+use crate::errors::ParseError;
+use crate::michelson::Micheline;
+use crate::responses::protocols::OperationKind;
+use crate::types::{Ballot, Mutez};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::fmt;
+
+/// An operation, in the JSON form used by the node's `forge` and `preapply`
+/// endpoints.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Operation {
+    /// Hash of the block the operation is anchored to.
+    pub branch: String,
+    pub contents: Vec<OperationContent>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<Signature>,
+}
+
+impl Operation {
+    /// Decodes a signed operation, as injected: forged contents followed by
+    /// a 64 byte signature.
+    ///
+    /// Fails unless the contents are in the binary form
+    /// [`forge_unsigned()`](Self::forge_unsigned()) gives, so that
+    /// [`hash()`](Self::hash()) is the hash of `bytes`.
+    pub fn from_signed_bytes(bytes: &[u8]) -> Result<Self, ParseError> {
+        if bytes.len() < 32 + 64 {
+            let detail = format!("signed operation of {} bytes is too short", bytes.len());
+            return Err(ParseError::ResponseParsingError(detail));
+        }
+        let (unsigned_bytes, signature) = bytes.split_at(bytes.len() - 64);
+        let mut operation = Self::from_unsigned_bytes(unsigned_bytes)?;
+        operation.signature = Some(Signature::from_bytes(signature)?);
+        Ok(operation)
+    }
+
+    /// Decodes a signed operation given in hexadecimal.
+    pub fn from_signed_hex(hex_string: &str) -> Result<Self, ParseError> {
+        let bytes = hex::decode(hex_string.trim())
+            .map_err(|error| ParseError::ResponseParsingError(error.to_string()))?;
+        Self::from_signed_bytes(&bytes)
+    }
+
+    /// Decodes an operation without signature, as returned by the node's
+    /// `forge/operations` endpoint.
+    ///
+    /// Fails if forging the decoded operation does not give back `bytes`,
+    /// e.g. for padded numbers or annotations with extra whitespace.
+    pub fn from_unsigned_bytes(bytes: &[u8]) -> Result<Self, ParseError> {
+        let operation = unforge::unforge_operation(bytes)?;
+        if operation.forge_unsigned()? != bytes {
+            return Err(ParseError::ResponseParsingError(
+                "operation bytes are not in canonical form".to_string(),
+            ));
+        }
+        Ok(operation)
+    }
+
+    /// Binary form of the branch and contents: the bytes that are signed.
+    pub fn forge_unsigned(&self) -> Result<Vec<u8>, ParseError> {
+        forge::forge_operation(self)
+    }
+
+    /// Binary form of the operation, followed by its signature if any.
+    pub fn forge(&self) -> Result<Vec<u8>, ParseError> {
+        let mut forged = self.forge_unsigned()?;
+        if let Some(signature) = &self.signature {
+            forged.extend_from_slice(signature.as_bytes());
+        }
+        Ok(forged)
+    }
+
+    /// Computes the `o...` hash the operation is injected under.
+    ///
+    /// The hash covers the signature, so it is only defined for signed
+    /// operations.
+    pub fn hash(&self) -> Result<String, ParseError> {
+        if self.signature.is_none() {
+            return Err(ParseError::RequestParsingError(
+                "the hash of an unsigned operation is not defined".to_string(),
+            ));
+        }
+        operation_hash(&self.forge()?)
+    }
+
+    /// Checks the operation's signature against `public_key`.
+    pub fn verify(&self, public_key: &PublicKey) -> Result<bool, ParseError> {
+        let signature = self.signature.as_ref().ok_or_else(|| {
+            ParseError::RequestParsingError("operation is not signed".to_string())
+        })?;
+        public_key.verify(
+            Some(&Watermark::GenericOperation),
+            &self.forge_unsigned()?,
+            signature,
+        )
+    }
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+/// Computes the `o...` hash of a forged and signed operation.
+pub fn operation_hash(signed_bytes: &[u8]) -> Result<String, ParseError> {
+    base58::encode(base58::OPERATION_HASH, &hash::blake2b_256(signed_bytes))
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum OperationContent {
+    Proposals {
+        source: String,
+        period: i32,
+        proposals: Vec<String>,
+    },
+    Ballot {
+        source: String,
+        period: i32,
+        proposal: String,
+        ballot: Ballot,
+    },
+    Reveal(Reveal),
+    Transaction(Transaction),
+    Origination(Origination),
+    Delegation(Delegation),
+}
+
+impl OperationContent {
+    pub fn kind(&self) -> OperationKind {
+        match self {
+            Self::Proposals { .. } => OperationKind::Proposals,
+            Self::Ballot { .. } => OperationKind::Ballot,
+            Self::Reveal(_) => OperationKind::Reveal,
+            Self::Transaction(_) => OperationKind::Transaction,
+            Self::Origination(_) => OperationKind::Origination,
+            Self::Delegation(_) => OperationKind::Delegation,
+        }
+    }
+
+    /// Address of the account signing this content.
+    pub fn source(&self) -> &str {
+        match self {
+            Self::Proposals { source, .. } | Self::Ballot { source, .. } => source,
+            Self::Reveal(reveal) => &reveal.manager.source,
+            Self::Transaction(transaction) => &transaction.manager.source,
+            Self::Origination(origination) => &origination.manager.source,
+            Self::Delegation(delegation) => &delegation.manager.source,
+        }
+    }
+
+    /// Fee and limits of manager operations, `None` for votes.
+    pub fn manager(&self) -> Option<&ManagerFields> {
+        match self {
+            Self::Proposals { .. } | Self::Ballot { .. } => None,
+            Self::Reveal(reveal) => Some(&reveal.manager),
+            Self::Transaction(transaction) => Some(&transaction.manager),
+            Self::Origination(origination) => Some(&origination.manager),
+            Self::Delegation(delegation) => Some(&delegation.manager),
+        }
+    }
+}
+
+/// Fields shared by all manager operations.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ManagerFields {
+    pub source: String,
+    pub fee: Mutez,
+    #[serde(with = "decimal")]
+    pub counter: u64,
+    #[serde(with = "decimal")]
+    pub gas_limit: u64,
+    #[serde(with = "decimal")]
+    pub storage_limit: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Reveal {
+    #[serde(flatten)]
+    pub manager: ManagerFields,
+    pub public_key: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Transaction {
+    #[serde(flatten)]
+    pub manager: ManagerFields,
+    pub amount: Mutez,
+    pub destination: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<Parameters>,
+}
+
+/// Entrypoint called by a transaction, and its argument.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Parameters {
+    pub entrypoint: String,
+    pub value: Micheline,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Origination {
+    #[serde(flatten)]
+    pub manager: ManagerFields,
+    pub balance: Mutez,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delegate: Option<String>,
+    pub script: Script,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Script {
+    pub code: Micheline,
+    pub storage: Micheline,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Delegation {
+    #[serde(flatten)]
+    pub manager: ManagerFields,
+    /// New delegate, `None` to withdraw the delegation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delegate: Option<String>,
+}
+
+/// Serde helpers for the natural numbers the node serializes as decimal
+/// strings.
+mod decimal {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(value: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&value.to_string())
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.trim().parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ed25519_dalek::Signer;
+
+    const BRANCH: &str = "BLockGenesisGenesisGenesisGenesisGenesisf79b5d1CoW2";
+    const SOURCE: &str = "tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx";
+
+    fn mock_operation() -> Operation {
+        serde_json::from_value(json!({
+            "branch": BRANCH,
+            "contents": [
+                {
+                    "kind": "reveal",
+                    "source": SOURCE,
+                    "fee": "1270",
+                    "counter": "1",
+                    "gas_limit": "1000",
+                    "storage_limit": "0",
+                    "public_key": "edpkuBknW28nW72KG6RoHtYW7p12T6GKc7nAbwYX5m8Wd9sDVC9yav"
+                },
+                {
+                    "kind": "transaction",
+                    "source": SOURCE,
+                    "fee": "2000",
+                    "counter": "2",
+                    "gas_limit": "10600",
+                    "storage_limit": "257",
+                    "amount": "1000000",
+                    "destination": "KT1PWx2mnDueood7fEmfbBDKx1D9BAnnXitn",
+                    "parameters": {
+                        "entrypoint": "transfer",
+                        "value": {"prim": "Pair", "args": [{"string": SOURCE}, {"int": "12"}]}
+                    }
+                },
+                {
+                    "kind": "delegation",
+                    "source": SOURCE,
+                    "fee": "1000",
+                    "counter": "3",
+                    "gas_limit": "1100",
+                    "storage_limit": "0"
+                }
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn forge_round_trip_ok() {
+        let operation = mock_operation();
+
+        let forged = operation.forge_unsigned().unwrap();
+        assert_eq!(Operation::from_unsigned_bytes(&forged).unwrap(), operation);
+        assert_eq!(operation.contents[1].kind(), OperationKind::Transaction);
+        assert_eq!(operation.contents[2].manager().unwrap().counter, 3);
+    }
+
+    #[test]
+    fn origination_and_votes_round_trip_ok() {
+        let operation: Operation = serde_json::from_value(json!({
+            "branch": BRANCH,
+            "contents": [
+                {
+                    "kind": "ballot",
+                    "source": SOURCE,
+                    "period": 120,
+                    "proposal": "PsQuebecnLByd3JwTiGadoG4nGWi3HYiLXUjkibeFV8dCFeVMUg",
+                    "ballot": "nay"
+                },
+                {
+                    "kind": "proposals",
+                    "source": SOURCE,
+                    "period": 121,
+                    "proposals": ["PtParisBxoLz5gzMmn3d9WBQNoPSZakgnkMC2VNuQ3KXfUtUQeZ"]
+                },
+                {
+                    "kind": "origination",
+                    "source": SOURCE,
+                    "fee": "800",
+                    "counter": "4",
+                    "gas_limit": "600",
+                    "storage_limit": "300",
+                    "balance": "0",
+                    "delegate": SOURCE,
+                    "script": {
+                        "code": [{"prim": "parameter", "args": [{"prim": "unit"}]}],
+                        "storage": {"prim": "Unit"}
+                    }
+                }
+            ]
+        }))
+        .unwrap();
+
+        let forged = operation.forge_unsigned().unwrap();
+        assert_eq!(Operation::from_unsigned_bytes(&forged).unwrap(), operation);
+        assert!(operation.contents[0].manager().is_none());
+        assert_eq!(operation.contents[2].source(), SOURCE);
+    }
+
+    #[test]
+    fn delegation_binary_form_ok() {
+        let delegation = r#"{"kind":"delegation","source":"tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx",
+            "fee":"1000","counter":"3","gas_limit":"1100","storage_limit":"0",
+            "delegate":"tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx"}"#;
+        let operation = Operation {
+            branch: BRANCH.to_string(),
+            contents: vec![serde_json::from_str(delegation).unwrap()],
+            signature: None,
+        };
+
+        let forged = hex::encode(operation.forge_unsigned().unwrap());
+        let pkh = "0002298c03ed7d454a101eb7022bc95f7e5f41ac78";
+        assert_eq!(
+            forged,
+            format!(
+                "8fcf233671b6a04fcf679d2a381c2544ea6c1ea29ba6157776ed8424c7ccd00b\
+                 6e{}e80703cc0800ff{}",
+                pkh, pkh
+            )
+        );
+    }
+
+    #[test]
+    fn decode_signed_delegation_hash_ok() {
+        let pkh = "0002298c03ed7d454a101eb7022bc95f7e5f41ac78";
+        let unsigned_hex = format!(
+            "8fcf233671b6a04fcf679d2a381c2544ea6c1ea29ba6157776ed8424c7ccd00b\
+             6e{}e80703cc0800ff{}",
+            pkh, pkh
+        );
+        let signed_hex = format!("{}{}", unsigned_hex, "01".repeat(64));
+
+        let operation = Operation::from_signed_hex(&signed_hex).unwrap();
+        assert_eq!(
+            operation.forge().unwrap(),
+            hex::decode(&signed_hex).unwrap()
+        );
+        assert_eq!(
+            operation.hash().unwrap(),
+            "ooTLmwxG49o4d5Rrd3Nd4cePavxef5Pc1phgCmhLZWKhrPxBsHo"
+        );
+
+        let padded_counter = unsigned_hex.replacen("e80703cc08", "e8078300cc08", 1);
+        assert!(Operation::from_unsigned_bytes(&hex::decode(padded_counter).unwrap()).is_err());
+    }
+
+    #[test]
+    fn non_canonical_annotations_fail() {
+        let operation: Operation = serde_json::from_value(json!({
+            "branch": BRANCH,
+            "contents": [{
+                "kind": "origination",
+                "source": SOURCE,
+                "fee": "800",
+                "counter": "4",
+                "gas_limit": "600",
+                "storage_limit": "300",
+                "balance": "0",
+                "script": {
+                    "code": [{"prim": "parameter", "args": [{"prim": "nat", "annots": ["%a", "%b"]}]}],
+                    "storage": {"int": "0"}
+                }
+            }]
+        }))
+        .unwrap();
+
+        let forged = hex::encode(operation.forge_unsigned().unwrap());
+        let tab_separated = forged.replacen(&hex::encode("%a %b"), &hex::encode("%a\t%b"), 1);
+        assert_ne!(tab_separated, forged);
+        assert!(Operation::from_unsigned_bytes(&hex::decode(tab_separated).unwrap()).is_err());
+    }
+
+    #[test]
+    fn signed_operation_hash_ok() {
+        let key = ed25519_dalek::SigningKey::from_bytes(&[5; 32]);
+        let mut operation = mock_operation();
+        let digest = crate::crypto::signing_digest(
+            Some(&Watermark::GenericOperation),
+            &operation.forge_unsigned().unwrap(),
+        )
+        .unwrap();
+        let signature = key.sign(&digest).to_bytes();
+        operation.signature = Some(Signature::from_bytes(&signature).unwrap());
+
+        let signed_hex = hex::encode(operation.forge().unwrap());
+        let decoded = Operation::from_signed_hex(&signed_hex).unwrap();
+        let hash = decoded.hash().unwrap();
+
+        assert_eq!(decoded, operation);
+        assert_eq!(
+            hash,
+            operation_hash(&hex::decode(signed_hex).unwrap()).unwrap()
+        );
+        assert!(hash.starts_with('o') && hash.len() == 51);
+        let public_key = PublicKey::Ed25519(key.verifying_key());
+        assert!(decoded.verify(&public_key).unwrap());
+    }
+
+    #[test]
+    fn unsigned_operation_hash_fails() {
+        assert!(mock_operation().hash().is_err());
+    }
+}
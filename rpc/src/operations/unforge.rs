@@ -0,0 +1,183 @@
+use super::forge::{
+    BALLOT_TAG, DELEGATION_TAG, ENTRYPOINTS, NAMED_ENTRYPOINT_TAG, ORIGINATION_TAG, PROPOSALS_TAG,
+    REVEAL_TAG, TRANSACTION_TAG,
+};
+use super::{
+    Delegation, ManagerFields, Operation, OperationContent, Origination, Parameters, Reveal,
+    Script, Transaction,
+};
+use crate::encoding::reader::Reader;
+use crate::encoding::{base58, forged};
+use crate::errors::ParseError;
+use crate::michelson::pack::unforge_micheline;
+use crate::types::{Ballot, Mutez};
+
+pub(super) fn unforge_operation(bytes: &[u8]) -> Result<Operation, ParseError> {
+    let mut reader = Reader::new(bytes);
+    let branch = base58::encode(base58::BLOCK_HASH, reader.read(32)?)?;
+    let mut contents = Vec::new();
+    while !reader.is_empty() {
+        contents.push(read_content(&mut reader)?);
+    }
+    if contents.is_empty() {
+        return Err(ParseError::ResponseParsingError(
+            "operation has no contents".to_string(),
+        ));
+    }
+
+    Ok(Operation {
+        branch,
+        contents,
+        signature: None,
+    })
+}
+
+fn read_content(reader: &mut Reader) -> Result<OperationContent, ParseError> {
+    let content = match reader.read_u8()? {
+        PROPOSALS_TAG => {
+            let source = read_public_key_hash(reader)?;
+            let period = i32::from_be_bytes(reader.read_array()?);
+            let mut proposals_reader = Reader::new(reader.read_dynamic()?);
+            let mut proposals = Vec::new();
+            while !proposals_reader.is_empty() {
+                let proposal = proposals_reader.read(32)?;
+                proposals.push(base58::encode(base58::PROTOCOL_HASH, proposal)?);
+            }
+            OperationContent::Proposals {
+                source,
+                period,
+                proposals,
+            }
+        }
+        BALLOT_TAG => {
+            let source = read_public_key_hash(reader)?;
+            let period = i32::from_be_bytes(reader.read_array()?);
+            let proposal = base58::encode(base58::PROTOCOL_HASH, reader.read(32)?)?;
+            let ballot = match reader.read_u8()? {
+                0 => Ballot::Yay,
+                1 => Ballot::Nay,
+                2 => Ballot::Pass,
+                other => return Err(unforge_error(format!("unknown ballot {}", other))),
+            };
+            OperationContent::Ballot {
+                source,
+                period,
+                proposal,
+                ballot,
+            }
+        }
+        REVEAL_TAG => {
+            let manager = read_manager_fields(reader)?;
+            let public_key = read_public_key(reader)?;
+            OperationContent::Reveal(Reveal {
+                manager,
+                public_key,
+            })
+        }
+        TRANSACTION_TAG => {
+            let manager = read_manager_fields(reader)?;
+            let amount = Mutez::new(reader.read_natural()?);
+            let destination = forged::decode_address(reader.read(22)?)?;
+            let parameters = if read_bool(reader)? {
+                Some(read_parameters(reader)?)
+            } else {
+                None
+            };
+            OperationContent::Transaction(Transaction {
+                manager,
+                amount,
+                destination,
+                parameters,
+            })
+        }
+        ORIGINATION_TAG => {
+            let manager = read_manager_fields(reader)?;
+            let balance = Mutez::new(reader.read_natural()?);
+            let delegate = read_optional_public_key_hash(reader)?;
+            let code = unforge_micheline(reader.read_dynamic()?)?;
+            let storage = unforge_micheline(reader.read_dynamic()?)?;
+            OperationContent::Origination(Origination {
+                manager,
+                balance,
+                delegate,
+                script: Script { code, storage },
+            })
+        }
+        DELEGATION_TAG => {
+            let manager = read_manager_fields(reader)?;
+            let delegate = read_optional_public_key_hash(reader)?;
+            OperationContent::Delegation(Delegation { manager, delegate })
+        }
+        tag => {
+            return Err(unforge_error(format!(
+                "unsupported operation content tag {}",
+                tag
+            )))
+        }
+    };
+    Ok(content)
+}
+
+fn read_manager_fields(reader: &mut Reader) -> Result<ManagerFields, ParseError> {
+    Ok(ManagerFields {
+        source: read_public_key_hash(reader)?,
+        fee: Mutez::new(reader.read_natural()?),
+        counter: reader.read_natural()?,
+        gas_limit: reader.read_natural()?,
+        storage_limit: reader.read_natural()?,
+    })
+}
+
+fn read_parameters(reader: &mut Reader) -> Result<Parameters, ParseError> {
+    let entrypoint = match reader.read_u8()? {
+        NAMED_ENTRYPOINT_TAG => {
+            let length = reader.read_u8()?;
+            String::from_utf8(reader.read(length as usize)?.to_vec())
+                .map_err(|error| unforge_error(error.to_string()))?
+        }
+        tag => ENTRYPOINTS
+            .get(tag as usize)
+            .ok_or_else(|| unforge_error(format!("unknown entrypoint tag {}", tag)))?
+            .to_string(),
+    };
+    let value = unforge_micheline(reader.read_dynamic()?)?;
+    Ok(Parameters { entrypoint, value })
+}
+
+fn read_public_key_hash(reader: &mut Reader) -> Result<String, ParseError> {
+    forged::decode_public_key_hash(reader.read(21)?)
+}
+
+fn read_optional_public_key_hash(reader: &mut Reader) -> Result<Option<String>, ParseError> {
+    if read_bool(reader)? {
+        read_public_key_hash(reader).map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+/// Reads a tagged public key, whose length depends on its curve.
+fn read_public_key(reader: &mut Reader) -> Result<String, ParseError> {
+    let tag = reader.read_u8()?;
+    let length = match tag {
+        0 => 32,
+        1 | 2 => 33,
+        3 => 48,
+        tag => return Err(unforge_error(format!("unknown public key tag {}", tag))),
+    };
+    let mut tagged = vec![tag];
+    tagged.extend(reader.read(length)?);
+    forged::decode_public_key(&tagged)
+}
+
+fn read_bool(reader: &mut Reader) -> Result<bool, ParseError> {
+    match reader.read_u8()? {
+        0x00 => Ok(false),
+        0xff => Ok(true),
+        other => Err(unforge_error(format!("invalid boolean byte {}", other))),
+    }
+}
+
+fn unforge_error(detail: String) -> ParseError {
+    ParseError::ResponseParsingError(detail)
+}
@@ -0,0 +1,86 @@
+use crate::errors::ParseError;
+use crate::responses::Response;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::fmt;
+
+/// The right of `delegate` to bake the block at `level` and `round`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct BakingRight {
+    pub level: i32,
+    pub delegate: String,
+    /// Called priority before the Ithaca protocol.
+    #[serde(alias = "priority")]
+    pub round: u32,
+    /// When the block can be baked, absent for levels already in the past.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimated_time: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub consensus_key: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BakingRightsResponse {
+    pub rights: Vec<BakingRight>,
+}
+
+impl BakingRightsResponse {
+    pub fn for_delegate<'a>(&'a self, delegate: &'a str) -> impl Iterator<Item = &'a BakingRight> {
+        self.rights
+            .iter()
+            .filter(move |right| right.delegate == delegate)
+    }
+}
+
+impl fmt::Display for BakingRightsResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for BakingRightsResponse {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let rights = serde_json::from_str(response)?;
+
+        Ok(Self { rights })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_baking_rights_ok() {
+        let mock_response = r#"[
+            {"level":5000,"delegate":"tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx","round":0,"estimated_time":"2024-03-01T12:00:00Z","consensus_key":"tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx"},
+            {"level":5000,"delegate":"tz1VkjQjvH3NTVdFtGz9tWutgxLKwnhnMe2x","round":1,"estimated_time":"2024-03-01T12:00:08Z","consensus_key":"tz1VkjQjvH3NTVdFtGz9tWutgxLKwnhnMe2x"}
+        ]"#;
+
+        let response = BakingRightsResponse::from_response_str(mock_response).unwrap();
+        assert_eq!(response.rights.len(), 2);
+
+        let rights: Vec<_> = response
+            .for_delegate("tz1VkjQjvH3NTVdFtGz9tWutgxLKwnhnMe2x")
+            .collect();
+        assert_eq!(rights.len(), 1);
+        assert_eq!(rights[0].round, 1);
+        assert_eq!(
+            rights[0].estimated_time.unwrap().timestamp(),
+            DateTime::parse_from_rfc3339("2024-03-01T12:00:08Z")
+                .unwrap()
+                .timestamp()
+        );
+    }
+
+    #[test]
+    fn parse_legacy_baking_rights_ok() {
+        let mock_response =
+            r#"[{"level":100,"delegate":"tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx","priority":2}]"#;
+
+        let response = BakingRightsResponse::from_response_str(mock_response).unwrap();
+        assert_eq!(response.rights[0].round, 2);
+        assert!(response.rights[0].estimated_time.is_none());
+    }
+}
@@ -52,7 +52,7 @@ mod test {
     fn test_empty_string_fail() {
         let mock_response = "";
 
-        let response_result = BalanceResponse::from_response_str(&mock_response);
+        let response_result = BalanceResponse::from_response_str(mock_response);
         assert!(response_result.is_err());
     }
 
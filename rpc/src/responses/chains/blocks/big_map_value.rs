@@ -0,0 +1,51 @@
+use crate::errors::ParseError;
+use crate::michelson::Micheline;
+use crate::responses::Response;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BigMapValueResponse {
+    /// `None` when the key is not bound in the big map, in which case the
+    /// node answers `404 Not Found`, see
+    /// [`GetBigMapValue`](crate::commands::chains::blocks::get_big_map_value::GetBigMapValue).
+    pub value: Option<Micheline>,
+}
+
+impl fmt::Display for BigMapValueResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for BigMapValueResponse {
+    /// Parses a response string holding a
+    /// `$micheline.michelson_v1.expression` into a
+    /// [`BigMapValueResponse`](Self).
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let value = serde_json::from_str(response)?;
+        Ok(Self { value: Some(value) })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_bound_value_ok() {
+        let response = BigMapValueResponse::from_response_str(r#"{"int":"12"}"#).unwrap();
+        assert_eq!(response.value, Some(Micheline::int(12)));
+    }
+
+    #[test]
+    fn parse_empty_body_fails() {
+        assert!(BigMapValueResponse::from_response_str("").is_err());
+    }
+
+    #[test]
+    fn parse_malformed_value_fails() {
+        assert!(BigMapValueResponse::from_response_str(r#"{"foo":1}"#).is_err());
+    }
+}
@@ -0,0 +1,513 @@
+use crate::crypto::{PublicKey, Signature, Watermark};
+use crate::encoding::reader::Reader;
+use crate::encoding::{base58, hash};
+use crate::errors::ParseError;
+use crate::responses::Response;
+use crate::types::Protocol;
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::fmt;
+
+/// Header of a block, with the protocol specific fields of Tenderbake
+/// protocols typed and the others kept in `extra`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BlockHeader {
+    pub protocol: Protocol,
+    pub chain_id: String,
+    pub hash: String,
+    pub level: i32,
+    /// Number of protocol changes since genesis.
+    pub proto: u8,
+    pub predecessor: String,
+    pub timestamp: DateTime<Utc>,
+    pub validation_pass: u8,
+    pub operations_hash: String,
+    pub fitness: Vec<String>,
+    pub context: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload_round: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proof_of_work_nonce: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed_nonce_hash: Option<String>,
+    pub signature: String,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl BlockHeader {
+    /// Checks that `raw` is the binary form of this header, that the block
+    /// belongs to the trusted `chain_id` and `protocol`, and that it is
+    /// signed by `public_key`, the key of the baker or its consensus key.
+    ///
+    /// Fails if the header's fields do not match `raw`'s, which happens when
+    /// a node serves a header that differs from the block it claims to
+    /// describe, or if `protocol` is unknown.
+    pub fn verify(
+        &self,
+        raw: &RawBlockHeaderResponse,
+        chain_id: &str,
+        protocol: &Protocol,
+        public_key: &PublicKey,
+    ) -> Result<bool, ParseError> {
+        if self.chain_id != chain_id || self.protocol != *protocol {
+            let detail = format!(
+                "block {} is a {} block of chain {}, expected a {} block of chain {}",
+                self.hash,
+                self.protocol.name(),
+                self.chain_id,
+                protocol.name(),
+                chain_id
+            );
+            return Err(ParseError::ResponseParsingError(detail));
+        }
+
+        let shell = raw.shell_header()?;
+        let data = raw.protocol_data(protocol)?;
+        let matches_raw = raw.block_hash() == self.hash
+            && shell.level == self.level
+            && shell.proto == self.proto
+            && shell.predecessor == self.predecessor
+            && shell.timestamp == self.timestamp
+            && shell.validation_pass == self.validation_pass
+            && shell.operations_hash == self.operations_hash
+            && shell.fitness == self.fitness
+            && shell.context == self.context
+            && data.payload_hash == self.payload_hash
+            && data.payload_round == self.payload_round
+            && Some(&data.proof_of_work_nonce) == self.proof_of_work_nonce.as_ref()
+            && data.seed_nonce_hash == self.seed_nonce_hash
+            && data.extra == self.extra
+            && data.signature == Signature::from_base58(&self.signature)?;
+        if !matches_raw {
+            let detail = format!("header of block {} does not match its raw bytes", self.hash);
+            return Err(ParseError::ResponseParsingError(detail));
+        }
+
+        let watermark = Watermark::block(protocol, chain_id)?;
+        raw.verify_signature(&watermark, public_key)
+    }
+}
+
+impl fmt::Display for BlockHeader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for BlockHeader {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        Ok(serde_json::from_str(response)?)
+    }
+}
+
+/// Fields of a header common to all protocols, decoded from its binary form.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct ShellHeader {
+    pub level: i32,
+    pub proto: u8,
+    pub predecessor: String,
+    pub timestamp: DateTime<Utc>,
+    pub validation_pass: u8,
+    pub operations_hash: String,
+    /// Hex encoded fitness elements.
+    pub fitness: Vec<String>,
+    pub context: String,
+}
+
+/// Fields of a header specific to the protocol of its block, decoded from
+/// its binary form.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProtocolData {
+    /// Hash of the block's payload, since Ithaca.
+    pub payload_hash: Option<String>,
+    /// Round at which the payload was first proposed, since Ithaca.
+    pub payload_round: Option<i32>,
+    /// Hex encoded nonce.
+    pub proof_of_work_nonce: String,
+    pub seed_nonce_hash: Option<String>,
+    /// Fields kept in [`BlockHeader::extra`], in their JSON form: the
+    /// priority of blocks before Ithaca and the per-block votes.
+    pub extra: Map<String, Value>,
+    pub signature: Signature,
+}
+
+/// Binary form of a block header, as signed by its baker.
+#[derive(Debug)]
+pub struct RawBlockHeaderResponse {
+    pub bytes: Vec<u8>,
+}
+
+impl RawBlockHeaderResponse {
+    /// `B...` hash of the header, which is the hash of the block.
+    pub fn block_hash(&self) -> String {
+        base58::encode(base58::BLOCK_HASH, &hash::blake2b_256(&self.bytes))
+            .expect("blake2b_256 digests are 32 bytes long")
+    }
+
+    /// Header bytes without the trailing signature.
+    pub fn unsigned_bytes(&self) -> Result<&[u8], ParseError> {
+        Ok(self.split_signature()?.0)
+    }
+
+    pub fn signature(&self) -> Result<Signature, ParseError> {
+        Signature::from_bytes(self.split_signature()?.1)
+    }
+
+    /// Checks the header's signature, watermarked with `watermark`
+    /// (see [`Watermark::block()`]).
+    pub fn verify_signature(
+        &self,
+        watermark: &Watermark,
+        public_key: &PublicKey,
+    ) -> Result<bool, ParseError> {
+        let (unsigned_bytes, signature) = self.split_signature()?;
+        public_key.verify(
+            Some(watermark),
+            unsigned_bytes,
+            &Signature::from_bytes(signature)?,
+        )
+    }
+
+    pub fn shell_header(&self) -> Result<ShellHeader, ParseError> {
+        read_shell_header(&mut Reader::new(&self.bytes))
+    }
+
+    /// Decodes the fields following the shell header, laid out as in
+    /// blocks of `protocol`.
+    pub fn protocol_data(&self, protocol: &Protocol) -> Result<ProtocolData, ParseError> {
+        let mut reader = Reader::new(self.unsigned_bytes()?);
+        read_shell_header(&mut reader)?;
+
+        let mut extra = Map::new();
+        let (payload_hash, payload_round) = match protocol {
+            Protocol::Genesis | Protocol::Unknown(_) => {
+                let detail = format!("cannot decode {} block headers", protocol.name());
+                return Err(ParseError::ResponseParsingError(detail));
+            }
+            protocol if *protocol < Protocol::Ithaca => {
+                let priority = u16::from_be_bytes(reader.read_array()?);
+                extra.insert("priority".to_string(), Value::from(priority));
+                (None, None)
+            }
+            _ => {
+                let payload_hash = base58::encode(base58::BLOCK_PAYLOAD_HASH, reader.read(32)?)?;
+                let payload_round = i32::from_be_bytes(reader.read_array()?);
+                (Some(payload_hash), Some(payload_round))
+            }
+        };
+        let proof_of_work_nonce = hex::encode(reader.read(8)?);
+        let seed_nonce_hash = match reader.read_u8()? {
+            0x00 => None,
+            _ => Some(base58::encode(base58::NONCE_HASH, reader.read(32)?)?),
+        };
+
+        if *protocol >= Protocol::Oxford {
+            let votes = reader.read_u8()?;
+            extra.insert(
+                "liquidity_baking_toggle_vote".to_string(),
+                Value::from(decode_vote(votes & 0b11)?),
+            );
+            extra.insert(
+                "adaptive_issuance_vote".to_string(),
+                Value::from(decode_vote(votes >> 2)?),
+            );
+        } else if *protocol >= Protocol::Jakarta {
+            let vote = decode_vote(reader.read_u8()?)?;
+            extra.insert(
+                "liquidity_baking_toggle_vote".to_string(),
+                Value::from(vote),
+            );
+        } else if *protocol >= Protocol::Granada {
+            let vote = reader.read_u8()? != 0x00;
+            extra.insert(
+                "liquidity_baking_escape_vote".to_string(),
+                Value::from(vote),
+            );
+        }
+
+        if !reader.is_empty() {
+            let detail = format!(
+                "{} trailing bytes in {} block header",
+                reader.remaining(),
+                protocol.name()
+            );
+            return Err(ParseError::ResponseParsingError(detail));
+        }
+
+        Ok(ProtocolData {
+            payload_hash,
+            payload_round,
+            proof_of_work_nonce,
+            seed_nonce_hash,
+            extra,
+            signature: self.signature()?,
+        })
+    }
+
+    fn split_signature(&self) -> Result<(&[u8], &[u8]), ParseError> {
+        if self.bytes.len() < 64 {
+            let detail = format!("block header of {} bytes is not signed", self.bytes.len());
+            return Err(ParseError::ResponseParsingError(detail));
+        }
+        Ok(self.bytes.split_at(self.bytes.len() - 64))
+    }
+}
+
+fn read_shell_header(reader: &mut Reader) -> Result<ShellHeader, ParseError> {
+    let level = i32::from_be_bytes(reader.read_array()?);
+    let proto = reader.read_u8()?;
+    let predecessor = base58::encode(base58::BLOCK_HASH, reader.read(32)?)?;
+    let timestamp = i64::from_be_bytes(reader.read_array()?);
+    let timestamp = Utc.timestamp_opt(timestamp, 0).single().ok_or_else(|| {
+        ParseError::ResponseParsingError(format!("invalid header timestamp {}", timestamp))
+    })?;
+    let validation_pass = reader.read_u8()?;
+    let operations_hash = base58::encode(base58::OPERATION_LIST_LIST_HASH, reader.read(32)?)?;
+    let mut fitness_reader = Reader::new(reader.read_dynamic()?);
+    let mut fitness = Vec::new();
+    while !fitness_reader.is_empty() {
+        fitness.push(hex::encode(fitness_reader.read_dynamic()?));
+    }
+    let context = base58::encode(base58::CONTEXT_HASH, reader.read(32)?)?;
+
+    Ok(ShellHeader {
+        level,
+        proto,
+        predecessor,
+        timestamp,
+        validation_pass,
+        operations_hash,
+        fitness,
+        context,
+    })
+}
+
+/// Liquidity baking or adaptive issuance vote, as named in JSON.
+fn decode_vote(vote: u8) -> Result<&'static str, ParseError> {
+    match vote {
+        0 => Ok("on"),
+        1 => Ok("off"),
+        2 => Ok("pass"),
+        _ => Err(ParseError::ResponseParsingError(format!(
+            "invalid per-block vote {}",
+            vote
+        ))),
+    }
+}
+
+impl Serialize for RawBlockHeaderResponse {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::encode(&self.bytes))
+    }
+}
+
+impl fmt::Display for RawBlockHeaderResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for RawBlockHeaderResponse {
+    /// Parses a response string in the form `"00000001..."`.
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let hex_string: String = serde_json::from_str(response)?;
+        let bytes = hex::decode(hex_string)
+            .map_err(|error| ParseError::ResponseParsingError(error.to_string()))?;
+
+        Ok(Self { bytes })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::encoding::forged;
+
+    const CHAIN_ID: &str = "NetXdQprcVkpaWU";
+
+    fn signing_key() -> ed25519_dalek::SigningKey {
+        ed25519_dalek::SigningKey::from_bytes(&[3; 32])
+    }
+
+    /// Shell header followed by a Paris style protocol data, signed with
+    /// `signing_key()`.
+    fn mock_raw_header() -> RawBlockHeaderResponse {
+        let mut bytes = Vec::new();
+        bytes.extend(1_000_i32.to_be_bytes());
+        bytes.push(2);
+        bytes.extend([1; 32]);
+        bytes.extend(1_700_000_000_i64.to_be_bytes());
+        bytes.push(4);
+        bytes.extend([2; 32]);
+        let fitness: Vec<u8> = [vec![0, 0, 0, 1, 2], vec![0, 0, 0, 0]].concat();
+        bytes.extend((fitness.len() as u32).to_be_bytes());
+        bytes.extend(fitness);
+        bytes.extend([3; 32]);
+        bytes.extend([4; 32]);
+        bytes.extend(0_i32.to_be_bytes());
+        bytes.extend([0; 8]);
+        bytes.push(0);
+        bytes.push(0x0a);
+
+        let watermark = Watermark::Block {
+            chain_id: CHAIN_ID.to_string(),
+        };
+        let digest = crate::crypto::signing_digest(Some(&watermark), &bytes).unwrap();
+        let signature = ed25519_dalek::Signer::sign(&signing_key(), &digest);
+        bytes.extend(signature.to_bytes());
+        RawBlockHeaderResponse { bytes }
+    }
+
+    fn mock_header(raw: &RawBlockHeaderResponse) -> BlockHeader {
+        let shell = raw.shell_header().unwrap();
+        let mut header = json!(shell);
+        header.as_object_mut().unwrap().extend(
+            json!({
+                "protocol": Protocol::Paris.hash(),
+                "chain_id": CHAIN_ID,
+                "hash": raw.block_hash(),
+                "payload_hash": base58::encode(base58::BLOCK_PAYLOAD_HASH, &[4; 32]).unwrap(),
+                "payload_round": 0,
+                "proof_of_work_nonce": "0000000000000000",
+                "liquidity_baking_toggle_vote": "pass",
+                "adaptive_issuance_vote": "pass",
+                "signature": raw.signature().unwrap().to_string()
+            })
+            .as_object()
+            .unwrap()
+            .clone(),
+        );
+        BlockHeader::from_response_str(&header.to_string()).unwrap()
+    }
+
+    #[test]
+    fn decode_shell_header_ok() {
+        let shell = mock_raw_header().shell_header().unwrap();
+
+        assert_eq!(shell.level, 1000);
+        assert_eq!(shell.validation_pass, 4);
+        assert_eq!(shell.fitness, vec!["02", ""]);
+        assert!(shell.operations_hash.starts_with("LLo"));
+        assert!(shell.context.starts_with("Co"));
+        assert_eq!(shell.timestamp.timestamp(), 1_700_000_000);
+    }
+
+    #[test]
+    fn verify_block_header_ok() {
+        let raw = mock_raw_header();
+        let header = mock_header(&raw);
+        let baker = PublicKey::Ed25519(signing_key().verifying_key());
+        let other =
+            PublicKey::Ed25519(ed25519_dalek::SigningKey::from_bytes(&[4; 32]).verifying_key());
+
+        assert!(header.extra.contains_key("liquidity_baking_toggle_vote"));
+        assert!(header
+            .verify(&raw, CHAIN_ID, &Protocol::Paris, &baker)
+            .unwrap());
+        assert!(!header
+            .verify(&raw, CHAIN_ID, &Protocol::Paris, &other)
+            .unwrap());
+    }
+
+    #[test]
+    fn untrusted_chain_or_protocol_fails() {
+        let raw = mock_raw_header();
+        let header = mock_header(&raw);
+        let baker = PublicKey::Ed25519(signing_key().verifying_key());
+
+        assert!(header
+            .verify(&raw, "NetXnHfVqm9iesp", &Protocol::Paris, &baker)
+            .is_err());
+        assert!(header
+            .verify(&raw, CHAIN_ID, &Protocol::Quebec, &baker)
+            .is_err());
+    }
+
+    #[test]
+    fn forged_header_fails() {
+        let raw = mock_raw_header();
+        let mut header = mock_header(&raw);
+        header.context = base58::encode(base58::CONTEXT_HASH, &[9; 32]).unwrap();
+
+        let baker = PublicKey::Ed25519(signing_key().verifying_key());
+        assert!(header
+            .verify(&raw, CHAIN_ID, &Protocol::Paris, &baker)
+            .is_err());
+    }
+
+    #[test]
+    fn forged_protocol_data_fails() {
+        let raw = mock_raw_header();
+        let baker = PublicKey::Ed25519(signing_key().verifying_key());
+        let tamperings: [fn(&mut BlockHeader); 6] = [
+            |header| {
+                let payload_hash = base58::encode(base58::BLOCK_PAYLOAD_HASH, &[9; 32]);
+                header.payload_hash = Some(payload_hash.unwrap());
+            },
+            |header| header.payload_round = Some(1),
+            |header| header.proof_of_work_nonce = Some("0000000000000001".to_string()),
+            |header| {
+                let nonce_hash = base58::encode(base58::NONCE_HASH, &[5; 32]);
+                header.seed_nonce_hash = Some(nonce_hash.unwrap());
+            },
+            |header| header.extra["adaptive_issuance_vote"] = json!("on"),
+            |header| header.signature = Signature::from_bytes(&[7; 64]).unwrap().to_string(),
+        ];
+
+        for tamper in tamperings {
+            let mut header = mock_header(&raw);
+            tamper(&mut header);
+            assert!(header
+                .verify(&raw, CHAIN_ID, &Protocol::Paris, &baker)
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn decode_emmy_protocol_data_ok() {
+        let mut raw = mock_raw_header();
+        let shell_length = raw.bytes.len() - 64 - 32 - 4 - 8 - 2;
+        raw.bytes.truncate(shell_length);
+        raw.bytes.extend(3_u16.to_be_bytes());
+        raw.bytes.extend([1; 8]);
+        raw.bytes.push(0xff);
+        raw.bytes.extend([5; 32]);
+        raw.bytes.push(0x00);
+        raw.bytes.extend([0; 64]);
+
+        let data = raw.protocol_data(&Protocol::Hangzhou).unwrap();
+        assert_eq!(data.payload_hash, None);
+        assert_eq!(data.proof_of_work_nonce, "0101010101010101");
+        assert!(data.seed_nonce_hash.unwrap().starts_with("nce"));
+        assert_eq!(data.extra["priority"], json!(3));
+        assert_eq!(data.extra["liquidity_baking_escape_vote"], json!(false));
+        assert!(raw.protocol_data(&Protocol::Paris).is_err());
+    }
+
+    #[test]
+    fn tampered_raw_header_fails_signature() {
+        let mut raw = mock_raw_header();
+        raw.bytes[0] ^= 1;
+
+        let watermark = Watermark::Block {
+            chain_id: CHAIN_ID.to_string(),
+        };
+        let baker = PublicKey::Ed25519(signing_key().verifying_key());
+        assert!(!raw.verify_signature(&watermark, &baker).unwrap());
+        assert_eq!(
+            forged::encode_chain_id(CHAIN_ID).unwrap(),
+            vec![0x7a, 0x06, 0xa7, 0x70]
+        );
+    }
+
+    #[test]
+    fn parse_raw_header_ok() {
+        let raw = RawBlockHeaderResponse::from_response_str(r#""0000""#).unwrap();
+        assert_eq!(raw.bytes, vec![0, 0]);
+        assert!(raw.signature().is_err());
+    }
+}
@@ -12,7 +12,7 @@ pub struct BlocksInChainResponse {
 
 impl fmt::Display for BlocksInChainResponse {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", json!(self).to_string())
+        write!(f, "{}", json!(self))
     }
 }
 
@@ -42,12 +42,12 @@ mod test {
         assert!(blocks_response.is_ok());
 
         let blocks = blocks_response.unwrap().block_ids.into_vec();
-        let mut zipped_tuple_iter = mock_arr_1
+        let zipped_tuple_iter = mock_arr_1
             .iter()
             .chain(mock_arr_2.iter())
             .zip(blocks.into_iter().flatten());
 
-        while let Some(tuple) = zipped_tuple_iter.next() {
+        for tuple in zipped_tuple_iter {
             let mock_str = *tuple.0;
             let parsed_response = tuple.1;
             assert_eq!(parsed_response, mock_str);
@@ -67,12 +67,12 @@ mod test {
         let blocks = blocks_response.unwrap().block_ids.into_vec();
         assert_eq!(blocks.len(), mock_block_id.len());
 
-        let mut zipped_tuple_iter = mock_block_id
+        let zipped_tuple_iter = mock_block_id
             .iter()
             .flatten()
             .zip(blocks.into_iter().flatten());
 
-        while let Some(tuple) = zipped_tuple_iter.next() {
+        for tuple in zipped_tuple_iter {
             let mock_str = *tuple.0;
             let parsed_response = tuple.1;
             assert_eq!(parsed_response, mock_str);
@@ -159,12 +159,12 @@ mod test {
         let blocks = blocks_response.unwrap().block_ids;
         assert_eq!(blocks.len(), 3);
 
-        let mut zipped_tuple_iter = blocks
+        let zipped_tuple_iter = blocks
             .into_flattened_vec()
             .into_iter()
             .zip(mock_block_ids.iter().flatten());
 
-        while let Some(tuple) = zipped_tuple_iter.next() {
+        for tuple in zipped_tuple_iter {
             let parsed_block_id = tuple.0;
             let mock_block_id = *tuple.1;
 
@@ -0,0 +1,139 @@
+use crate::errors::ParseError;
+use crate::responses::Response;
+use crate::types::HistoryMode;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChainIdResponse {
+    pub chain_id: String,
+}
+
+impl fmt::Display for ChainIdResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for ChainIdResponse {
+    /// Parses a response string in the form `"NetXdQprcVkpaWU"`.
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let chain_id = serde_json::from_str(response)?;
+
+        Ok(Self { chain_id })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct BlockLevelResponse {
+    pub block_hash: String,
+    pub level: i32,
+}
+
+impl fmt::Display for BlockLevelResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for BlockLevelResponse {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        Ok(serde_json::from_str(response)?)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CheckpointResponse {
+    /// Header of the checkpoint block.
+    pub block: Value,
+    pub save_point: i32,
+    pub caboose: i32,
+    pub history_mode: HistoryMode,
+}
+
+impl fmt::Display for CheckpointResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for CheckpointResponse {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        Ok(serde_json::from_str(response)?)
+    }
+}
+
+/// How a node considers itself synchronized with its peers.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncState {
+    Synced,
+    Unsynced,
+    /// The node's peers agree on a head which is not advancing.
+    Stuck,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BootstrappedResponse {
+    pub bootstrapped: bool,
+    pub sync_state: SyncState,
+}
+
+impl BootstrappedResponse {
+    /// Whether the node is bootstrapped and follows the head of the chain.
+    pub fn is_synced(&self) -> bool {
+        self.bootstrapped && self.sync_state == SyncState::Synced
+    }
+}
+
+impl fmt::Display for BootstrappedResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for BootstrappedResponse {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        Ok(serde_json::from_str(response)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_chain_id_ok() {
+        let response = ChainIdResponse::from_response_str(r#""NetXdQprcVkpaWU""#).unwrap();
+        assert_eq!(response.chain_id, "NetXdQprcVkpaWU");
+    }
+
+    #[test]
+    fn parse_block_level_ok() {
+        let mock_response =
+            r#"{"block_hash":"BLockGenesisGenesisGenesisGenesisGenesisf79b5d1CoW2","level":0}"#;
+
+        let response = BlockLevelResponse::from_response_str(mock_response).unwrap();
+        assert_eq!(response.level, 0);
+    }
+
+    #[test]
+    fn parse_bootstrapped_ok() {
+        let synced = r#"{"bootstrapped":true,"sync_state":"synced"}"#;
+        let stuck = r#"{"bootstrapped":true,"sync_state":"stuck"}"#;
+
+        assert!(BootstrappedResponse::from_response_str(synced)
+            .unwrap()
+            .is_synced());
+        assert!(!BootstrappedResponse::from_response_str(stuck)
+            .unwrap()
+            .is_synced());
+    }
+
+    #[test]
+    fn parse_unknown_sync_state_fails() {
+        let mock_response = r#"{"bootstrapped":false,"sync_state":"syncing"}"#;
+        assert!(BootstrappedResponse::from_response_str(mock_response).is_err());
+    }
+}
@@ -0,0 +1,179 @@
+use crate::errors::ParseError;
+use crate::responses::Response;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::fmt;
+
+/// The consensus slots of `delegate` at a given level.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ConsensusSlots {
+    pub delegate: String,
+    pub first_slot: u16,
+    /// Number of slots held, called endorsing power before Oxford.
+    #[serde(alias = "endorsing_power")]
+    pub attestation_power: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub consensus_key: Option<String>,
+    /// Every slot held, only listed before Ithaca, when a delegate's slots
+    /// were not contiguous.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slots: Option<Vec<u16>>,
+}
+
+/// The endorsing (or attestation) rights at `level`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ConsensusRights {
+    pub level: i32,
+    pub delegates: Vec<ConsensusSlots>,
+    /// When the block at `level` is expected, absent for levels already in
+    /// the past.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimated_time: Option<DateTime<Utc>>,
+}
+
+/// Rights returned by the endorsing and attestation rights endpoints, in
+/// the format used from the Ithaca protocol on.
+///
+/// Rights of earlier protocols, listed per level and delegate, are grouped
+/// by level into the same format.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ConsensusRightsResponse {
+    pub rights: Vec<ConsensusRights>,
+}
+
+impl ConsensusRightsResponse {
+    /// Returns the slots of `delegate` along with the level they are at.
+    pub fn for_delegate<'a>(
+        &'a self,
+        delegate: &'a str,
+    ) -> impl Iterator<Item = (&'a ConsensusRights, &'a ConsensusSlots)> {
+        self.rights.iter().flat_map(move |rights| {
+            rights
+                .delegates
+                .iter()
+                .filter(move |slots| slots.delegate == delegate)
+                .map(move |slots| (rights, slots))
+        })
+    }
+}
+
+impl fmt::Display for ConsensusRightsResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+/// Entry of a rights response, either the rights of a level or, before
+/// Ithaca, the slots of one delegate at a level.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RightsEntry {
+    Level(ConsensusRights),
+    LegacySlots {
+        level: i32,
+        delegate: String,
+        slots: Vec<u16>,
+        #[serde(default)]
+        estimated_time: Option<DateTime<Utc>>,
+    },
+}
+
+impl Response for ConsensusRightsResponse {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let entries: Vec<RightsEntry> = serde_json::from_str(response)?;
+
+        let mut rights: Vec<ConsensusRights> = Vec::new();
+        for entry in entries {
+            match entry {
+                RightsEntry::Level(level_rights) => rights.push(level_rights),
+                RightsEntry::LegacySlots {
+                    level,
+                    delegate,
+                    slots,
+                    estimated_time,
+                } => {
+                    let delegate_slots = ConsensusSlots {
+                        delegate,
+                        first_slot: slots.iter().copied().min().unwrap_or_default(),
+                        attestation_power: slots.len() as u32,
+                        consensus_key: None,
+                        slots: Some(slots),
+                    };
+                    match rights.iter_mut().find(|rights| rights.level == level) {
+                        Some(level_rights) => level_rights.delegates.push(delegate_slots),
+                        None => rights.push(ConsensusRights {
+                            level,
+                            delegates: vec![delegate_slots],
+                            estimated_time,
+                        }),
+                    }
+                }
+            }
+        }
+
+        Ok(Self { rights })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_attestation_rights_ok() {
+        let mock_response = r#"[{
+            "level": 5000,
+            "delegates": [
+                {"delegate":"tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx","first_slot":0,"attestation_power":1500,"consensus_key":"tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx"},
+                {"delegate":"tz1VkjQjvH3NTVdFtGz9tWutgxLKwnhnMe2x","first_slot":3,"attestation_power":5000,"consensus_key":"tz1VkjQjvH3NTVdFtGz9tWutgxLKwnhnMe2x"}
+            ],
+            "estimated_time": "2024-03-01T12:00:00Z"
+        }]"#;
+
+        let response = ConsensusRightsResponse::from_response_str(mock_response).unwrap();
+        let slots: Vec<_> = response
+            .for_delegate("tz1VkjQjvH3NTVdFtGz9tWutgxLKwnhnMe2x")
+            .collect();
+        assert_eq!(slots.len(), 1);
+        assert_eq!(slots[0].0.level, 5000);
+        assert_eq!(slots[0].1.attestation_power, 5000);
+    }
+
+    #[test]
+    fn parse_endorsing_rights_ok() {
+        let mock_response = r#"[{"level":100,"delegates":[{"delegate":"tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx","first_slot":12,"endorsing_power":40}]}]"#;
+
+        let response = ConsensusRightsResponse::from_response_str(mock_response).unwrap();
+        assert_eq!(response.rights[0].delegates[0].attestation_power, 40);
+        assert!(response.rights[0].estimated_time.is_none());
+    }
+
+    #[test]
+    fn parse_pre_ithaca_rights_ok() {
+        let mock_response = r#"[
+            {"level":100,"delegate":"tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx","slots":[7,2,30]},
+            {"level":100,"delegate":"tz1VkjQjvH3NTVdFtGz9tWutgxLKwnhnMe2x","slots":[0]},
+            {"level":101,"delegate":"tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx","slots":[5],"estimated_time":"2021-03-01T12:00:00Z"}
+        ]"#;
+
+        let response = ConsensusRightsResponse::from_response_str(mock_response).unwrap();
+        assert_eq!(response.rights.len(), 2);
+        assert_eq!(response.rights[0].delegates.len(), 2);
+        assert!(response.rights[1].estimated_time.is_some());
+
+        let slots: Vec<_> = response
+            .for_delegate("tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx")
+            .collect();
+        assert_eq!(slots[0].1.first_slot, 2);
+        assert_eq!(slots[0].1.attestation_power, 3);
+        assert_eq!(slots[0].1.slots, Some(vec![7, 2, 30]));
+        assert_eq!(slots[1].0.level, 101);
+    }
+
+    #[test]
+    fn parse_malformed_rights_fails() {
+        let mock_response = r#"[{"level":100,"delegate":"tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx"}]"#;
+        assert!(ConsensusRightsResponse::from_response_str(mock_response).is_err());
+    }
+}
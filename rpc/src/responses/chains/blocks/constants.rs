@@ -0,0 +1,185 @@
+use crate::errors::ParseError;
+use crate::responses::Response;
+use crate::types::{int64, Mutez};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::fmt;
+
+/// Constants of a protocol, as returned by `context/constants`.
+///
+/// Constants which only exist in some protocols are optional, and all
+/// constants this type does not model are kept in `extra`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ProtocolConstants {
+    pub proof_of_work_nonce_size: u8,
+    pub nonce_length: u8,
+    pub max_anon_ops_per_block: i32,
+    pub max_operation_data_length: i32,
+    pub max_proposals_per_delegate: u8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_micheline_node_count: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_micheline_bytes_limit: Option<i32>,
+    /// Replaced by `consensus_rights_delay` and `blocks_preservation_cycles`
+    /// in the Paris protocol.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preserved_cycles: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub consensus_rights_delay: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocks_preservation_cycles: Option<u8>,
+    pub blocks_per_cycle: i32,
+    pub blocks_per_commitment: i32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nonce_revelation_threshold: Option<i32>,
+    /// Replaced `blocks_per_voting_period` in the Jakarta protocol.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycles_per_voting_period: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocks_per_voting_period: Option<i32>,
+    #[serde(deserialize_with = "int64::deserialize")]
+    pub hard_gas_limit_per_operation: i64,
+    #[serde(deserialize_with = "int64::deserialize")]
+    pub hard_gas_limit_per_block: i64,
+    #[serde(deserialize_with = "int64::deserialize")]
+    pub hard_storage_limit_per_operation: i64,
+    #[serde(deserialize_with = "int64::deserialize")]
+    pub proof_of_work_threshold: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub minimal_stake: Option<Mutez>,
+    pub origination_size: i32,
+    pub cost_per_byte: Mutez,
+    /// Minimal time between blocks in seconds, from the Ithaca protocol on.
+    #[serde(
+        default,
+        deserialize_with = "int64::deserialize_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub minimal_block_delay: Option<i64>,
+    #[serde(
+        default,
+        deserialize_with = "int64::deserialize_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub delay_increment_per_round: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub consensus_committee_size: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub consensus_threshold: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quorum_min: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quorum_max: Option<i32>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl ProtocolConstants {
+    /// Number of cycles between a cycle's snapshot and the cycle whose
+    /// rights it determines, whichever name the protocol gives it.
+    pub fn consensus_rights_delay(&self) -> Option<u8> {
+        self.consensus_rights_delay.or(self.preserved_cycles)
+    }
+
+    /// Number of blocks in a voting period, whichever way the protocol
+    /// expresses it.
+    pub fn blocks_per_voting_period(&self) -> Option<i32> {
+        self.cycles_per_voting_period
+            .map(|cycles| cycles * self.blocks_per_cycle)
+            .or(self.blocks_per_voting_period)
+    }
+}
+
+impl fmt::Display for ProtocolConstants {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for ProtocolConstants {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        Ok(serde_json::from_str(response)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_paris_constants_ok() {
+        let mock_response = r#"{
+            "proof_of_work_nonce_size": 8,
+            "nonce_length": 32,
+            "max_anon_ops_per_block": 132,
+            "max_operation_data_length": 32768,
+            "max_proposals_per_delegate": 20,
+            "max_micheline_node_count": 50000,
+            "max_micheline_bytes_limit": 50000,
+            "max_allowed_global_constants_depth": 10000,
+            "cache_layout_size": 3,
+            "michelson_maximum_type_size": 2001,
+            "consensus_rights_delay": 2,
+            "blocks_preservation_cycles": 1,
+            "delegate_parameters_activation_delay": 5,
+            "blocks_per_cycle": 24576,
+            "blocks_per_commitment": 192,
+            "nonce_revelation_threshold": 768,
+            "cycles_per_voting_period": 5,
+            "hard_gas_limit_per_operation": "1040000",
+            "hard_gas_limit_per_block": "1386666",
+            "proof_of_work_threshold": "-1",
+            "minimal_stake": "6000000000",
+            "origination_size": 257,
+            "cost_per_byte": "250",
+            "hard_storage_limit_per_operation": "60000",
+            "quorum_min": 2000,
+            "quorum_max": 7000,
+            "minimal_block_delay": "10",
+            "delay_increment_per_round": "5",
+            "consensus_committee_size": 7000,
+            "consensus_threshold": 4667
+        }"#;
+
+        let constants = ProtocolConstants::from_response_str(mock_response).unwrap();
+        assert_eq!(constants.blocks_per_cycle, 24576);
+        assert_eq!(constants.hard_gas_limit_per_operation, 1_040_000);
+        assert_eq!(constants.cost_per_byte, Mutez::new(250));
+        assert_eq!(constants.minimal_block_delay, Some(10));
+        assert_eq!(constants.consensus_rights_delay(), Some(2));
+        assert_eq!(constants.blocks_per_voting_period(), Some(5 * 24576));
+        assert_eq!(constants.extra["cache_layout_size"], 3);
+    }
+
+    #[test]
+    fn parse_legacy_constants_ok() {
+        let mock_response = r#"{
+            "proof_of_work_nonce_size": 8,
+            "nonce_length": 32,
+            "max_anon_ops_per_block": 132,
+            "max_operation_data_length": 16384,
+            "max_proposals_per_delegate": 20,
+            "preserved_cycles": 5,
+            "blocks_per_cycle": 4096,
+            "blocks_per_commitment": 32,
+            "blocks_per_voting_period": 32768,
+            "hard_gas_limit_per_operation": "1040000",
+            "hard_gas_limit_per_block": "5200000",
+            "proof_of_work_threshold": "70368744177663",
+            "origination_size": 257,
+            "cost_per_byte": "250",
+            "hard_storage_limit_per_operation": "60000",
+            "time_between_blocks": ["60", "40"]
+        }"#;
+
+        let constants = ProtocolConstants::from_response_str(mock_response).unwrap();
+        assert_eq!(constants.consensus_rights_delay(), Some(5));
+        assert_eq!(constants.blocks_per_voting_period(), Some(32768));
+        assert!(constants.minimal_block_delay.is_none());
+    }
+
+    #[test]
+    fn parse_constants_without_blocks_per_cycle_fails() {
+        assert!(ProtocolConstants::from_response_str(r#"{"nonce_length": 32}"#).is_err());
+    }
+}
@@ -0,0 +1,94 @@
+use crate::errors::ParseError;
+use crate::michelson::{encode_json, Micheline, MichelsonValue, TransactionParameters};
+use crate::responses::Response;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ContractEntrypointsResponse {
+    pub entrypoints: HashMap<String, Micheline>,
+}
+
+impl ContractEntrypointsResponse {
+    /// Builds the parameters of a call to `entrypoint`, encoding the
+    /// JSON-like `argument` against the type returned by the node.
+    pub fn build_parameters(
+        &self,
+        entrypoint: &str,
+        argument: &Value,
+    ) -> Result<TransactionParameters, ParseError> {
+        let entrypoint = entrypoint.strip_prefix('%').unwrap_or(entrypoint);
+        let parameter_type = self.entrypoints.get(entrypoint).ok_or_else(|| {
+            let detail = format!("contract has no entrypoint named {}", entrypoint);
+            ParseError::RequestParsingError(detail)
+        })?;
+
+        Ok(TransactionParameters {
+            entrypoint: entrypoint.to_string(),
+            value: encode_json(parameter_type, argument)?,
+        })
+    }
+
+    /// Same as [`build_parameters`](Self::build_parameters), from an already
+    /// typed argument.
+    pub fn build_typed_parameters(
+        &self,
+        entrypoint: &str,
+        argument: &MichelsonValue,
+    ) -> Result<TransactionParameters, ParseError> {
+        self.build_parameters(entrypoint, &argument.to_json())
+    }
+}
+
+impl fmt::Display for ContractEntrypointsResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for ContractEntrypointsResponse {
+    /// Parses a response string in the form
+    /// `"{ "entrypoints": { "entrypoint_name": $micheline.michelson_v1.expression, ... } }"`
+    /// into a [`ContractEntrypointsResponse`](Self).
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        Ok(serde_json::from_str(response)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn build_parameters_from_entrypoint_type_ok() {
+        let mock_response = r#"{"entrypoints": {
+            "approve": {"prim":"pair","args":[{"prim":"address","annots":[":spender"]},{"prim":"nat","annots":[":value"]}]},
+            "transfer": {"prim":"pair","args":[{"prim":"address","annots":[":from"]},{"prim":"pair","args":[{"prim":"address","annots":[":to"]},{"prim":"nat","annots":[":value"]}]}]}
+        }}"#;
+
+        let response = ContractEntrypointsResponse::from_response_str(mock_response).unwrap();
+        assert_eq!(response.entrypoints.len(), 2);
+
+        let argument = json!([
+            "tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx",
+            "tz1VkjQjvH3NTVdFtGz9tWutgxLKwnhnMe2x",
+            100
+        ]);
+        let parameters = response.build_parameters("%transfer", &argument).unwrap();
+        assert_eq!(parameters.entrypoint, "transfer");
+        assert_eq!(
+            parameters.value.to_string(),
+            r#"{"prim":"Pair","args":[{"string":"tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx"},{"prim":"Pair","args":[{"string":"tz1VkjQjvH3NTVdFtGz9tWutgxLKwnhnMe2x"},{"int":"100"}]}]}"#
+        );
+    }
+
+    #[test]
+    fn build_parameters_for_missing_entrypoint_fails() {
+        let mock_response = r#"{"entrypoints": {}}"#;
+
+        let response = ContractEntrypointsResponse::from_response_str(mock_response).unwrap();
+        assert!(response.build_parameters("transfer", &Value::Null).is_err());
+    }
+}
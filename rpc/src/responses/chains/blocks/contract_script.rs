@@ -0,0 +1,113 @@
+use crate::errors::ParseError;
+use crate::michelson::{Micheline, MichelinePrim, MichelsonValue};
+use crate::responses::Response;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ContractScriptResponse {
+    pub code: Micheline,
+    pub storage: Micheline,
+}
+
+impl ContractScriptResponse {
+    /// Returns the type expression of the contract's `parameter` section.
+    pub fn parameter_type(&self) -> Option<&Micheline> {
+        self.code_section("parameter")
+    }
+
+    /// Returns the type expression of the contract's `storage` section.
+    pub fn storage_type(&self) -> Option<&Micheline> {
+        self.code_section("storage")
+    }
+
+    /// Returns the input and return types of the on-chain view `name`,
+    /// declared with a `view` section in the contract code.
+    pub fn view_types(&self, name: &str) -> Option<(&Micheline, &Micheline)> {
+        self.code_sections()
+            .filter(|prim| prim.prim == "view" && prim.args.len() == 4)
+            .find(|prim| prim.args[0].as_str() == Some(name))
+            .map(|prim| (&prim.args[1], &prim.args[2]))
+    }
+
+    /// Decodes the current storage against the contract's storage type.
+    pub fn decode_storage(&self) -> Result<MichelsonValue, ParseError> {
+        let storage_type = self.storage_type().ok_or_else(|| {
+            ParseError::ResponseParsingError("contract code has no storage section".to_string())
+        })?;
+        MichelsonValue::decode(storage_type, &self.storage)
+    }
+
+    fn code_section(&self, section: &str) -> Option<&Micheline> {
+        self.code_sections()
+            .find(|prim| prim.prim == section)
+            .and_then(|prim| prim.args.first())
+    }
+
+    fn code_sections(&self) -> impl Iterator<Item = &MichelinePrim> {
+        let sections = match &self.code {
+            Micheline::Seq(sections) => sections.as_slice(),
+            _ => &[],
+        };
+        sections.iter().filter_map(Micheline::as_prim)
+    }
+}
+
+impl fmt::Display for ContractScriptResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for ContractScriptResponse {
+    /// Parses a response string in the form
+    /// `"{ "code": $micheline.michelson_v1.expression,
+    ///     "storage": $micheline.michelson_v1.expression }"` into a
+    /// [`ContractScriptResponse`](Self).
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        Ok(serde_json::from_str(response)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn decode_storage_from_script_ok() {
+        let mock_response = r#"{
+            "code": [
+                {"prim":"parameter","args":[{"prim":"nat"}]},
+                {"prim":"storage","args":[{"prim":"pair","args":[
+                    {"prim":"nat","annots":["%counter"]},
+                    {"prim":"string","annots":["%name"]}
+                ]}]},
+                {"prim":"code","args":[[{"prim":"FAILWITH"}]]},
+                {"prim":"view","args":[{"string":"get_counter"},{"prim":"unit"},{"prim":"nat"},[{"prim":"CDR"}]]}
+            ],
+            "storage": {"prim":"Pair","args":[{"int":"7"},{"string":"counter"}]}
+        }"#;
+
+        let response = ContractScriptResponse::from_response_str(mock_response).unwrap();
+        assert!(response.parameter_type().unwrap().is_prim("nat"));
+
+        let (input_type, return_type) = response.view_types("get_counter").unwrap();
+        assert!(input_type.is_prim("unit"));
+        assert!(return_type.is_prim("nat"));
+
+        let storage = response.decode_storage().unwrap();
+        assert_eq!(
+            storage.to_json(),
+            json!({"counter": "7", "name": "counter"})
+        );
+    }
+
+    #[test]
+    fn decode_storage_without_storage_section_fails() {
+        let mock_response = r#"{"code": [], "storage": {"prim":"Unit"}}"#;
+
+        let response = ContractScriptResponse::from_response_str(mock_response).unwrap();
+        assert!(response.decode_storage().is_err());
+    }
+}
@@ -0,0 +1,255 @@
+use crate::errors::ParseError;
+use crate::responses::Response;
+use crate::types::{int64, Mutez};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::fmt;
+
+/// Everything known about a delegate.
+///
+/// Balances and deposits introduced or removed across protocols are
+/// optional, and fields this type does not model are kept in `extra`.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DelegateResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub full_balance: Option<Mutez>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_frozen_deposits: Option<Mutez>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frozen_deposits: Option<Mutez>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub staking_balance: Option<Mutez>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delegated_balance: Option<Mutez>,
+    #[serde(default)]
+    pub delegated_contracts: Vec<String>,
+    pub deactivated: bool,
+    pub grace_period: i32,
+    #[serde(
+        default,
+        deserialize_with = "int64::deserialize_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub voting_power: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remaining_proposals: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active_consensus_key: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl fmt::Display for DelegateResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for DelegateResponse {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        Ok(serde_json::from_str(response)?)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DelegateBalanceResponse {
+    pub balance: Mutez,
+}
+
+impl fmt::Display for DelegateBalanceResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for DelegateBalanceResponse {
+    /// Parses a response string in the form `"1000000"`.
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let balance = serde_json::from_str(response)?;
+
+        Ok(Self { balance })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DelegateDeactivatedResponse {
+    pub deactivated: bool,
+}
+
+impl fmt::Display for DelegateDeactivatedResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for DelegateDeactivatedResponse {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let deactivated = serde_json::from_str(response)?;
+
+        Ok(Self { deactivated })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DelegateGracePeriodResponse {
+    pub cycle: i32,
+}
+
+impl fmt::Display for DelegateGracePeriodResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for DelegateGracePeriodResponse {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let cycle = serde_json::from_str(response)?;
+
+        Ok(Self { cycle })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DelegatedContractsResponse {
+    pub contracts: Vec<String>,
+}
+
+impl fmt::Display for DelegatedContractsResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for DelegatedContractsResponse {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let contracts = serde_json::from_str(response)?;
+
+        Ok(Self { contracts })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct VotingPowerResponse {
+    pub voting_power: i64,
+}
+
+impl fmt::Display for VotingPowerResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for VotingPowerResponse {
+    /// Parses a response string in the form `42` or `"42"`, depending on the
+    /// protocol.
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let value: Value = serde_json::from_str(response)?;
+        let voting_power = int64::deserialize(value)?;
+
+        Ok(Self { voting_power })
+    }
+}
+
+/// Consensus participation of a delegate in the current cycle, in slots
+/// unless stated otherwise.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DelegateParticipationResponse {
+    pub expected_cycle_activity: i64,
+    pub minimal_cycle_activity: i64,
+    pub missed_slots: i64,
+    /// Levels at which the delegate missed at least one slot.
+    pub missed_levels: i64,
+    /// Slots the delegate may still miss while keeping its rewards, negative
+    /// once they are lost.
+    pub remaining_allowed_missed_slots: i64,
+    #[serde(alias = "expected_endorsing_rewards")]
+    pub expected_attesting_rewards: Mutez,
+}
+
+impl fmt::Display for DelegateParticipationResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for DelegateParticipationResponse {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        Ok(serde_json::from_str(response)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_delegate_ok() {
+        let mock_response = r#"{
+            "full_balance": "6000000000",
+            "current_frozen_deposits": "600000000",
+            "frozen_deposits": "600000000",
+            "staking_balance": "8000000000",
+            "delegated_contracts": ["tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx"],
+            "delegated_balance": "2000000000",
+            "deactivated": false,
+            "grace_period": 712,
+            "voting_power": "8000000000",
+            "remaining_proposals": 20,
+            "active_consensus_key": "tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx",
+            "pending_denunciations": false
+        }"#;
+
+        let response = DelegateResponse::from_response_str(mock_response).unwrap();
+        assert_eq!(response.staking_balance, Some(Mutez::new(8_000_000_000)));
+        assert_eq!(response.voting_power, Some(8_000_000_000));
+        assert_eq!(response.grace_period, 712);
+        assert_eq!(response.delegated_contracts.len(), 1);
+        assert_eq!(response.extra["pending_denunciations"], false);
+    }
+
+    #[test]
+    fn parse_legacy_delegate_ok() {
+        let mock_response = r#"{
+            "balance": "6000000000",
+            "staking_balance": "8000000000",
+            "delegated_contracts": [],
+            "deactivated": true,
+            "grace_period": 300,
+            "voting_power": 1000
+        }"#;
+
+        let response = DelegateResponse::from_response_str(mock_response).unwrap();
+        assert!(response.full_balance.is_none());
+        assert!(response.deactivated);
+        assert_eq!(response.voting_power, Some(1000));
+    }
+
+    #[test]
+    fn parse_voting_power_ok() {
+        let number = VotingPowerResponse::from_response_str("1000").unwrap();
+        let string = VotingPowerResponse::from_response_str(r#""1000""#).unwrap();
+        assert_eq!(number.voting_power, 1000);
+        assert_eq!(string.voting_power, 1000);
+    }
+
+    #[test]
+    fn parse_participation_ok() {
+        let mock_response = r#"{
+            "expected_cycle_activity": 2048,
+            "minimal_cycle_activity": 1365,
+            "missed_slots": 12,
+            "missed_levels": 3,
+            "remaining_allowed_missed_slots": 671,
+            "expected_endorsing_rewards": "5600000"
+        }"#;
+
+        let response = DelegateParticipationResponse::from_response_str(mock_response).unwrap();
+        assert_eq!(response.missed_slots, 12);
+        assert_eq!(response.expected_attesting_rewards, Mutez::new(5_600_000));
+    }
+
+    #[test]
+    fn parse_balance_number_fails() {
+        assert!(DelegateBalanceResponse::from_response_str("1000").is_err());
+    }
+}
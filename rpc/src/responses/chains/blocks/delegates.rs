@@ -0,0 +1,44 @@
+use crate::errors::ParseError;
+use crate::responses::Response;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DelegatesResponse {
+    pub delegates: Vec<String>,
+}
+
+impl fmt::Display for DelegatesResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for DelegatesResponse {
+    /// Parses a response string in the form `["tz1...", "tz2..."]`.
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let delegates = serde_json::from_str(response)?;
+
+        Ok(Self { delegates })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_delegates_ok() {
+        let mock_response =
+            r#"["tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx","tz1VkjQjvH3NTVdFtGz9tWutgxLKwnhnMe2x"]"#;
+
+        let response = DelegatesResponse::from_response_str(mock_response).unwrap();
+        assert_eq!(response.delegates.len(), 2);
+    }
+
+    #[test]
+    fn parse_non_array_fails() {
+        assert!(DelegatesResponse::from_response_str(r#"{"delegates":[]}"#).is_err());
+    }
+}
@@ -1,37 +1,195 @@
 use crate::errors::ParseError;
+use crate::michelson::Micheline;
 use crate::responses::{json_array, Response};
-use crate::types::Unistring;
-use serde::{Deserialize, Serialize};
-use serde_json::{json, Value};
-use std::collections::HashMap;
+use crate::types::{Mutez, Unistring};
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{json, Map, Value};
 use std::fmt;
 
-#[derive(Serialize, Deserialize, Debug)]
+/// How long an error is expected to last, as classified by the node.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorKind {
+    Permanent,
+    Temporary,
+    /// The error only holds on the current branch of the chain.
+    Branch,
+}
+
+/// An error reported by the node for an invalid block.
+///
+/// `details` holds the error's fields, typed for the errors listed in
+/// [`ErrorDetails`] and kept as JSON for the others.
+#[derive(Debug, Clone, PartialEq)]
 pub struct InvalidBlockError {
-    pub kind: String,
+    pub kind: ErrorKind,
     pub id: String,
-    pub invalid_block: Unistring,
-    pub error: String,
-    #[serde(flatten)]
-    pub extra_error_info: HashMap<String, Value>,
+    pub details: ErrorDetails,
+}
+
+impl InvalidBlockError {
+    /// The error's id without its protocol prefix, e.g.
+    /// `contract.balance_too_low` for `proto.018-Proxford.contract.balance_too_low`.
+    pub fn name(&self) -> &str {
+        match self.id.strip_prefix("proto.") {
+            Some(protocol_error) => protocol_error
+                .split_once('.')
+                .map(|(_protocol, name)| name)
+                .unwrap_or(protocol_error),
+            None => &self.id,
+        }
+    }
+}
+
+impl Serialize for InvalidBlockError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut fields = Map::new();
+        fields.insert("kind".to_string(), json!(self.kind));
+        fields.insert("id".to_string(), json!(self.id));
+        match serde_json::to_value(&self.details).map_err(ser::Error::custom)? {
+            Value::Object(details) => fields.extend(details),
+            _ => return Err(ser::Error::custom("error details must be an object")),
+        }
+        fields.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for InvalidBlockError {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut fields = Map::deserialize(deserializer)?;
+        let kind = fields
+            .remove("kind")
+            .ok_or_else(|| de::Error::missing_field("kind"))?;
+        let id = fields
+            .remove("id")
+            .ok_or_else(|| de::Error::missing_field("id"))?;
+
+        let mut error = Self {
+            kind: serde_json::from_value(kind).map_err(de::Error::custom)?,
+            id: serde_json::from_value(id).map_err(de::Error::custom)?,
+            details: ErrorDetails::Other(Map::new()),
+        };
+        error.details = ErrorDetails::from_fields(error.name(), fields);
+        Ok(error)
+    }
 }
 
 impl fmt::Display for InvalidBlockError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", json!(self).to_string())
+        write!(f, "{}", json!(self))
+    }
+}
+
+/// Fields of the node errors commonly found in invalid blocks.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum ErrorDetails {
+    /// `validator.invalid_block`
+    InvalidBlock(InvalidBlockDetails),
+    /// `contract.balance_too_low`
+    BalanceTooLow(BalanceTooLowDetails),
+    /// `contract.non_existing_contract`
+    NonExistingContract(ContractDetails),
+    /// `contract.counter_in_the_past` and `contract.counter_in_the_future`
+    Counter(CounterDetails),
+    /// `michelson_v1.script_rejected`
+    ScriptRejected(ScriptRejectedDetails),
+    /// Any other error, or a known one whose fields did not match.
+    Other(Map<String, Value>),
+}
+
+impl ErrorDetails {
+    fn from_fields(name: &str, fields: Map<String, Value>) -> Self {
+        let value = Value::Object(fields.clone());
+        let details = match name {
+            "validator.invalid_block" => serde_json::from_value(value).map(Self::InvalidBlock),
+            "contract.balance_too_low" => serde_json::from_value(value).map(Self::BalanceTooLow),
+            "contract.non_existing_contract" => {
+                serde_json::from_value(value).map(Self::NonExistingContract)
+            }
+            "contract.counter_in_the_past" | "contract.counter_in_the_future" => {
+                serde_json::from_value(value).map(Self::Counter)
+            }
+            "michelson_v1.script_rejected" => {
+                serde_json::from_value(value).map(Self::ScriptRejected)
+            }
+            _ => return Self::Other(fields),
+        };
+
+        details.unwrap_or(Self::Other(fields))
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct InvalidBlockDetails {
+    pub invalid_block: Unistring,
+    pub error: String,
+    /// The operation which could not be parsed or applied, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operation: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct BalanceTooLowDetails {
+    pub contract: String,
+    pub balance: Mutez,
+    pub amount: Mutez,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ContractDetails {
+    pub contract: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CounterDetails {
+    pub contract: String,
+    pub expected: String,
+    pub found: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ScriptRejectedDetails {
+    pub location: i64,
+    pub with: Micheline,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct InvalidBlock {
     pub block: Unistring,
     pub level: i32,
-    pub errors: InvalidBlockError,
+    /// The node reports a list of errors; single error objects are accepted
+    /// as well.
+    #[serde(deserialize_with = "deserialize_errors")]
+    pub errors: Vec<InvalidBlockError>,
+}
+
+fn deserialize_errors<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<InvalidBlockError>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        Many(Vec<InvalidBlockError>),
+        One(InvalidBlockError),
+    }
+
+    match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::Many(errors) => Ok(errors),
+        OneOrMany::One(error) => Ok(vec![error]),
+    }
 }
 
 impl fmt::Display for InvalidBlock {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", json!(self).to_string())
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for InvalidBlock {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        Ok(serde_json::from_str(response)?)
     }
 }
 
@@ -104,14 +262,57 @@ mod test {
         assert!(invalid_blocks_response.is_err());
     }
 
+    #[test]
+    fn single_error_object_is_accepted_ok() {
+        let mock_response = r#"{
+            "block": "blockId1",
+            "level": 1,
+            "errors": {"kind": "permanent", "id": "validator.invalid_block", "invalid_block": "blockId1", "error": "invalid_predecessor"}
+        }"#;
+
+        let invalid_block = InvalidBlock::from_response_str(mock_response).unwrap();
+        assert_eq!(invalid_block.errors.len(), 1);
+        match &invalid_block.errors[0].details {
+            ErrorDetails::InvalidBlock(details) => {
+                assert_eq!(details.error, "invalid_predecessor");
+                assert!(details.operation.is_none());
+            }
+            other => panic!("unexpected details {:?}", other),
+        }
+    }
+
+    #[test]
+    fn typed_protocol_error_details_ok() {
+        let mock_response = r#"{
+            "block": "blockId1",
+            "level": 1,
+            "errors": [
+                {"kind": "temporary", "id": "proto.018-Proxford.contract.balance_too_low", "contract": "tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx", "balance": "10", "amount": "20"},
+                {"kind": "branch", "id": "proto.018-Proxford.unknown_error", "foo": 1}
+            ]
+        }"#;
+
+        let invalid_block = InvalidBlock::from_response_str(mock_response).unwrap();
+        let balance_error = &invalid_block.errors[0];
+        assert_eq!(balance_error.name(), "contract.balance_too_low");
+        assert_eq!(balance_error.kind, ErrorKind::Temporary);
+        match &balance_error.details {
+            ErrorDetails::BalanceTooLow(details) => assert_eq!(details.amount, Mutez::new(20)),
+            other => panic!("unexpected details {:?}", other),
+        }
+
+        match &invalid_block.errors[1].details {
+            ErrorDetails::Other(fields) => assert_eq!(fields["foo"], 1),
+            other => panic!("unexpected details {:?}", other),
+        }
+    }
+
     fn generate_valid_mock_response_string() -> String {
         let mock_block = "blockId1";
         let mock_level = 1;
         let mock_error_response = generate_valid_mock_error_response_string();
 
-        let mock_response =
-            format_response_data_as_string(mock_block, mock_level, &mock_error_response);
-        mock_response
+        format_response_data_as_string(mock_block, mock_level, &mock_error_response)
     }
 
     fn generate_valid_mock_error_response_string() -> String {
@@ -122,16 +323,14 @@ mod test {
         let mock_extra_key = "operation";
         let mock_extra_value = "operationHash1";
 
-        let mock_error_response = format_error_response_data_as_string(
+        format_error_response_data_as_string(
             mock_kind,
             mock_id,
             mock_invalid_block_hash,
             mock_error,
             mock_extra_key,
             mock_extra_value,
-        );
-
-        mock_error_response
+        )
     }
 
     fn format_error_response_data_as_string(
@@ -158,8 +357,7 @@ mod test {
             mock_extra_value
         );
 
-        let trimmed_string = trim_and_remove_whitespace_from_string(mock_error_response);
-        trimmed_string
+        trim_and_remove_whitespace_from_string(mock_error_response)
     }
 
     fn format_response_data_as_string(
@@ -171,19 +369,17 @@ mod test {
             r#"[{{
                 "block":"{}",
                 "level":{},
-                "errors":{}
+                "errors":[{}]
 			}}]"#,
             mock_block, mock_level, mock_error_response
         );
 
-        let trimmed_string = trim_and_remove_whitespace_from_string(mock_response);
-        trimmed_string
+        trim_and_remove_whitespace_from_string(mock_response)
     }
 
     fn trim_and_remove_whitespace_from_string(pre_format_string: String) -> String {
         pre_format_string
             .replacen(' ', "", usize::MAX)
-            .replace('\n', "")
-            .replace('\t', "")
+            .replace(['\n', '\t'], "")
     }
 }
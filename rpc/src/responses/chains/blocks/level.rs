@@ -0,0 +1,139 @@
+use super::ProtocolConstants;
+use crate::errors::ParseError;
+use crate::responses::Response;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::fmt;
+
+/// A level along with its position in the chain and in its cycle.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct LevelInfo {
+    pub level: i32,
+    /// Level relative to the activation of the first protocol with cycles.
+    pub level_position: i32,
+    pub cycle: i32,
+    pub cycle_position: i32,
+    /// Whether a seed nonce commitment is expected in the block.
+    pub expected_commitment: bool,
+}
+
+impl fmt::Display for LevelInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for LevelInfo {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        Ok(serde_json::from_str(response)?)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CycleLevelsResponse {
+    pub first: i32,
+    pub last: i32,
+}
+
+impl fmt::Display for CycleLevelsResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for CycleLevelsResponse {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        Ok(serde_json::from_str(response)?)
+    }
+}
+
+/// Converts between levels and cycles without querying the node.
+///
+/// Cycles are assumed to all be `blocks_per_cycle` long, so conversions are
+/// only exact for levels and cycles under the protocol the constants come
+/// from, or any other protocol with the same cycle length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CycleCalculator {
+    blocks_per_cycle: i32,
+    reference_cycle: i32,
+    reference_cycle_first_level: i32,
+}
+
+impl CycleCalculator {
+    /// Anchors the calculator on any level of the protocol `constants`
+    /// belong to, e.g. one returned by
+    /// [`GetCurrentLevel`](crate::commands::chains::blocks::get_current_level::GetCurrentLevel).
+    pub fn new(constants: &ProtocolConstants, reference: &LevelInfo) -> Self {
+        Self {
+            blocks_per_cycle: constants.blocks_per_cycle,
+            reference_cycle: reference.cycle,
+            reference_cycle_first_level: reference.level - reference.cycle_position,
+        }
+    }
+
+    pub fn cycle_of(&self, level: i32) -> i32 {
+        let offset = level - self.reference_cycle_first_level;
+        self.reference_cycle + offset.div_euclid(self.blocks_per_cycle)
+    }
+
+    pub fn cycle_position_of(&self, level: i32) -> i32 {
+        let offset = level - self.reference_cycle_first_level;
+        offset.rem_euclid(self.blocks_per_cycle)
+    }
+
+    pub fn first_level_of(&self, cycle: i32) -> i32 {
+        self.reference_cycle_first_level + (cycle - self.reference_cycle) * self.blocks_per_cycle
+    }
+
+    pub fn last_level_of(&self, cycle: i32) -> i32 {
+        self.first_level_of(cycle + 1) - 1
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn calculator() -> CycleCalculator {
+        let mock_constants = r#"{
+            "proof_of_work_nonce_size": 8,
+            "nonce_length": 32,
+            "max_anon_ops_per_block": 132,
+            "max_operation_data_length": 32768,
+            "max_proposals_per_delegate": 20,
+            "blocks_per_cycle": 24576,
+            "blocks_per_commitment": 192,
+            "hard_gas_limit_per_operation": "1040000",
+            "hard_gas_limit_per_block": "1386666",
+            "proof_of_work_threshold": "-1",
+            "origination_size": 257,
+            "cost_per_byte": "250",
+            "hard_storage_limit_per_operation": "60000"
+        }"#;
+        let mock_level = r#"{"level":5726208,"level_position":5726207,"cycle":745,"cycle_position":0,"expected_commitment":false}"#;
+
+        let constants = ProtocolConstants::from_response_str(mock_constants).unwrap();
+        let reference = LevelInfo::from_response_str(mock_level).unwrap();
+        CycleCalculator::new(&constants, &reference)
+    }
+
+    #[test]
+    fn cycle_bounds_ok() {
+        let calculator = calculator();
+
+        assert_eq!(calculator.first_level_of(745), 5726208);
+        assert_eq!(calculator.last_level_of(745), 5726208 + 24575);
+        assert_eq!(calculator.first_level_of(747), 5726208 + 2 * 24576);
+        assert_eq!(calculator.first_level_of(744), 5726208 - 24576);
+    }
+
+    #[test]
+    fn cycle_of_level_ok() {
+        let calculator = calculator();
+
+        assert_eq!(calculator.cycle_of(5726208), 745);
+        assert_eq!(calculator.cycle_of(5726207), 744);
+        assert_eq!(calculator.cycle_position_of(5726207), 24575);
+        assert_eq!(calculator.cycle_of(5726208 + 24576), 746);
+    }
+}
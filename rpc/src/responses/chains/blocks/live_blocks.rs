@@ -0,0 +1,79 @@
+use crate::errors::ParseError;
+use crate::responses::Response;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BlockHashResponse {
+    pub block_hash: String,
+}
+
+impl fmt::Display for BlockHashResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for BlockHashResponse {
+    /// Parses a response string in the form `"BL..."`.
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let block_hash = serde_json::from_str(response)?;
+
+        Ok(Self { block_hash })
+    }
+}
+
+/// Blocks an operation can use as its branch when injected on top of the
+/// requested block.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LiveBlocksResponse {
+    pub live_blocks: Vec<String>,
+}
+
+impl LiveBlocksResponse {
+    /// Whether an operation forged on `branch` is still valid.
+    pub fn is_live(&self, branch: &str) -> bool {
+        self.live_blocks.iter().any(|block| block == branch)
+    }
+}
+
+impl fmt::Display for LiveBlocksResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for LiveBlocksResponse {
+    /// Parses a response string in the form `["BL...", "BM..."]`.
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let live_blocks = serde_json::from_str(response)?;
+
+        Ok(Self { live_blocks })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_block_hash_ok() {
+        let mock_response = r#""BLockGenesisGenesisGenesisGenesisGenesisf79b5d1CoW2""#;
+
+        let response = BlockHashResponse::from_response_str(mock_response).unwrap();
+        assert!(response.block_hash.starts_with("BLock"));
+    }
+
+    #[test]
+    fn parse_live_blocks_ok() {
+        let mock_response = r#"[
+            "BLockGenesisGenesisGenesisGenesisGenesisf79b5d1CoW2",
+            "BMeaiFq5S6EuPVR3ctvNGWT7dGkrcmHXmkrPtm8cMFoTYM3uDJq"
+        ]"#;
+
+        let response = LiveBlocksResponse::from_response_str(mock_response).unwrap();
+        assert!(response.is_live("BMeaiFq5S6EuPVR3ctvNGWT7dGkrcmHXmkrPtm8cMFoTYM3uDJq"));
+        assert!(!response.is_live("BKiHLREqU3JkXfzEDYAkmmfX48gBDtYhMrpA98s7Aq4SzbUAB6M"));
+    }
+}
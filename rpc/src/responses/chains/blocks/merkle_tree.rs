@@ -0,0 +1,864 @@
+//! Merkle proofs of parts of a block's context.
+//!
+//! A proof only contains the data along the requested path, and the hashes
+//! of the subtrees it prunes. [`ContextProof::verify()`] rebuilds the hash of
+//! the context's root tree from an Irmin proof. The `context` field of a block
+//! header is the hash of the context *commit*, which also covers the commit's
+//! parents and metadata: those are not served by the node, so they must be
+//! given as a [`ContextCommit`] to check a proof against a header.
+
+use super::raw_context::{parse_hex_leaf, ContextTree};
+use crate::encoding::{base58, hash};
+use crate::errors::ParseError;
+use crate::responses::Response;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{json, Map, Value};
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MerkleHashKind {
+    Contents,
+    Node,
+}
+
+/// Child of a [`MerkleTree`] directory.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MerkleNode {
+    /// Pruned subtree, represented by its `Co...` hash.
+    Hash { kind: MerkleHashKind, hash: String },
+    /// Subtree at the end of the requested path.
+    Data(ContextTree<Vec<u8>>),
+    /// Directory along the requested path.
+    Continue(MerkleTree),
+}
+
+impl MerkleNode {
+    fn from_value(value: Value) -> Result<Self, ParseError> {
+        let mut object = match value {
+            Value::Object(object) if object.len() == 1 => object,
+            other => return Err(invalid_merkle_tree(&other)),
+        };
+        if let Some(hash) = object.remove("hash") {
+            let (kind, hash) = serde_json::from_value(hash)?;
+            Ok(Self::Hash { kind, hash })
+        } else if let Some(data) = object.remove("data") {
+            ContextTree::from_value(data, &parse_hex_leaf).map(Self::Data)
+        } else if let Some(children) = object.remove("continue") {
+            MerkleTree::from_value(children).map(Self::Continue)
+        } else {
+            Err(invalid_merkle_tree(&Value::Object(object)))
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        match self {
+            Self::Hash { kind, hash } => json!({ "hash": [kind, hash] }),
+            Self::Data(data) => json!({ "data": data.to_value() }),
+            Self::Continue(tree) => json!({ "continue": tree.to_value() }),
+        }
+    }
+}
+
+/// Directory of a merkle proof, as served by the `context/merkle_tree`
+/// endpoint.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MerkleTree {
+    pub children: Vec<(String, MerkleNode)>,
+}
+
+impl MerkleTree {
+    /// Data at `path`, relative to this directory, if the proof includes it.
+    ///
+    /// Returns `None` when the path is missing from the proof or leads to a
+    /// pruned subtree.
+    pub fn data_at(&self, path: &[&str]) -> Option<&ContextTree<Vec<u8>>> {
+        let (name, rest) = path.split_first()?;
+        let (_, child) = self
+            .children
+            .iter()
+            .find(|(child_name, _)| child_name == name)?;
+        match child {
+            MerkleNode::Continue(tree) => tree.data_at(rest),
+            MerkleNode::Data(data) => data.get(rest),
+            MerkleNode::Hash { .. } => None,
+        }
+    }
+
+    /// Hashes of all the subtrees pruned from the proof, with their path.
+    pub fn pruned_hashes(&self) -> Vec<(Vec<&str>, &str)> {
+        let mut hashes = Vec::new();
+        self.collect_pruned_hashes(&mut Vec::new(), &mut hashes);
+        hashes
+    }
+
+    fn collect_pruned_hashes<'a>(
+        &'a self,
+        path: &mut Vec<&'a str>,
+        hashes: &mut Vec<(Vec<&'a str>, &'a str)>,
+    ) {
+        for (name, child) in &self.children {
+            path.push(name);
+            match child {
+                MerkleNode::Hash { hash, .. } => hashes.push((path.clone(), hash)),
+                MerkleNode::Continue(tree) => tree.collect_pruned_hashes(path, hashes),
+                MerkleNode::Data(_) => {}
+            }
+            path.pop();
+        }
+    }
+
+    /// Parses a directory, given either as an object or as a list of
+    /// `[name, node]` pairs.
+    fn from_value(value: Value) -> Result<Self, ParseError> {
+        let entries: Vec<(String, Value)> = match value {
+            Value::Object(object) => object.into_iter().collect(),
+            Value::Array(_) => serde_json::from_value(value)?,
+            other => return Err(invalid_merkle_tree(&other)),
+        };
+        let children = entries
+            .into_iter()
+            .map(|(name, node)| Ok((name, MerkleNode::from_value(node)?)))
+            .collect::<Result<_, ParseError>>()?;
+
+        Ok(Self { children })
+    }
+
+    fn to_value(&self) -> Value {
+        Value::Object(
+            self.children
+                .iter()
+                .map(|(name, child)| (name.clone(), child.to_value()))
+                .collect::<Map<String, Value>>(),
+        )
+    }
+}
+
+fn invalid_merkle_tree(value: &Value) -> ParseError {
+    ParseError::ResponseParsingError(format!("{} is not a valid merkle tree node", value))
+}
+
+/// Merkle proof of a path, `None` if the path is not in the context.
+#[derive(Debug)]
+pub struct MerkleTreeResponse {
+    pub tree: Option<MerkleTree>,
+}
+
+impl Serialize for MerkleTreeResponse {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match &self.tree {
+            Some(tree) => tree.to_value().serialize(serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+impl fmt::Display for MerkleTreeResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for MerkleTreeResponse {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let tree = match serde_json::from_str(response)? {
+            Value::Null => None,
+            value => Some(MerkleTree::from_value(value)?),
+        };
+
+        Ok(Self { tree })
+    }
+}
+
+/// Hash of a context tree, tagged with whether it is a value or a node.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum KindedHash {
+    Value(String),
+    Node(String),
+}
+
+impl KindedHash {
+    pub fn hash(&self) -> &str {
+        match self {
+            Self::Value(hash) | Self::Node(hash) => hash,
+        }
+    }
+}
+
+/// Number of entries up to which Irmin hashes a directory from the flat
+/// list of its entries, even when it is stored as a tree of inodes.
+const STABLE_HASH_ENTRIES: u64 = 256;
+
+/// Tree of an Irmin proof, as served by the `context/merkle_tree_v2`
+/// endpoint.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProofTree {
+    Value(Vec<u8>),
+    /// Pruned value, represented by its `Co...` hash.
+    BlindedValue(String),
+    Node(Vec<(String, ProofTree)>),
+    /// Pruned directory, represented by its `Co...` hash.
+    BlindedNode(String),
+    /// Directory with too many entries for a single node, split into a tree
+    /// of inodes.
+    Inode(ProofInode),
+    Extender(ProofInodeExtender),
+}
+
+/// Part of a directory stored as a tree of inodes.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InodeTree {
+    /// Pruned inode, represented by its `Co...` hash.
+    Blinded(String),
+    Values(Vec<(String, ProofTree)>),
+    Tree(ProofInode),
+    Extender(ProofInodeExtender),
+}
+
+/// Inode of a directory of `length` entries, with its children by index.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProofInode {
+    pub length: u64,
+    pub proofs: Vec<(u8, InodeTree)>,
+}
+
+/// Chain of inodes with a single child each, `segments` holding the index
+/// of the child at each level.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProofInodeExtender {
+    pub length: u64,
+    pub segments: Vec<u8>,
+    pub proof: Box<InodeTree>,
+}
+
+impl ProofTree {
+    /// Irmin hash of the tree, as a `Co...` hash.
+    ///
+    /// Fails if the proof prunes part of a directory whose hash depends on
+    /// all of its entries.
+    pub fn hash(&self) -> Result<String, ParseError> {
+        base58::encode(base58::CONTEXT_HASH, &self.hash_bytes()?)
+    }
+
+    fn is_contents(&self) -> bool {
+        matches!(self, Self::Value(_) | Self::BlindedValue(_))
+    }
+
+    fn hash_bytes(&self) -> Result<[u8; 32], ParseError> {
+        match self {
+            Self::Value(bytes) => {
+                let mut pre_image = (bytes.len() as u64).to_be_bytes().to_vec();
+                pre_image.extend(bytes);
+                Ok(hash::blake2b_256(&pre_image))
+            }
+            Self::BlindedValue(hash) | Self::BlindedNode(hash) => decode_context_hash(hash),
+            Self::Node(entries) => hash_node(entries.iter().collect()),
+            Self::Inode(inode) if inode.length <= STABLE_HASH_ENTRIES => {
+                let mut entries = Vec::new();
+                inode.collect_entries(&mut entries)?;
+                hash_stable_inode(inode.length, entries)
+            }
+            Self::Extender(extender) if extender.length <= STABLE_HASH_ENTRIES => {
+                let mut entries = Vec::new();
+                extender.proof.collect_entries(&mut entries)?;
+                hash_stable_inode(extender.length, entries)
+            }
+            Self::Inode(inode) => inode.hash_bytes(0),
+            Self::Extender(extender) => extender.hash_bytes(0),
+        }
+    }
+
+    fn from_value(value: Value) -> Result<Self, ParseError> {
+        let (tag, value) = single_field(value)?;
+        match tag.as_str() {
+            "value" => parse_hex_leaf(value).map(Self::Value),
+            "blinded_value" => Ok(Self::BlindedValue(serde_json::from_value(value)?)),
+            "node" => parse_proof_entries(value).map(Self::Node),
+            "blinded_node" => Ok(Self::BlindedNode(serde_json::from_value(value)?)),
+            "inode" => ProofInode::from_value(value).map(Self::Inode),
+            "extender" => ProofInodeExtender::from_value(value).map(Self::Extender),
+            _ => Err(invalid_proof(&json!({ tag: value }))),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        match self {
+            Self::Value(bytes) => json!({ "value": hex::encode(bytes) }),
+            Self::BlindedValue(hash) => json!({ "blinded_value": hash }),
+            Self::Node(entries) => json!({ "node": proof_entries_to_value(entries) }),
+            Self::BlindedNode(hash) => json!({ "blinded_node": hash }),
+            Self::Inode(inode) => json!({ "inode": inode.to_value() }),
+            Self::Extender(extender) => json!({ "extender": extender.to_value() }),
+        }
+    }
+}
+
+impl Serialize for ProofTree {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_value().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ProofTree {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        Self::from_value(value).map_err(|error| serde::de::Error::custom(format!("{:?}", error)))
+    }
+}
+
+impl InodeTree {
+    fn hash_bytes(&self, depth: u64) -> Result<[u8; 32], ParseError> {
+        match self {
+            Self::Blinded(hash) => decode_context_hash(hash),
+            Self::Values(entries) => {
+                let mut entries: Vec<_> = entries.iter().collect();
+                entries.sort_by(|(left, _), (right, _)| left.cmp(right));
+
+                let mut pre_image = vec![0];
+                write_varint(entries.len() as u64, &mut pre_image);
+                for (name, tree) in entries {
+                    write_varint(name.len() as u64, &mut pre_image);
+                    pre_image.extend(name.as_bytes());
+                    pre_image.push(if tree.is_contents() { 1 } else { 0 });
+                    pre_image.extend(tree.hash_bytes()?);
+                }
+                Ok(hash::blake2b_256(&pre_image))
+            }
+            Self::Tree(inode) => inode.hash_bytes(depth),
+            Self::Extender(extender) => extender.hash_bytes(depth),
+        }
+    }
+
+    fn collect_entries<'a>(
+        &'a self,
+        entries: &mut Vec<&'a (String, ProofTree)>,
+    ) -> Result<(), ParseError> {
+        match self {
+            Self::Blinded(hash) => Err(ParseError::ResponseParsingError(format!(
+                "proof prunes inode {} of a directory hashed from all its entries",
+                hash
+            ))),
+            Self::Values(values) => {
+                entries.extend(values);
+                Ok(())
+            }
+            Self::Tree(inode) => inode.collect_entries(entries),
+            Self::Extender(extender) => extender.proof.collect_entries(entries),
+        }
+    }
+
+    fn from_value(value: Value) -> Result<Self, ParseError> {
+        let (tag, value) = single_field(value)?;
+        match tag.as_str() {
+            "blinded_inode" => Ok(Self::Blinded(serde_json::from_value(value)?)),
+            "inode_values" => parse_proof_entries(value).map(Self::Values),
+            "inode_tree" => ProofInode::from_value(value).map(Self::Tree),
+            "inode_extender" => ProofInodeExtender::from_value(value).map(Self::Extender),
+            _ => Err(invalid_proof(&json!({ tag: value }))),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        match self {
+            Self::Blinded(hash) => json!({ "blinded_inode": hash }),
+            Self::Values(entries) => json!({ "inode_values": proof_entries_to_value(entries) }),
+            Self::Tree(inode) => json!({ "inode_tree": inode.to_value() }),
+            Self::Extender(extender) => json!({ "inode_extender": extender.to_value() }),
+        }
+    }
+}
+
+impl ProofInode {
+    fn hash_bytes(&self, depth: u64) -> Result<[u8; 32], ParseError> {
+        let mut pointers = self
+            .proofs
+            .iter()
+            .map(|(index, child)| Ok((*index, child.hash_bytes(depth + 1)?)))
+            .collect::<Result<Vec<_>, ParseError>>()?;
+        pointers.sort_by_key(|(index, _)| *index);
+        Ok(hash_inode_pointers(depth, self.length, &pointers))
+    }
+
+    fn collect_entries<'a>(
+        &'a self,
+        entries: &mut Vec<&'a (String, ProofTree)>,
+    ) -> Result<(), ParseError> {
+        for (_, child) in &self.proofs {
+            child.collect_entries(entries)?;
+        }
+        Ok(())
+    }
+
+    fn from_value(value: Value) -> Result<Self, ParseError> {
+        #[derive(Deserialize)]
+        struct Inode {
+            length: String,
+            proofs: Vec<(u8, Value)>,
+        }
+
+        let inode: Inode = serde_json::from_value(value)?;
+        let proofs = inode
+            .proofs
+            .into_iter()
+            .map(|(index, child)| Ok((index, InodeTree::from_value(child)?)))
+            .collect::<Result<_, ParseError>>()?;
+
+        Ok(Self {
+            length: inode.length.parse()?,
+            proofs,
+        })
+    }
+
+    fn to_value(&self) -> Value {
+        let proofs: Vec<Value> = self
+            .proofs
+            .iter()
+            .map(|(index, child)| json!([index, child.to_value()]))
+            .collect();
+        json!({ "length": self.length.to_string(), "proofs": proofs })
+    }
+}
+
+impl ProofInodeExtender {
+    /// Hashes the chain of inodes the extender stands for, from the inode
+    /// at the end of the chain up.
+    fn hash_bytes(&self, depth: u64) -> Result<[u8; 32], ParseError> {
+        let mut hash = self.proof.hash_bytes(depth + self.segments.len() as u64)?;
+        for (level, index) in self.segments.iter().enumerate().rev() {
+            hash = hash_inode_pointers(depth + level as u64, self.length, &[(*index, hash)]);
+        }
+        Ok(hash)
+    }
+
+    fn from_value(value: Value) -> Result<Self, ParseError> {
+        #[derive(Deserialize)]
+        struct Extender {
+            length: String,
+            segment: String,
+            proof: Value,
+        }
+
+        let extender: Extender = serde_json::from_value(value)?;
+        let segment = hex::decode(&extender.segment)
+            .map_err(|error| ParseError::ResponseParsingError(error.to_string()))?;
+
+        Ok(Self {
+            length: extender.length.parse()?,
+            segments: decode_segments(&segment)?,
+            proof: Box::new(InodeTree::from_value(extender.proof)?),
+        })
+    }
+
+    fn to_value(&self) -> Value {
+        json!({
+            "length": self.length.to_string(),
+            "segment": hex::encode(encode_segments(&self.segments)),
+            "proof": self.proof.to_value(),
+        })
+    }
+}
+
+/// Hashes a directory from its entries, in the format Irmin inherited from
+/// its 1.x versions: lengths are 64 bits, and entries are sorted by name.
+fn hash_node(mut entries: Vec<&(String, ProofTree)>) -> Result<[u8; 32], ParseError> {
+    entries.sort_by(|(left, _), (right, _)| left.cmp(right));
+
+    let mut pre_image = (entries.len() as u64).to_be_bytes().to_vec();
+    for (name, tree) in entries {
+        let kind = if tree.is_contents() { 0xff } else { 0x00 };
+        pre_image.extend([kind, 0, 0, 0, 0, 0, 0, 0]);
+        write_varint(name.len() as u64, &mut pre_image);
+        pre_image.extend(name.as_bytes());
+        pre_image.extend(32u64.to_be_bytes());
+        pre_image.extend(tree.hash_bytes()?);
+    }
+    Ok(hash::blake2b_256(&pre_image))
+}
+
+fn hash_stable_inode(
+    length: u64,
+    entries: Vec<&(String, ProofTree)>,
+) -> Result<[u8; 32], ParseError> {
+    if entries.len() as u64 != length {
+        let detail = format!(
+            "inode of {} entries only has {} in the proof",
+            length,
+            entries.len()
+        );
+        return Err(ParseError::ResponseParsingError(detail));
+    }
+    hash_node(entries)
+}
+
+fn hash_inode_pointers(depth: u64, length: u64, pointers: &[(u8, [u8; 32])]) -> [u8; 32] {
+    let mut pre_image = vec![1];
+    write_varint(depth, &mut pre_image);
+    write_varint(length, &mut pre_image);
+    write_varint(pointers.len() as u64, &mut pre_image);
+    for (index, hash) in pointers {
+        write_varint(u64::from(*index), &mut pre_image);
+        pre_image.extend(hash);
+    }
+    hash::blake2b_256(&pre_image)
+}
+
+/// Irmin's variable length encoding of integers, seven bits per byte.
+fn write_varint(mut natural: u64, out: &mut Vec<u8>) {
+    while natural >= 0x80 {
+        out.push((natural & 0x7f) as u8 | 0x80);
+        natural >>= 7;
+    }
+    out.push(natural as u8);
+}
+
+fn decode_context_hash(hash: &str) -> Result<[u8; 32], ParseError> {
+    let mut bytes = [0; 32];
+    bytes.copy_from_slice(&base58::decode(base58::CONTEXT_HASH, hash)?);
+    Ok(bytes)
+}
+
+/// Unpacks the 5 bit inode indexes of an extender, followed by a single set
+/// bit marking their end.
+fn decode_segments(packed: &[u8]) -> Result<Vec<u8>, ParseError> {
+    let mut bits: Vec<u8> = packed
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |shift| (byte >> shift) & 1))
+        .collect();
+    while bits.last() == Some(&0) {
+        bits.pop();
+    }
+    if bits.pop() != Some(1) || !bits.len().is_multiple_of(5) {
+        let detail = format!("{} is not a valid inode segment", hex::encode(packed));
+        return Err(ParseError::ResponseParsingError(detail));
+    }
+
+    Ok(bits
+        .chunks(5)
+        .map(|chunk| chunk.iter().fold(0, |index, bit| index << 1 | bit))
+        .collect())
+}
+
+fn encode_segments(segments: &[u8]) -> Vec<u8> {
+    let mut bits: Vec<u8> = segments
+        .iter()
+        .flat_map(|index| (0..5).rev().map(move |shift| (index >> shift) & 1))
+        .collect();
+    bits.push(1);
+    bits.chunks(8)
+        .map(|chunk| {
+            let byte = chunk.iter().fold(0, |byte, bit| byte << 1 | bit);
+            byte << (8 - chunk.len())
+        })
+        .collect()
+}
+
+fn single_field(value: Value) -> Result<(String, Value), ParseError> {
+    match value {
+        Value::Object(object) if object.len() == 1 => Ok(object.into_iter().next().unwrap()),
+        other => Err(invalid_proof(&other)),
+    }
+}
+
+fn parse_proof_entries(value: Value) -> Result<Vec<(String, ProofTree)>, ParseError> {
+    let entries: Vec<(String, Value)> = serde_json::from_value(value)?;
+    entries
+        .into_iter()
+        .map(|(name, tree)| Ok((name, ProofTree::from_value(tree)?)))
+        .collect()
+}
+
+fn proof_entries_to_value(entries: &[(String, ProofTree)]) -> Value {
+    entries
+        .iter()
+        .map(|(name, tree)| json!([name, tree.to_value()]))
+        .collect()
+}
+
+fn invalid_proof(value: &Value) -> ParseError {
+    ParseError::ResponseParsingError(format!("{} is not a valid context proof tree", value))
+}
+
+/// Irmin proof of a part of a block's context, as served by the
+/// `context/merkle_tree_v2` endpoint.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ContextProof {
+    /// Irmin proof format: only tree proofs of directories split into
+    /// 32-way inodes (version 0), as used by the context of blocks, can be
+    /// verified.
+    pub version: i16,
+    /// Hash of the context's root tree the proof starts from.
+    pub before: KindedHash,
+    /// Hash of the root tree after the proof's reads, the same as `before`
+    /// for proofs served by the node.
+    pub after: KindedHash,
+    /// Partial tree, hashing to `before`.
+    pub state: ProofTree,
+}
+
+impl ContextProof {
+    /// Checks that `state` hashes to `before`, i.e. that the data in the
+    /// proof is part of the context tree `before`.
+    ///
+    /// Fails if the proof is malformed, or in a format other than version 0.
+    pub fn verify(&self) -> Result<bool, ParseError> {
+        if self.version != 0 {
+            let detail = format!("cannot verify version {} context proofs", self.version);
+            return Err(ParseError::ResponseParsingError(detail));
+        }
+        let kind_matches = match self.before {
+            KindedHash::Value(_) => self.state.is_contents(),
+            KindedHash::Node(_) => !self.state.is_contents(),
+        };
+
+        Ok(kind_matches && self.state.hash()? == self.before.hash())
+    }
+
+    /// Checks the proof with [`verify()`](Self::verify()), and that
+    /// committing its root tree with `commit` gives `context`, the hash
+    /// of the header of a trusted block.
+    pub fn verify_commit(&self, commit: &ContextCommit, context: &str) -> Result<bool, ParseError> {
+        Ok(self.verify()?
+            && matches!(self.before, KindedHash::Node(_))
+            && commit.hash(self.before.hash())? == context)
+    }
+}
+
+/// Metadata of a context commit, which the `context` of a block header
+/// hashes along with the context's root tree.
+///
+/// The node does not serve commits: `parents` holds the `context` of the
+/// predecessor block, while `date`, `author` and `message` must come from
+/// the node that committed the block.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ContextCommit {
+    pub parents: Vec<String>,
+    /// Unix timestamp of the commit, the timestamp of its block.
+    pub date: i64,
+    pub author: String,
+    pub message: String,
+}
+
+impl ContextCommit {
+    /// Hash of the commit of the root tree `tree`, in the format Irmin
+    /// inherited from its 1.x versions.
+    pub fn hash(&self, tree: &str) -> Result<String, ParseError> {
+        let mut pre_image = 32u64.to_be_bytes().to_vec();
+        pre_image.extend(decode_context_hash(tree)?);
+        pre_image.extend((self.parents.len() as u64).to_be_bytes());
+        for parent in &self.parents {
+            pre_image.extend(32u64.to_be_bytes());
+            pre_image.extend(decode_context_hash(parent)?);
+        }
+        pre_image.extend(self.date.to_be_bytes());
+        for field in [&self.author, &self.message] {
+            pre_image.extend((field.len() as u64).to_be_bytes());
+            pre_image.extend(field.as_bytes());
+        }
+
+        base58::encode(base58::CONTEXT_HASH, &hash::blake2b_256(&pre_image))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ContextProofResponse {
+    pub proof: Option<ContextProof>,
+}
+
+impl fmt::Display for ContextProofResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for ContextProofResponse {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let proof = serde_json::from_str(response)?;
+
+        Ok(Self { proof })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MOCK_MERKLE_TREE: &str = r#"{
+        "big_maps": {"hash": ["node", "CoVqzBnVhPbhwHSL8W2fGqJstFzFLp2BWLnS4HAUJD8DfGhxgdZK"]},
+        "contracts": {"continue": {
+            "index": {"continue": {
+                "0000b1": {"data": {"balance": "80897a", "counter": "0c"}}
+            }}
+        }},
+        "version": {"hash": ["contents", "CoUkWzoH5wVBe5gBXzuGWo1BAxb1UDyvWUm6kHFBbvbH8bZZxfmz"]}
+    }"#;
+
+    #[test]
+    fn parse_merkle_tree_ok() {
+        let response = MerkleTreeResponse::from_response_str(MOCK_MERKLE_TREE).unwrap();
+        let tree = response.tree.as_ref().unwrap();
+
+        let balance = tree
+            .data_at(&["contracts", "index", "0000b1", "balance"])
+            .and_then(ContextTree::leaf);
+        assert_eq!(balance, Some(&vec![0x80, 0x89, 0x7a]));
+        assert!(tree.data_at(&["big_maps", "index"]).is_none());
+        assert_eq!(tree.pruned_hashes().len(), 2);
+        assert_eq!(
+            json!(response),
+            serde_json::from_str::<Value>(MOCK_MERKLE_TREE).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_merkle_tree_as_pairs_ok() {
+        let mock_response = r#"[["version", {"data": "616c706861"}]]"#;
+
+        let response = MerkleTreeResponse::from_response_str(mock_response).unwrap();
+        let version = response.tree.unwrap();
+        assert_eq!(
+            version.data_at(&["version"]).and_then(ContextTree::leaf),
+            Some(&b"alpha".to_vec())
+        );
+    }
+
+    #[test]
+    fn missing_path_ok() {
+        let response = MerkleTreeResponse::from_response_str("null").unwrap();
+        assert!(response.tree.is_none());
+    }
+
+    #[test]
+    fn invalid_merkle_node_fails() {
+        assert!(MerkleTreeResponse::from_response_str(r#"{"a": {"other": 1}}"#).is_err());
+    }
+
+    /// Proof of a context holding `alpha` at `version`, with hashes computed
+    /// from Irmin's hash pre-images.
+    const MOCK_CONTEXT_PROOF: &str = r#"{
+        "version": 0,
+        "before": {"node": "CoVvroWSumSgAqDrXuzywrz25NiiK32ovHRrHfDXqnzaEeB2qFx9"},
+        "after": {"node": "CoVvroWSumSgAqDrXuzywrz25NiiK32ovHRrHfDXqnzaEeB2qFx9"},
+        "state": {"node": [["version", {"value": "616c706861"}]]}
+    }"#;
+
+    fn mock_context_proof() -> ContextProof {
+        let response = ContextProofResponse::from_response_str(MOCK_CONTEXT_PROOF).unwrap();
+        response.proof.unwrap()
+    }
+
+    fn value(bytes: &[u8]) -> ProofTree {
+        ProofTree::Value(bytes.to_vec())
+    }
+
+    #[test]
+    fn parse_context_proof_ok() {
+        let proof = mock_context_proof();
+
+        assert_eq!(proof.before, proof.after);
+        assert_eq!(
+            proof.state,
+            ProofTree::Node(vec![("version".to_string(), value(b"alpha"))])
+        );
+        assert_eq!(
+            json!(proof),
+            serde_json::from_str::<Value>(MOCK_CONTEXT_PROOF).unwrap()
+        );
+        assert!(proof.verify().unwrap());
+    }
+
+    #[test]
+    fn tampered_context_proof_fails() {
+        let mut proof = mock_context_proof();
+        proof.state = ProofTree::Node(vec![("version".to_string(), value(b"omega"))]);
+        assert!(!proof.verify().unwrap());
+
+        let mut proof = mock_context_proof();
+        proof.state = ProofTree::BlindedValue(proof.before.hash().to_string());
+        assert!(!proof.verify().unwrap());
+
+        let mut proof = mock_context_proof();
+        proof.version = 2;
+        assert!(proof.verify().is_err());
+    }
+
+    #[test]
+    fn blinded_subtree_keeps_hash_ok() {
+        let proof = mock_context_proof();
+        let blinded_value = ProofTree::BlindedValue(value(b"alpha").hash().unwrap());
+        let blinded = ProofTree::Node(vec![("version".to_string(), blinded_value)]);
+
+        assert_eq!(
+            value(b"alpha").hash().unwrap(),
+            "CoVuvY4BrRcjqD7RTTwdfZVySZ6DBNPMUXgA43ZQNjsQNx5t2JFL"
+        );
+        assert_eq!(blinded.hash().unwrap(), proof.before.hash());
+    }
+
+    #[test]
+    fn small_inode_hashes_as_node_ok() {
+        let entry = |name: &str| (name.to_string(), value(name.as_bytes()));
+        let node = ProofTree::Node(vec![entry("b"), entry("a")]);
+        let inode = ProofTree::Inode(ProofInode {
+            length: 2,
+            proofs: vec![
+                (3, InodeTree::Values(vec![entry("a")])),
+                (17, InodeTree::Values(vec![entry("b")])),
+            ],
+        });
+        assert_eq!(inode.hash().unwrap(), node.hash().unwrap());
+
+        let blinded = ProofTree::Inode(ProofInode {
+            length: 2,
+            proofs: vec![
+                (3, InodeTree::Values(vec![entry("a")])),
+                (17, InodeTree::Blinded(node.hash().unwrap())),
+            ],
+        });
+        assert!(blinded.hash().is_err());
+    }
+
+    #[test]
+    fn inode_extender_hashes_as_inode_chain_ok() {
+        let leaf = InodeTree::Values(vec![("a".to_string(), value(b"a"))]);
+        let extender = ProofTree::Extender(ProofInodeExtender {
+            length: 300,
+            segments: vec![4, 9],
+            proof: Box::new(leaf.clone()),
+        });
+        let inodes = ProofTree::Inode(ProofInode {
+            length: 300,
+            proofs: vec![(
+                4,
+                InodeTree::Tree(ProofInode {
+                    length: 300,
+                    proofs: vec![(9, leaf)],
+                }),
+            )],
+        });
+        assert_eq!(extender.hash().unwrap(), inodes.hash().unwrap());
+
+        let serialized = json!(extender);
+        assert_eq!(serialized["extender"]["segment"], "2260");
+        assert_eq!(
+            serde_json::from_value::<ProofTree>(serialized).unwrap(),
+            extender
+        );
+    }
+
+    #[test]
+    fn verify_context_commit_ok() {
+        let proof = mock_context_proof();
+        let mut commit = ContextCommit {
+            parents: vec!["CoWGbpEuDHXwwVwoetwGGMnguaJTLBxpgmmm74AdDDPA44myjDH3".to_string()],
+            date: 1530374852,
+            author: "Tezos".to_string(),
+            message: "Genesis".to_string(),
+        };
+        let context = "CoWGfnipVMCPh7kB76gA7dGYNgLMHRS9iXzQyM16PrZoQUnSVGje";
+        assert!(proof.verify_commit(&commit, context).unwrap());
+
+        commit.date += 1;
+        assert!(!proof.verify_commit(&commit, context).unwrap());
+    }
+}
@@ -1,6 +1,60 @@
+pub mod baking_rights;
 pub mod balance;
+pub mod big_map_value;
+pub mod block_header;
 pub mod block_ids_in_chain;
+pub mod chain;
+pub mod consensus_rights;
+pub mod constants;
+pub mod contract_entrypoints;
+pub mod contract_script;
+pub mod delegate;
+pub mod delegates;
 pub mod invalid_blocks_in_chain;
+pub mod level;
+pub mod live_blocks;
+pub mod merkle_tree;
+pub mod operation_hashes;
+pub mod protocols;
+pub mod raw_context;
+pub mod run_view;
+pub mod votes;
+pub use baking_rights::{BakingRight, BakingRightsResponse};
 pub use balance::BalanceResponse;
+pub use big_map_value::BigMapValueResponse;
+pub use block_header::{BlockHeader, ProtocolData, RawBlockHeaderResponse, ShellHeader};
 pub use block_ids_in_chain::BlocksInChainResponse;
-pub use invalid_blocks_in_chain::InvalidBlocksInChainResponse;
+pub use chain::{
+    BlockLevelResponse, BootstrappedResponse, ChainIdResponse, CheckpointResponse, SyncState,
+};
+pub use consensus_rights::{ConsensusRights, ConsensusRightsResponse, ConsensusSlots};
+pub use constants::ProtocolConstants;
+pub use contract_entrypoints::ContractEntrypointsResponse;
+pub use contract_script::ContractScriptResponse;
+pub use delegate::{
+    DelegateBalanceResponse, DelegateDeactivatedResponse, DelegateGracePeriodResponse,
+    DelegateParticipationResponse, DelegateResponse, DelegatedContractsResponse,
+    VotingPowerResponse,
+};
+pub use delegates::DelegatesResponse;
+pub use invalid_blocks_in_chain::{
+    BalanceTooLowDetails, ContractDetails, CounterDetails, ErrorDetails, ErrorKind, InvalidBlock,
+    InvalidBlockDetails, InvalidBlockError, InvalidBlocksInChainResponse, ScriptRejectedDetails,
+};
+pub use level::{CycleCalculator, CycleLevelsResponse, LevelInfo};
+pub use live_blocks::{BlockHashResponse, LiveBlocksResponse};
+pub use merkle_tree::{
+    ContextCommit, ContextProof, ContextProofResponse, InodeTree, KindedHash, MerkleHashKind,
+    MerkleNode, MerkleTree, MerkleTreeResponse, ProofInode, ProofInodeExtender, ProofTree,
+};
+pub use operation_hashes::{
+    OperationHashResponse, OperationHashesResponse, ValidationPassHashesResponse,
+};
+pub use protocols::BlockProtocolsResponse;
+pub use raw_context::{ContextTree, RawContextBytesResponse, RawContextResponse};
+pub use run_view::RunViewResponse;
+pub use votes::{
+    BallotListResponse, BallotsResponse, CastBallot, CurrentProposalResponse,
+    CurrentQuorumResponse, ProposalsResponse, VotingListing, VotingListingsResponse, VotingPeriod,
+    VotingPeriodInfoResponse,
+};
@@ -0,0 +1,129 @@
+use crate::errors::ParseError;
+use crate::responses::Response;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::fmt;
+
+/// Hashes of a block's operations, grouped by validation pass.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct OperationHashesResponse {
+    pub validation_passes: Vec<Vec<String>>,
+}
+
+impl OperationHashesResponse {
+    /// Validation pass and index of the operation with hash
+    /// `operation_hash`, if the block includes it.
+    pub fn position(&self, operation_hash: &str) -> Option<(usize, usize)> {
+        self.validation_passes
+            .iter()
+            .enumerate()
+            .find_map(|(validation_pass, hashes)| {
+                hashes
+                    .iter()
+                    .position(|hash| hash == operation_hash)
+                    .map(|index| (validation_pass, index))
+            })
+    }
+
+    pub fn contains(&self, operation_hash: &str) -> bool {
+        self.position(operation_hash).is_some()
+    }
+}
+
+impl fmt::Display for OperationHashesResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for OperationHashesResponse {
+    /// Parses a response string in the form `[["o..."], [], [], ["o...", "o..."]]`.
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let validation_passes = serde_json::from_str(response)?;
+
+        Ok(Self { validation_passes })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ValidationPassHashesResponse {
+    pub operation_hashes: Vec<String>,
+}
+
+impl ValidationPassHashesResponse {
+    pub fn contains(&self, operation_hash: &str) -> bool {
+        self.operation_hashes
+            .iter()
+            .any(|hash| hash == operation_hash)
+    }
+}
+
+impl fmt::Display for ValidationPassHashesResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for ValidationPassHashesResponse {
+    /// Parses a response string in the form `["o...", "o..."]`.
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let operation_hashes = serde_json::from_str(response)?;
+
+        Ok(Self { operation_hashes })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct OperationHashResponse {
+    pub operation_hash: String,
+}
+
+impl fmt::Display for OperationHashResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for OperationHashResponse {
+    /// Parses a response string in the form `"o..."`.
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let operation_hash = serde_json::from_str(response)?;
+
+        Ok(Self { operation_hash })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TRANSFER: &str = "opNCXxWCJMPTxn3EWZ9Yk4VmGGxYGbR9z9Y9bXhh3HHgt9zD2Ff";
+
+    #[test]
+    fn parse_operation_hashes_ok() {
+        let mock_response = format!(
+            r#"[["ooFnsQUXD4Mw9QbbGXaWVqxbBpGHxpvnEJf1VJrQXpKHKmWvsdo"],[],[],["{}"]]"#,
+            TRANSFER
+        );
+
+        let response = OperationHashesResponse::from_response_str(&mock_response).unwrap();
+        assert_eq!(response.position(TRANSFER), Some((3, 0)));
+        assert!(!response.contains("onvmYwMzwuBvVJx5QdNf6mV5yGUUDMm2SmDpdzALAeaWvFeLb9R"));
+    }
+
+    #[test]
+    fn parse_validation_pass_hashes_ok() {
+        let mock_response = format!(r#"["{}"]"#, TRANSFER);
+
+        let response = ValidationPassHashesResponse::from_response_str(&mock_response).unwrap();
+        assert!(response.contains(TRANSFER));
+    }
+
+    #[test]
+    fn parse_operation_hash_ok() {
+        let mock_response = format!(r#""{}""#, TRANSFER);
+
+        let response = OperationHashResponse::from_response_str(&mock_response).unwrap();
+        assert_eq!(response.operation_hash, TRANSFER);
+    }
+}
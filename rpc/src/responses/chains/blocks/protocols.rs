@@ -0,0 +1,51 @@
+use crate::errors::ParseError;
+use crate::responses::Response;
+use crate::types::Protocol;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct BlockProtocolsResponse {
+    /// Protocol the block was produced with.
+    pub protocol: Protocol,
+    /// Protocol of the block's successor, which differs from `protocol` on
+    /// the last block before an activation.
+    pub next_protocol: Protocol,
+}
+
+impl BlockProtocolsResponse {
+    pub fn is_migration_block(&self) -> bool {
+        self.protocol != self.next_protocol
+    }
+}
+
+impl fmt::Display for BlockProtocolsResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for BlockProtocolsResponse {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        Ok(serde_json::from_str(response)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_block_protocols_ok() {
+        let mock_response = r#"{
+            "protocol": "PtParisBxoLz5gzMmn3d9WBQNoPSZakgnkMC2VNuQ3KXfUtUQeZ",
+            "next_protocol": "PsParisCZo7KAh1Z1smVd9ZMZ1HHn5gkzbM94V3PLCpknFWhUAi"
+        }"#;
+
+        let response = BlockProtocolsResponse::from_response_str(mock_response).unwrap();
+        assert_eq!(response.protocol, Protocol::Paris);
+        assert_eq!(response.next_protocol, Protocol::ParisC);
+        assert!(response.is_migration_block());
+    }
+}
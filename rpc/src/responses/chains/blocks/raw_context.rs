@@ -0,0 +1,232 @@
+use crate::errors::ParseError;
+use crate::responses::Response;
+use serde::{Serialize, Serializer};
+use serde_json::{json, Map, Value};
+use std::fmt;
+
+/// Subtree of a block's context, as returned by the `context/raw` endpoints.
+///
+/// Directories keep the order in which the node lists their children.
+/// Subtrees deeper than the requested depth are [`Cut`](Self::Cut).
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContextTree<T> {
+    Leaf(T),
+    Directory(Vec<(String, ContextTree<T>)>),
+    Cut,
+}
+
+impl<T> ContextTree<T> {
+    /// Subtree at `path`, relative to this one.
+    pub fn get(&self, path: &[&str]) -> Option<&ContextTree<T>> {
+        match path.split_first() {
+            None => Some(self),
+            Some((name, rest)) => self
+                .children()?
+                .iter()
+                .find(|(child_name, _)| child_name == name)
+                .and_then(|(_, child)| child.get(rest)),
+        }
+    }
+
+    pub fn leaf(&self) -> Option<&T> {
+        match self {
+            Self::Leaf(leaf) => Some(leaf),
+            _ => None,
+        }
+    }
+
+    pub fn children(&self) -> Option<&[(String, ContextTree<T>)]> {
+        match self {
+            Self::Directory(children) => Some(children),
+            _ => None,
+        }
+    }
+
+    pub fn is_cut(&self) -> bool {
+        matches!(self, Self::Cut)
+    }
+
+    /// All the leaves of the tree, with their path relative to it.
+    pub fn leaves(&self) -> Vec<(Vec<&str>, &T)> {
+        let mut leaves = Vec::new();
+        self.collect_leaves(&mut Vec::new(), &mut leaves);
+        leaves
+    }
+
+    fn collect_leaves<'a>(
+        &'a self,
+        path: &mut Vec<&'a str>,
+        leaves: &mut Vec<(Vec<&'a str>, &'a T)>,
+    ) {
+        match self {
+            Self::Leaf(leaf) => leaves.push((path.clone(), leaf)),
+            Self::Directory(children) => {
+                for (name, child) in children {
+                    path.push(name);
+                    child.collect_leaves(path, leaves);
+                    path.pop();
+                }
+            }
+            Self::Cut => {}
+        }
+    }
+
+    /// Builds a tree from its JSON representation: objects are directories,
+    /// `null` is a cut subtree and any other value is a leaf.
+    pub(super) fn from_value<F>(value: Value, parse_leaf: &F) -> Result<Self, ParseError>
+    where
+        F: Fn(Value) -> Result<T, ParseError>,
+    {
+        match value {
+            Value::Null => Ok(Self::Cut),
+            Value::Object(children) => children
+                .into_iter()
+                .map(|(name, child)| Ok((name, Self::from_value(child, parse_leaf)?)))
+                .collect::<Result<_, ParseError>>()
+                .map(Self::Directory),
+            leaf => parse_leaf(leaf).map(Self::Leaf),
+        }
+    }
+}
+
+impl ContextTree<Value> {
+    /// JSON representation of the tree, as served by the node.
+    pub fn to_value(&self) -> Value {
+        match self {
+            Self::Leaf(leaf) => leaf.clone(),
+            Self::Directory(children) => Value::Object(
+                children
+                    .iter()
+                    .map(|(name, child)| (name.clone(), child.to_value()))
+                    .collect::<Map<String, Value>>(),
+            ),
+            Self::Cut => Value::Null,
+        }
+    }
+}
+
+impl ContextTree<Vec<u8>> {
+    /// JSON representation of the tree, as served by the node.
+    pub fn to_value(&self) -> Value {
+        match self {
+            Self::Leaf(bytes) => Value::String(hex::encode(bytes)),
+            Self::Directory(children) => Value::Object(
+                children
+                    .iter()
+                    .map(|(name, child)| (name.clone(), child.to_value()))
+                    .collect::<Map<String, Value>>(),
+            ),
+            Self::Cut => Value::Null,
+        }
+    }
+}
+
+/// Context subtree with its values decoded to JSON by the protocol.
+///
+/// JSON objects stored as values cannot be told apart from directories, so
+/// they are represented as directories too; [`ContextTree::to_value()`]
+/// rebuilds the node's response as is.
+#[derive(Debug)]
+pub struct RawContextResponse {
+    pub tree: ContextTree<Value>,
+}
+
+impl Serialize for RawContextResponse {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.tree.to_value().serialize(serializer)
+    }
+}
+
+impl fmt::Display for RawContextResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for RawContextResponse {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let value = serde_json::from_str(response)?;
+        let tree = ContextTree::from_value(value, &Ok)?;
+
+        Ok(Self { tree })
+    }
+}
+
+/// Context subtree with its values as stored, in binary.
+#[derive(Debug)]
+pub struct RawContextBytesResponse {
+    pub tree: ContextTree<Vec<u8>>,
+}
+
+impl Serialize for RawContextBytesResponse {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.tree.to_value().serialize(serializer)
+    }
+}
+
+impl fmt::Display for RawContextBytesResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for RawContextBytesResponse {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let value = serde_json::from_str(response)?;
+        let tree = ContextTree::from_value(value, &parse_hex_leaf)?;
+
+        Ok(Self { tree })
+    }
+}
+
+pub(super) fn parse_hex_leaf(leaf: Value) -> Result<Vec<u8>, ParseError> {
+    let detail = format!("{} is not a hex encoded context value", leaf);
+    leaf.as_str()
+        .and_then(|hex_string| hex::decode(hex_string).ok())
+        .ok_or(ParseError::ResponseParsingError(detail))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_raw_context_ok() {
+        let mock_response = r#"{
+            "balance": "4000000",
+            "counter": "12",
+            "manager": {"public_key": "edpkuBknW28nW72KG6RoHtYW7p12T6GKc7nAbwYX5m8Wd9sDVC9yav"},
+            "big_map": null
+        }"#;
+
+        let response = RawContextResponse::from_response_str(mock_response).unwrap();
+        assert_eq!(
+            response.tree.get(&["balance"]).and_then(ContextTree::leaf),
+            Some(&json!("4000000"))
+        );
+        assert!(response.tree.get(&["big_map"]).unwrap().is_cut());
+        assert!(response.tree.get(&["manager", "public_key"]).is_some());
+        assert_eq!(response.tree.leaves().len(), 3);
+        assert_eq!(
+            json!(response),
+            serde_json::from_str::<Value>(mock_response).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_raw_context_bytes_ok() {
+        let mock_response = r#"{"index": {"0": {"total_bytes": "8301", "value_type": null}}}"#;
+
+        let response = RawContextBytesResponse::from_response_str(mock_response).unwrap();
+        let leaves = response.tree.leaves();
+        assert_eq!(
+            leaves,
+            vec![(vec!["index", "0", "total_bytes"], &vec![0x83, 0x01])]
+        );
+    }
+
+    #[test]
+    fn non_hex_leaf_fails() {
+        assert!(RawContextBytesResponse::from_response_str(r#"{"balance": 12}"#).is_err());
+    }
+}
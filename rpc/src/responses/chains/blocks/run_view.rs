@@ -0,0 +1,60 @@
+use crate::errors::ParseError;
+use crate::michelson::{Micheline, MichelsonValue};
+use crate::responses::Response;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RunViewResponse {
+    pub data: Micheline,
+}
+
+impl RunViewResponse {
+    /// Decodes the value returned by the view against its return type.
+    pub fn decode(&self, return_type: &Micheline) -> Result<MichelsonValue, ParseError> {
+        MichelsonValue::decode(return_type, &self.data)
+    }
+}
+
+impl fmt::Display for RunViewResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for RunViewResponse {
+    /// Parses a response string in the form
+    /// `"{ "data": $micheline.michelson_v1.expression }"` into a
+    /// [`RunViewResponse`](Self).
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        Ok(serde_json::from_str(response)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn decode_view_result_ok() {
+        let mock_response = r#"{"data":{"prim":"Pair","args":[{"int":"1000"},{"int":"2000"}]}}"#;
+        let return_type: Micheline = serde_json::from_str(
+            r#"{"prim":"pair","args":[{"prim":"nat","annots":["%tez_pool"]},{"prim":"nat","annots":["%token_pool"]}]}"#,
+        )
+        .unwrap();
+
+        let response = RunViewResponse::from_response_str(mock_response).unwrap();
+        let decoded = response.decode(&return_type).unwrap();
+        assert_eq!(
+            decoded.to_json(),
+            json!({"tez_pool": "1000", "token_pool": "2000"})
+        );
+    }
+
+    #[test]
+    fn parse_error_response_fails() {
+        let mock_response = r#"[{"kind":"temporary","id":"proto.script_rejected"}]"#;
+        assert!(RunViewResponse::from_response_str(mock_response).is_err());
+    }
+}
@@ -0,0 +1,283 @@
+use crate::errors::ParseError;
+use crate::responses::Response;
+use crate::types::{int64, Ballot, VotingPeriodKind};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct VotingPeriod {
+    pub index: i32,
+    pub kind: VotingPeriodKind,
+    /// Level of the period's first block, relative to the protocol's
+    /// activation.
+    pub start_position: i32,
+}
+
+/// A voting period together with the position of a block in it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct VotingPeriodInfoResponse {
+    pub voting_period: VotingPeriod,
+    pub position: i32,
+    /// Number of blocks left in the period after this one.
+    pub remaining: i32,
+}
+
+impl fmt::Display for VotingPeriodInfoResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for VotingPeriodInfoResponse {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        Ok(serde_json::from_str(response)?)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CastBallot {
+    pub pkh: String,
+    pub ballot: Ballot,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BallotListResponse {
+    pub ballots: Vec<CastBallot>,
+}
+
+impl BallotListResponse {
+    pub fn ballot_of(&self, pkh: &str) -> Option<Ballot> {
+        self.ballots
+            .iter()
+            .find(|cast_ballot| cast_ballot.pkh == pkh)
+            .map(|cast_ballot| cast_ballot.ballot)
+    }
+}
+
+impl fmt::Display for BallotListResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for BallotListResponse {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let ballots = serde_json::from_str(response)?;
+
+        Ok(Self { ballots })
+    }
+}
+
+/// Voting power behind each ballot of the current period.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BallotsResponse {
+    #[serde(deserialize_with = "int64::deserialize")]
+    pub yay: i64,
+    #[serde(deserialize_with = "int64::deserialize")]
+    pub nay: i64,
+    #[serde(deserialize_with = "int64::deserialize")]
+    pub pass: i64,
+}
+
+impl BallotsResponse {
+    pub fn total(&self) -> i64 {
+        self.yay + self.nay + self.pass
+    }
+}
+
+impl fmt::Display for BallotsResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for BallotsResponse {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        Ok(serde_json::from_str(response)?)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CurrentProposalResponse {
+    pub proposal: Option<String>,
+}
+
+impl fmt::Display for CurrentProposalResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for CurrentProposalResponse {
+    /// Parses a response string in the form `"Pt..."` or `null`.
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let proposal = serde_json::from_str(response)?;
+
+        Ok(Self { proposal })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CurrentQuorumResponse {
+    /// Participation required, in hundredths of a percent.
+    pub quorum: i32,
+}
+
+impl fmt::Display for CurrentQuorumResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for CurrentQuorumResponse {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let quorum = serde_json::from_str(response)?;
+
+        Ok(Self { quorum })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct VotingListing {
+    pub pkh: String,
+    /// Counted in rolls before the Jakarta protocol.
+    #[serde(alias = "rolls", deserialize_with = "int64::deserialize")]
+    pub voting_power: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct VotingListingsResponse {
+    pub listings: Vec<VotingListing>,
+}
+
+impl VotingListingsResponse {
+    pub fn voting_power_of(&self, pkh: &str) -> Option<i64> {
+        self.listings
+            .iter()
+            .find(|listing| listing.pkh == pkh)
+            .map(|listing| listing.voting_power)
+    }
+
+    pub fn total_voting_power(&self) -> i64 {
+        self.listings
+            .iter()
+            .map(|listing| listing.voting_power)
+            .sum()
+    }
+
+    /// Pairs every listed delegate with the ballot it cast, if any.
+    pub fn participation<'a>(
+        &'a self,
+        ballots: &'a BallotListResponse,
+    ) -> impl Iterator<Item = (&'a VotingListing, Option<Ballot>)> {
+        self.listings
+            .iter()
+            .map(move |listing| (listing, ballots.ballot_of(&listing.pkh)))
+    }
+}
+
+impl fmt::Display for VotingListingsResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for VotingListingsResponse {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let listings = serde_json::from_str(response)?;
+
+        Ok(Self { listings })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ProposalsResponse {
+    /// Proposed protocol hashes with the voting power supporting them.
+    pub proposals: Vec<(String, i64)>,
+}
+
+impl fmt::Display for ProposalsResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for ProposalsResponse {
+    /// Parses a response string in the form `[["Pt...", "1000"], ...]`.
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let raw_proposals: Vec<(String, Value)> = serde_json::from_str(response)?;
+
+        let mut proposals = Vec::new();
+        for (protocol, voting_power) in raw_proposals {
+            proposals.push((protocol, int64::deserialize(voting_power)?));
+        }
+
+        Ok(Self { proposals })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_current_period_ok() {
+        let mock_response = r#"{"voting_period":{"index":90,"kind":"exploration","start_position":3096576},"position":1200,"remaining":39759}"#;
+
+        let response = VotingPeriodInfoResponse::from_response_str(mock_response).unwrap();
+        assert_eq!(response.voting_period.kind, VotingPeriodKind::Exploration);
+        assert_eq!(response.remaining, 39759);
+    }
+
+    #[test]
+    fn participation_per_delegate_ok() {
+        let listings = VotingListingsResponse::from_response_str(
+            r#"[{"pkh":"tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx","voting_power":"6000"},{"pkh":"tz1VkjQjvH3NTVdFtGz9tWutgxLKwnhnMe2x","voting_power":"4000"}]"#,
+        )
+        .unwrap();
+        let ballots = BallotListResponse::from_response_str(
+            r#"[{"pkh":"tz1VkjQjvH3NTVdFtGz9tWutgxLKwnhnMe2x","ballot":"nay"}]"#,
+        )
+        .unwrap();
+
+        assert_eq!(listings.total_voting_power(), 10000);
+        let participation: Vec<_> = listings.participation(&ballots).collect();
+        assert_eq!(participation[0].1, None);
+        assert_eq!(participation[1].1, Some(Ballot::Nay));
+    }
+
+    #[test]
+    fn parse_legacy_listings_ok() {
+        let response = VotingListingsResponse::from_response_str(
+            r#"[{"pkh":"tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx","rolls":12}]"#,
+        )
+        .unwrap();
+        assert_eq!(
+            response.voting_power_of("tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx"),
+            Some(12)
+        );
+    }
+
+    #[test]
+    fn parse_ballots_and_proposals_ok() {
+        let ballots =
+            BallotsResponse::from_response_str(r#"{"yay":"700","nay":200,"pass":"100"}"#).unwrap();
+        assert_eq!(ballots.total(), 1000);
+
+        let proposals = ProposalsResponse::from_response_str(
+            r#"[["PtParisBxoLz5gzMmn3d9WBQNoPSZakgnkMC2VNuQ3KXfUtUQeZ","1500"]]"#,
+        )
+        .unwrap();
+        assert_eq!(proposals.proposals[0].1, 1500);
+
+        let proposal = CurrentProposalResponse::from_response_str("null").unwrap();
+        assert!(proposal.proposal.is_none());
+    }
+
+    #[test]
+    fn parse_unknown_ballot_fails() {
+        let mock_response = r#"[{"pkh":"tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx","ballot":"maybe"}]"#;
+        assert!(BallotListResponse::from_response_str(mock_response).is_err());
+    }
+}
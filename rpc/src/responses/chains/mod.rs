@@ -1 +1 @@
-pub mod blocks;
\ No newline at end of file
+pub mod blocks;
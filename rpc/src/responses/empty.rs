@@ -0,0 +1,48 @@
+use crate::errors::ParseError;
+use crate::responses::Response;
+use serde_json::Value;
+
+/// Response of commands whose only result is their success, such as
+/// `DELETE` commands.
+///
+/// Failures are reported by [`RpcClient::execute()`](crate::RpcClient::execute())
+/// from the response status, so an error status with an empty body is not
+/// mistaken for success.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmptyResponse;
+
+impl Response for EmptyResponse {
+    /// Accepts an empty body, `null` or `{}`, and fails on anything else.
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        if response.trim().is_empty() {
+            return Ok(Self);
+        }
+
+        match serde_json::from_str(response)? {
+            Value::Null => Ok(Self),
+            Value::Object(fields) if fields.is_empty() => Ok(Self),
+            _ => {
+                let detail = format!("expected an empty response, got {}", response);
+                Err(ParseError::ResponseParsingError(detail))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_empty_bodies_ok() {
+        assert!(EmptyResponse::from_response_str("").is_ok());
+        assert!(EmptyResponse::from_response_str("{}").is_ok());
+        assert!(EmptyResponse::from_response_str("null\n").is_ok());
+    }
+
+    #[test]
+    fn parse_error_body_fails() {
+        let mock_response = r#"[{"kind":"temporary","id":"failure","msg":"unknown block"}]"#;
+        assert!(EmptyResponse::from_response_str(mock_response).is_err());
+    }
+}
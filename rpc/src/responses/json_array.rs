@@ -173,8 +173,7 @@ mod test {
         let mock_value_arr = [mock_object_1, mock_object_2];
         assert_eq!(json_array.len(), mock_value_arr.len());
 
-        let mock_value_and_parsed_value_tuple_iter =
-            mock_value_arr.iter().zip(json_array.into_iter());
+        let mock_value_and_parsed_value_tuple_iter = mock_value_arr.iter().zip(json_array);
 
         for tuple in mock_value_and_parsed_value_tuple_iter {
             let mock_val = tuple.0;
@@ -229,11 +228,11 @@ mod test {
 
         assert_eq!(flattened_response_vec.len(), flattened_mock_value_vec.len());
 
-        let mut zipped_iters = flattened_mock_value_vec
+        let zipped_iters = flattened_mock_value_vec
             .into_iter()
             .zip(flattened_response_vec);
 
-        while let Some(tuple) = zipped_iters.next() {
+        for tuple in zipped_iters {
             let response = tuple.1;
             let mock = *tuple.0;
             assert_eq!(response, mock);
@@ -259,7 +258,7 @@ mod test {
     fn no_json_array_values_parse_ok() {
         let mock_str_to_parse = "[]";
 
-        let parse_response = JsonArray::<String>::from_response_str(&mock_str_to_parse);
+        let parse_response = JsonArray::<String>::from_response_str(mock_str_to_parse);
         assert!(parse_response.is_ok());
 
         let json_array = parse_response.unwrap().into_vec();
@@ -270,20 +269,20 @@ mod test {
     fn empty_json_str_fail() {
         let mock_str_to_parse = "";
 
-        let parse_response = JsonArray::<String>::from_response_str(&mock_str_to_parse);
+        let parse_response = JsonArray::<String>::from_response_str(mock_str_to_parse);
         assert!(parse_response.is_err());
     }
 
-    fn get_tuple_vec_from_response_and_mock_values<'a, T, I: 'a>(
+    fn get_tuple_vec_from_response_and_mock_values<'a, T, I>(
         response: JsonArray<JsonArray<T>>,
         mock_values: I,
     ) -> Vec<(T, <<I as IntoIterator>::Item as IntoIterator>::Item)>
     where
         T: de::DeserializeOwned,
-        I: iter::IntoIterator,
+        I: iter::IntoIterator + 'a,
         I::Item: iter::IntoIterator,
     {
-        let mut zipped_tuple_iter = {
+        let zipped_tuple_iter = {
             let flat_response_iter = response.into_vec().into_iter().flatten();
             let mock_values_iter = mock_values.into_iter().flatten();
 
@@ -291,7 +290,7 @@ mod test {
         };
 
         let mut arr = Vec::new();
-        while let Some(tuple) = zipped_tuple_iter.next() {
+        for tuple in zipped_tuple_iter {
             arr.push(tuple);
         }
         arr
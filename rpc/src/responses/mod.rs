@@ -1,9 +1,27 @@
 pub mod chains;
+pub mod empty;
 pub mod json_array;
+pub mod network;
+pub mod node;
+pub mod protocols;
+pub mod workers;
 use crate::errors::ParseError;
+use crate::types::Protocol;
 
 pub trait Response {
     fn from_response_str(response: &str) -> Result<Self, ParseError>
     where
         Self: Sized;
 }
+
+/// Response whose schema depends on the protocol of the block it was
+/// requested for.
+///
+/// Decoded through [`WithProtocol`](crate::commands::protocols::with_protocol::WithProtocol),
+/// once the protocol is known, e.g. from
+/// [`RpcClient::detect_protocol()`](crate::RpcClient::detect_protocol()).
+pub trait ProtocolResponse: Response {
+    fn from_protocol_response_str(protocol: &Protocol, response: &str) -> Result<Self, ParseError>
+    where
+        Self: Sized;
+}
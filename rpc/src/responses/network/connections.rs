@@ -0,0 +1,85 @@
+use super::PointId;
+use crate::errors::ParseError;
+use crate::responses::Response;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AnnouncedVersion {
+    /// Name of the network, e.g. `TEZOS_MAINNET`.
+    pub chain_name: String,
+    pub distributed_db_version: u16,
+    pub p2p_version: u16,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ConnectionMetadata {
+    pub disable_mempool: bool,
+    pub private_node: bool,
+}
+
+/// An open connection to a peer.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Connection {
+    pub incoming: bool,
+    pub peer_id: String,
+    pub id_point: PointId,
+    pub remote_socket_port: u16,
+    pub announced_version: AnnouncedVersion,
+    pub private: bool,
+    pub local_metadata: ConnectionMetadata,
+    pub remote_metadata: ConnectionMetadata,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ConnectionsResponse {
+    pub connections: Vec<Connection>,
+}
+
+impl ConnectionsResponse {
+    pub fn incoming_count(&self) -> usize {
+        self.connections
+            .iter()
+            .filter(|connection| connection.incoming)
+            .count()
+    }
+}
+
+impl fmt::Display for ConnectionsResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for ConnectionsResponse {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let connections = serde_json::from_str(response)?;
+
+        Ok(Self { connections })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_connections_ok() {
+        let mock_response = r#"[{
+            "incoming": false,
+            "peer_id": "idrpUzAJV6bBj3vHFCNwY6aFCaDCpa",
+            "id_point": {"addr": "::ffff:51.15.220.7", "port": 9732},
+            "remote_socket_port": 9732,
+            "announced_version": {"chain_name": "TEZOS_MAINNET", "distributed_db_version": 2, "p2p_version": 1},
+            "private": false,
+            "local_metadata": {"disable_mempool": false, "private_node": false},
+            "remote_metadata": {"disable_mempool": false, "private_node": false}
+        }]"#;
+
+        let response = ConnectionsResponse::from_response_str(mock_response).unwrap();
+        assert_eq!(response.connections.len(), 1);
+        assert_eq!(response.incoming_count(), 0);
+        assert_eq!(response.connections[0].id_point.port, Some(9732));
+    }
+}
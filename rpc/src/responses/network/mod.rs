@@ -0,0 +1,41 @@
+pub mod connections;
+pub mod peers;
+pub mod points;
+pub mod stat;
+pub use connections::{AnnouncedVersion, Connection, ConnectionMetadata, ConnectionsResponse};
+pub use peers::{PeerInfo, PeersResponse};
+pub use points::{PointInfo, PointState, PointsResponse};
+pub use stat::{NetworkStat, PeerIdResponse};
+
+use serde::{Deserialize, Serialize};
+
+/// Connection state of a peer or point, also used to filter the peers and
+/// points listed by the node.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectionState {
+    /// A connection to the point was requested; only applies to points.
+    Requested,
+    Accepted,
+    Running,
+    Disconnected,
+}
+
+impl ConnectionState {
+    pub fn to_str(&self) -> &str {
+        match self {
+            Self::Requested => "requested",
+            Self::Accepted => "accepted",
+            Self::Running => "running",
+            Self::Disconnected => "disconnected",
+        }
+    }
+}
+
+/// An IP address and port.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct PointId {
+    pub addr: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+}
@@ -0,0 +1,94 @@
+use super::{ConnectionState, NetworkStat, PointId};
+use crate::errors::ParseError;
+use crate::responses::Response;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::fmt;
+
+/// What the node knows about a peer.
+///
+/// Connection history and metadata are kept in `extra`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PeerInfo {
+    pub score: f64,
+    pub trusted: bool,
+    pub state: ConnectionState,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reachable_at: Option<PointId>,
+    pub stat: NetworkStat,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl fmt::Display for PeerInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for PeerInfo {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        Ok(serde_json::from_str(response)?)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PeersResponse {
+    /// Peer ids along with what the node knows about them.
+    pub peers: Vec<(String, PeerInfo)>,
+}
+
+impl PeersResponse {
+    pub fn count_in_state(&self, state: ConnectionState) -> usize {
+        self.peers
+            .iter()
+            .filter(|(_peer_id, info)| info.state == state)
+            .count()
+    }
+}
+
+impl fmt::Display for PeersResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for PeersResponse {
+    /// Parses a response string in the form `[["idt...", $peer_info], ...]`.
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let peers = serde_json::from_str(response)?;
+
+        Ok(Self { peers })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_peers_ok() {
+        let mock_response = r#"[
+            ["idrpUzAJV6bBj3vHFCNwY6aFCaDCpa", {
+                "score": 0,
+                "trusted": false,
+                "state": "running",
+                "reachable_at": {"addr": "::ffff:51.15.220.7", "port": 9732},
+                "stat": {"total_sent": "1000", "total_recv": "2000", "current_inflow": 10, "current_outflow": 20},
+                "last_seen": [{"addr": "::ffff:51.15.220.7", "port": 9732}, "2024-03-01T12:00:00Z"]
+            }],
+            ["idtC6LLJSVNuGqVbrUJR2xAAq5Tz3a", {
+                "score": 0,
+                "trusted": true,
+                "state": "disconnected",
+                "stat": {"total_sent": "0", "total_recv": "0", "current_inflow": 0, "current_outflow": 0}
+            }]
+        ]"#;
+
+        let response = PeersResponse::from_response_str(mock_response).unwrap();
+        assert_eq!(response.peers.len(), 2);
+        assert_eq!(response.count_in_state(ConnectionState::Running), 1);
+        assert!(response.peers[0].1.extra.contains_key("last_seen"));
+        assert!(response.peers[1].1.reachable_at.is_none());
+    }
+}
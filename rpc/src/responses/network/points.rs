@@ -0,0 +1,94 @@
+use super::ConnectionState;
+use crate::errors::ParseError;
+use crate::responses::Response;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct PointState {
+    pub event_kind: ConnectionState,
+    /// The peer behind the point, once a connection is accepted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub p2p_peer_id: Option<String>,
+}
+
+/// What the node knows about a point (an address it may connect to).
+///
+/// Connection history is kept in `extra`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PointInfo {
+    pub trusted: bool,
+    pub state: PointState,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub p2p_peer_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub greylisted_until: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl fmt::Display for PointInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for PointInfo {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        Ok(serde_json::from_str(response)?)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PointsResponse {
+    /// Points (`address:port`) along with what the node knows about them.
+    pub points: Vec<(String, PointInfo)>,
+}
+
+impl fmt::Display for PointsResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for PointsResponse {
+    /// Parses a response string in the form `[["address:port", $point_info], ...]`.
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let points = serde_json::from_str(response)?;
+
+        Ok(Self { points })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_points_ok() {
+        let mock_response = r#"[
+            ["51.15.220.7:9732", {
+                "trusted": false,
+                "state": {"event_kind": "running", "p2p_peer_id": "idrpUzAJV6bBj3vHFCNwY6aFCaDCpa"},
+                "p2p_peer_id": "idrpUzAJV6bBj3vHFCNwY6aFCaDCpa",
+                "last_established_connection": ["idrpUzAJV6bBj3vHFCNwY6aFCaDCpa", "2024-03-01T12:00:00Z"]
+            }],
+            ["10.0.0.1:9732", {"trusted": true, "state": {"event_kind": "disconnected"}}]
+        ]"#;
+
+        let response = PointsResponse::from_response_str(mock_response).unwrap();
+        assert_eq!(
+            response.points[0].1.state.event_kind,
+            ConnectionState::Running
+        );
+        assert!(response.points[1].1.trusted);
+        assert!(response.points[1].1.state.p2p_peer_id.is_none());
+    }
+
+    #[test]
+    fn parse_unknown_point_state_fails() {
+        let mock_response = r#"{"trusted": false, "state": {"event_kind": "banned"}}"#;
+        assert!(PointInfo::from_response_str(mock_response).is_err());
+    }
+}
@@ -0,0 +1,72 @@
+use crate::errors::ParseError;
+use crate::responses::Response;
+use crate::types::int64;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::fmt;
+
+/// Bandwidth statistics of the node or of a single peer, in bytes and
+/// bytes per second.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct NetworkStat {
+    #[serde(deserialize_with = "int64::deserialize")]
+    pub total_sent: i64,
+    #[serde(deserialize_with = "int64::deserialize")]
+    pub total_recv: i64,
+    pub current_inflow: i32,
+    pub current_outflow: i32,
+}
+
+impl fmt::Display for NetworkStat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for NetworkStat {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        Ok(serde_json::from_str(response)?)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PeerIdResponse {
+    pub peer_id: String,
+}
+
+impl fmt::Display for PeerIdResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for PeerIdResponse {
+    /// Parses a response string in the form `"idt..."`.
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let peer_id = serde_json::from_str(response)?;
+
+        Ok(Self { peer_id })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_network_stat_ok() {
+        let mock_response = r#"{"total_sent":"1283746","total_recv":"9837465","current_inflow":1024,"current_outflow":512}"#;
+
+        let stat = NetworkStat::from_response_str(mock_response).unwrap();
+        assert_eq!(stat.total_recv, 9_837_465);
+        assert_eq!(stat.current_outflow, 512);
+    }
+
+    #[test]
+    fn parse_peer_id_ok() {
+        let mock_response = r#""idrpUzAJV6bBj3vHFCNwY6aFCaDCpa""#;
+
+        let response = PeerIdResponse::from_response_str(mock_response).unwrap();
+        assert_eq!(response.peer_id, "idrpUzAJV6bBj3vHFCNwY6aFCaDCpa");
+    }
+}
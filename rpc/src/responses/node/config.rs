@@ -0,0 +1,70 @@
+use crate::errors::ParseError;
+use crate::responses::Response;
+use crate::types::HistoryMode;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct HistoryModeResponse {
+    pub history_mode: HistoryMode,
+}
+
+impl fmt::Display for HistoryModeResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for HistoryModeResponse {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        Ok(serde_json::from_str(response)?)
+    }
+}
+
+/// A protocol switch forced by the node's configuration at `level`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct UserActivatedUpgrade {
+    pub level: i32,
+    pub replacement_protocol: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UserActivatedUpgradesResponse {
+    pub upgrades: Vec<UserActivatedUpgrade>,
+}
+
+impl fmt::Display for UserActivatedUpgradesResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for UserActivatedUpgradesResponse {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let upgrades = serde_json::from_str(response)?;
+
+        Ok(Self { upgrades })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_history_mode_ok() {
+        let mock_response = r#"{"history_mode":{"full":{"additional_cycles":1}}}"#;
+
+        let response = HistoryModeResponse::from_response_str(mock_response).unwrap();
+        assert!(!response.history_mode.is_archive());
+    }
+
+    #[test]
+    fn parse_user_activated_upgrades_ok() {
+        let mock_response = r#"[{"level":28082,"replacement_protocol":"PsYLVpVvgbLhAhoqAkMFUo6gudkJ9weNXhUYCiLDzcUpFpkk8Wt"}]"#;
+
+        let response = UserActivatedUpgradesResponse::from_response_str(mock_response).unwrap();
+        assert_eq!(response.upgrades[0].level, 28082);
+    }
+}
@@ -0,0 +1,4 @@
+pub mod config;
+pub mod version;
+pub use config::{HistoryModeResponse, UserActivatedUpgrade, UserActivatedUpgradesResponse};
+pub use version::{AdditionalInfo, CommitInfo, OctezVersion, VersionResponse};
@@ -0,0 +1,113 @@
+use crate::errors::ParseError;
+use crate::responses::network::AnnouncedVersion;
+use crate::responses::Response;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::fmt;
+
+/// Kind of an Octez release.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AdditionalInfo {
+    Dev,
+    Release,
+    Beta(u32),
+    /// Development version on top of a beta.
+    BetaDev(u32),
+    Rc(u32),
+    /// Development version on top of a release candidate.
+    RcDev(u32),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct OctezVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub additional_info: AdditionalInfo,
+}
+
+impl fmt::Display for OctezVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)?;
+        match self.additional_info {
+            AdditionalInfo::Dev => write!(f, "+dev"),
+            AdditionalInfo::Release => Ok(()),
+            AdditionalInfo::Beta(beta) => write!(f, "~beta{}", beta),
+            AdditionalInfo::BetaDev(beta) => write!(f, "~beta{}+dev", beta),
+            AdditionalInfo::Rc(rc) => write!(f, "~rc{}", rc),
+            AdditionalInfo::RcDev(rc) => write!(f, "~rc{}+dev", rc),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CommitInfo {
+    pub commit_hash: String,
+    pub commit_date: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct VersionResponse {
+    pub version: OctezVersion,
+    /// Network the node runs on and versions of its P2P protocols.
+    pub network_version: AnnouncedVersion,
+    pub commit_info: CommitInfo,
+}
+
+impl VersionResponse {
+    /// Name of the network the node runs on, e.g. `TEZOS_MAINNET`.
+    pub fn chain_name(&self) -> &str {
+        &self.network_version.chain_name
+    }
+}
+
+impl fmt::Display for VersionResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for VersionResponse {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        Ok(serde_json::from_str(response)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_version_ok() {
+        let mock_response = r#"{
+            "version": {"major": 20, "minor": 0, "additional_info": {"rc": 1}},
+            "network_version": {"chain_name": "TEZOS_MAINNET", "distributed_db_version": 2, "p2p_version": 1},
+            "commit_info": {"commit_hash": "a1b2c3d4", "commit_date": "2024-05-03 08:51:01 +0000"}
+        }"#;
+
+        let response = VersionResponse::from_response_str(mock_response).unwrap();
+        assert_eq!(response.chain_name(), "TEZOS_MAINNET");
+        assert_eq!(response.version.to_string(), "20.0~rc1");
+    }
+
+    #[test]
+    fn parse_release_version_ok() {
+        let mock_version = r#"{"major": 19, "minor": 1, "additional_info": "release"}"#;
+
+        let version: OctezVersion = serde_json::from_str(mock_version).unwrap();
+        assert_eq!(version.additional_info, AdditionalInfo::Release);
+        assert_eq!(version.to_string(), "19.1");
+    }
+
+    #[test]
+    fn parse_dev_versions_ok() {
+        let mock_version = r#"{"major": 21, "minor": 0, "additional_info": {"rc_dev": 2}}"#;
+        let version: OctezVersion = serde_json::from_str(mock_version).unwrap();
+        assert_eq!(version.additional_info, AdditionalInfo::RcDev(2));
+        assert_eq!(version.to_string(), "21.0~rc2+dev");
+
+        let mock_version = r#"{"major": 21, "minor": 0, "additional_info": {"beta_dev": 1}}"#;
+        let version: OctezVersion = serde_json::from_str(mock_version).unwrap();
+        assert_eq!(version.to_string(), "21.0~beta1+dev");
+    }
+}
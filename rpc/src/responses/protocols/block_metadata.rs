@@ -0,0 +1,344 @@
+use crate::errors::ParseError;
+use crate::responses::chains::blocks::{LevelInfo, VotingPeriodInfoResponse};
+use crate::responses::{ProtocolResponse, Response};
+use crate::types::{int64, Protocol};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::fmt;
+
+/// Change to the balance of a contract or of one of the protocol's internal
+/// accounts (freezers, accumulators, minted and burned tez...).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct BalanceUpdate {
+    pub kind: String,
+    /// Sub-kind of internal accounts, e.g. `"deposits"` or `"baking rewards"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contract: Option<String>,
+    /// Delegate whose frozen balance changed, up to Nairobi.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delegate: Option<String>,
+    #[serde(deserialize_with = "int64::deserialize")]
+    pub change: i64,
+    /// Why the update happened: `"block"`, `"migration"`, `"subsidy"`...
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub origin: Option<String>,
+    /// Cycle of frozen balances, for protocols that freeze per cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub staker: Option<Value>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl BalanceUpdate {
+    /// Delegate whose balance changed, whether given as `delegate` or
+    /// through `staker`.
+    pub fn delegate(&self) -> Option<&str> {
+        self.delegate.as_deref().or_else(|| {
+            let staker = self.staker.as_ref()?;
+            ["baker", "baker_own_stake", "baker_edge", "delegate"]
+                .iter()
+                .find_map(|key| staker.get(key))
+                .and_then(Value::as_str)
+        })
+    }
+}
+
+/// Fields of the block metadata served by every supported protocol.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BlockMetadataCommon {
+    pub protocol: Protocol,
+    pub next_protocol: Protocol,
+    pub max_operations_ttl: i32,
+    pub baker: String,
+    pub level_info: LevelInfo,
+    pub voting_period_info: VotingPeriodInfoResponse,
+    #[serde(default)]
+    pub nonce_hash: Option<String>,
+    #[serde(default)]
+    pub deactivated: Vec<String>,
+    #[serde(default)]
+    pub balance_updates: Vec<BalanceUpdate>,
+    #[serde(default)]
+    pub implicit_operations_results: Vec<Value>,
+}
+
+impl BlockMetadataCommon {
+    /// Normalised view of the metadata, with all protocol specific fields
+    /// left empty.
+    pub fn into_normalized(self, extra: Map<String, Value>) -> BlockMetadata {
+        BlockMetadata {
+            protocol: self.protocol,
+            next_protocol: self.next_protocol,
+            max_operations_ttl: self.max_operations_ttl,
+            baker: self.baker,
+            proposer: None,
+            baker_consensus_key: None,
+            proposer_consensus_key: None,
+            level_info: self.level_info,
+            voting_period_info: self.voting_period_info,
+            nonce_hash: self.nonce_hash,
+            consumed_milligas: None,
+            deactivated: self.deactivated,
+            balance_updates: self.balance_updates,
+            implicit_operations_results: self.implicit_operations_results,
+            liquidity_baking_ema: None,
+            adaptive_issuance_vote_ema: None,
+            adaptive_issuance_activation_cycle: None,
+            dal_attestation: None,
+            extra,
+        }
+    }
+}
+
+/// Block metadata normalised across protocols.
+///
+/// Fields which only exist in some protocols are `None` for the others, and
+/// fields which were renamed are exposed under a single name. Fields this
+/// view does not know about are kept in `extra`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BlockMetadata {
+    pub protocol: Protocol,
+    pub next_protocol: Protocol,
+    pub max_operations_ttl: i32,
+    pub baker: String,
+    /// Delegate which proposed the block's payload, from Ithaca on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proposer: Option<String>,
+    /// Consensus key used to sign the block, from Lima on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baker_consensus_key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proposer_consensus_key: Option<String>,
+    pub level_info: LevelInfo,
+    pub voting_period_info: VotingPeriodInfoResponse,
+    #[serde(default)]
+    pub nonce_hash: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "int64::deserialize_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub consumed_milligas: Option<i64>,
+    #[serde(default)]
+    pub deactivated: Vec<String>,
+    #[serde(default)]
+    pub balance_updates: Vec<BalanceUpdate>,
+    #[serde(default)]
+    pub implicit_operations_results: Vec<Value>,
+    /// Exponential moving average of the liquidity baking votes, called
+    /// `liquidity_baking_escape_ema` up to Ithaca.
+    #[serde(
+        default,
+        alias = "liquidity_baking_toggle_ema",
+        alias = "liquidity_baking_escape_ema",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub liquidity_baking_ema: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub adaptive_issuance_vote_ema: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub adaptive_issuance_activation_cycle: Option<i32>,
+    /// Bitset of the DAL slots attested in the block, called
+    /// `dal_slot_availability` in Kathmandu and Lima.
+    #[serde(
+        default,
+        alias = "dal_slot_availability",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub dal_attestation: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl BlockMetadata {
+    /// Delegate which proposed the block's payload, which is the baker for
+    /// protocols before Tenderbake.
+    pub fn proposer(&self) -> &str {
+        self.proposer.as_deref().unwrap_or(&self.baker)
+    }
+}
+
+impl fmt::Display for BlockMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for BlockMetadata {
+    /// Parses the metadata of a block from any protocol leniently, without
+    /// checking it against the protocol's schema.
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        Ok(serde_json::from_str(response)?)
+    }
+}
+
+impl ProtocolResponse for BlockMetadata {
+    /// Parses the metadata with the schema of `protocol` when its cargo
+    /// feature is enabled, and leniently otherwise.
+    fn from_protocol_response_str(protocol: &Protocol, response: &str) -> Result<Self, ParseError> {
+        match protocol {
+            #[cfg(feature = "hangzhou")]
+            Protocol::Hangzhou => parse::<super::hangzhou::BlockMetadata>(response),
+            #[cfg(feature = "ithaca")]
+            Protocol::Ithaca => parse::<super::ithaca::BlockMetadata>(response),
+            #[cfg(feature = "jakarta")]
+            Protocol::Jakarta => parse::<super::jakarta::BlockMetadata>(response),
+            #[cfg(feature = "kathmandu")]
+            Protocol::Kathmandu => parse::<super::kathmandu::BlockMetadata>(response),
+            #[cfg(feature = "lima")]
+            Protocol::Lima => parse::<super::lima::BlockMetadata>(response),
+            #[cfg(feature = "mumbai")]
+            Protocol::Mumbai => parse::<super::mumbai::BlockMetadata>(response),
+            #[cfg(feature = "nairobi")]
+            Protocol::Nairobi => parse::<super::nairobi::BlockMetadata>(response),
+            #[cfg(feature = "oxford")]
+            Protocol::Oxford => parse::<super::oxford::BlockMetadata>(response),
+            #[cfg(feature = "paris")]
+            Protocol::Paris | Protocol::ParisC => parse::<super::paris::BlockMetadata>(response),
+            #[cfg(feature = "quebec")]
+            Protocol::Quebec => parse::<super::quebec::BlockMetadata>(response),
+            #[cfg(feature = "rio")]
+            Protocol::Rio => parse::<super::rio::BlockMetadata>(response),
+            _ => Self::from_response_str(response),
+        }
+    }
+}
+
+#[allow(dead_code)]
+fn parse<T>(response: &str) -> Result<BlockMetadata, ParseError>
+where
+    T: serde::de::DeserializeOwned + Into<BlockMetadata>,
+{
+    let metadata: T = serde_json::from_str(response)?;
+    Ok(metadata.into())
+}
+
+/// Block metadata common to all protocols, merged with `specific` fields.
+#[cfg(test)]
+pub(super) fn mock_block_metadata(protocol: &str, specific: Value) -> String {
+    let mut metadata = json!({
+        "protocol": protocol,
+        "next_protocol": protocol,
+        "test_chain_status": { "status": "not_running" },
+        "max_operations_ttl": 240,
+        "max_operation_data_length": 32768,
+        "baker": "tz1VkjQjvH3NTVdFtGz9tWutgxLKwnhnMe2x",
+        "level_info": {
+            "level": 100,
+            "level_position": 99,
+            "cycle": 0,
+            "cycle_position": 99,
+            "expected_commitment": false
+        },
+        "voting_period_info": {
+            "voting_period": { "index": 0, "kind": "proposal", "start_position": 0 },
+            "position": 99,
+            "remaining": 1948
+        },
+        "nonce_hash": null,
+        "deactivated": [],
+        "balance_updates": [
+            {
+                "kind": "contract",
+                "contract": "tz1VkjQjvH3NTVdFtGz9tWutgxLKwnhnMe2x",
+                "change": "10000000",
+                "origin": "block"
+            }
+        ]
+    });
+    metadata
+        .as_object_mut()
+        .unwrap()
+        .extend(specific.as_object().unwrap().clone());
+    metadata.to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_block_metadata_leniently_ok() {
+        let mock_response = mock_block_metadata(
+            "PtNextProtocol",
+            json!({ "liquidity_baking_toggle_ema": 1000, "dal_slot_availability": "0" }),
+        );
+
+        let metadata = BlockMetadata::from_response_str(&mock_response).unwrap();
+        assert_eq!(metadata.liquidity_baking_ema, Some(1000));
+        assert_eq!(metadata.dal_attestation.as_deref(), Some("0"));
+        assert_eq!(metadata.proposer(), metadata.baker);
+        assert_eq!(metadata.balance_updates[0].change, 10000000);
+        assert!(metadata.extra.contains_key("test_chain_status"));
+    }
+
+    #[test]
+    fn unknown_protocol_falls_back_to_lenient_parsing() {
+        let mock_response = mock_block_metadata("PtNextProtocol", json!({}));
+        let protocol = Protocol::from_hash("PtNextProtocol");
+
+        let metadata =
+            BlockMetadata::from_protocol_response_str(&protocol, &mock_response).unwrap();
+        assert_eq!(metadata.protocol, protocol);
+    }
+
+    #[cfg(feature = "quebec")]
+    #[test]
+    fn known_protocol_enforces_its_schema() {
+        let quebec = Protocol::Quebec.hash();
+        let missing_proposer = mock_block_metadata(quebec, json!({}));
+
+        assert!(BlockMetadata::from_response_str(&missing_proposer).is_ok());
+        assert!(
+            BlockMetadata::from_protocol_response_str(&Protocol::Quebec, &missing_proposer)
+                .is_err()
+        );
+    }
+
+    #[cfg(feature = "rio")]
+    #[test]
+    fn aliased_protocol_enforces_its_schema() {
+        let rio = Protocol::Rio.hash();
+        let missing_proposer = mock_block_metadata(rio, json!({}));
+
+        assert!(
+            BlockMetadata::from_protocol_response_str(&Protocol::Rio, &missing_proposer).is_err()
+        );
+    }
+
+    #[test]
+    fn balance_update_delegate_ok() {
+        let delegate = "tz1VkjQjvH3NTVdFtGz9tWutgxLKwnhnMe2x";
+        let staked: BalanceUpdate = serde_json::from_value(json!({
+            "kind": "freezer",
+            "category": "deposits",
+            "staker": { "baker_own_stake": delegate },
+            "change": "-100",
+            "origin": "block"
+        }))
+        .unwrap();
+        let legacy: BalanceUpdate = serde_json::from_value(json!({
+            "kind": "freezer",
+            "category": "deposits",
+            "delegate": delegate,
+            "cycle": 1,
+            "change": "100"
+        }))
+        .unwrap();
+        let contract: BalanceUpdate = serde_json::from_value(json!({
+            "kind": "contract",
+            "contract": delegate,
+            "change": "100"
+        }))
+        .unwrap();
+
+        assert_eq!(staked.delegate(), Some(delegate));
+        assert_eq!(staked.change, -100);
+        assert_eq!(legacy.delegate(), Some(delegate));
+        assert_eq!(contract.delegate(), None);
+    }
+}
@@ -0,0 +1,72 @@
+//! Block metadata as served by Jakarta,
+//! which renames the liquidity baking escape vote to a toggle vote.
+
+use super::block_metadata::{BlockMetadata as NormalizedBlockMetadata, BlockMetadataCommon};
+use crate::errors::ParseError;
+use crate::responses::Response;
+use crate::types::int64;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BlockMetadata {
+    #[serde(flatten)]
+    pub common: BlockMetadataCommon,
+    pub proposer: String,
+    #[serde(deserialize_with = "int64::deserialize")]
+    pub consumed_milligas: i64,
+    pub liquidity_baking_toggle_ema: i64,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl From<BlockMetadata> for NormalizedBlockMetadata {
+    fn from(metadata: BlockMetadata) -> Self {
+        NormalizedBlockMetadata {
+            proposer: Some(metadata.proposer),
+            consumed_milligas: Some(metadata.consumed_milligas),
+            liquidity_baking_ema: Some(metadata.liquidity_baking_toggle_ema),
+            ..metadata.common.into_normalized(metadata.extra)
+        }
+    }
+}
+
+impl fmt::Display for BlockMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for BlockMetadata {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        Ok(serde_json::from_str(response)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::responses::protocols::block_metadata::mock_block_metadata;
+    use crate::types::Protocol;
+
+    #[test]
+    fn parse_jakarta_block_metadata_ok() {
+        let mock_response = mock_block_metadata(
+            Protocol::Jakarta.hash(),
+            json!({
+                "proposer": "tz1VkjQjvH3NTVdFtGz9tWutgxLKwnhnMe2x",
+                "consumed_milligas": 1000,
+                "liquidity_baking_toggle_ema": 1000,
+            }),
+        );
+
+        let metadata = BlockMetadata::from_response_str(&mock_response).unwrap();
+        let normalized = NormalizedBlockMetadata::from(metadata);
+        assert_eq!(normalized.protocol, Protocol::Jakarta);
+        assert_eq!(normalized.liquidity_baking_ema, Some(1000));
+        assert_eq!(normalized.consumed_milligas, Some(1000));
+        assert!(normalized.proposer.is_some());
+        assert!(normalized.extra.contains_key("test_chain_status"));
+    }
+}
@@ -0,0 +1,40 @@
+//! Responses whose shape depends on the protocol.
+//!
+//! Only block metadata is versioned: the schema of each protocol lives in a
+//! module named after it, compiled in when the cargo feature of the same name
+//! is enabled (all of them are by default). [`BlockMetadata`] is the
+//! normalised view over all of them. Operation contents and receipts have no
+//! per-protocol schemas and are classified by the shared [`OperationKind`].
+
+pub mod block_metadata;
+#[cfg(feature = "hangzhou")]
+pub mod hangzhou;
+#[cfg(feature = "ithaca")]
+pub mod ithaca;
+#[cfg(feature = "jakarta")]
+pub mod jakarta;
+#[cfg(feature = "kathmandu")]
+pub mod kathmandu;
+#[cfg(feature = "lima")]
+pub mod lima;
+#[cfg(feature = "mumbai")]
+pub mod mumbai;
+pub mod operation_kind;
+#[cfg(feature = "oxford")]
+pub mod oxford;
+pub mod sources;
+
+// Protocols whose block metadata kept the shape of an earlier one are aliases
+// of that protocol's module.
+#[cfg(feature = "nairobi")]
+pub use mumbai as nairobi;
+#[cfg(feature = "paris")]
+pub use oxford as paris;
+#[cfg(feature = "quebec")]
+pub use oxford as quebec;
+#[cfg(feature = "rio")]
+pub use oxford as rio;
+
+pub use block_metadata::{BalanceUpdate, BlockMetadata, BlockMetadataCommon};
+pub use operation_kind::OperationKind;
+pub use sources::{ProtocolComponent, ProtocolSourcesResponse, ProtocolsResponse};
@@ -0,0 +1,182 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// Kind of an operation's content, normalised across protocols.
+///
+/// Consensus operations renamed in Oxford (`endorsement` to `attestation`...)
+/// and the DAL publication renamed in Paris (`dal_publish_slot_header` to
+/// `dal_publish_commitment`) map to their latest name. Kinds this crate does
+/// not know about are kept as [`Other`](Self::Other).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum OperationKind {
+    Preattestation,
+    Attestation,
+    AttestationsAggregate,
+    DoubleBakingEvidence,
+    DoublePreattestationEvidence,
+    DoubleAttestationEvidence,
+    SeedNonceRevelation,
+    VdfRevelation,
+    ActivateAccount,
+    Proposals,
+    Ballot,
+    Reveal,
+    Transaction,
+    Origination,
+    Delegation,
+    SetDepositsLimit,
+    UpdateConsensusKey,
+    DrainDelegate,
+    IncreasePaidStorage,
+    RegisterGlobalConstant,
+    TransferTicket,
+    DalPublishCommitment,
+    Other(String),
+}
+
+impl OperationKind {
+    pub fn from_kind_str(kind: &str) -> Self {
+        match kind {
+            "preendorsement" | "preattestation" => Self::Preattestation,
+            "endorsement"
+            | "endorsement_with_slot"
+            | "endorsement_with_dal"
+            | "attestation"
+            | "attestation_with_dal" => Self::Attestation,
+            "attestations_aggregate" => Self::AttestationsAggregate,
+            "double_baking_evidence" => Self::DoubleBakingEvidence,
+            "double_preendorsement_evidence" | "double_preattestation_evidence" => {
+                Self::DoublePreattestationEvidence
+            }
+            "double_endorsement_evidence" | "double_attestation_evidence" => {
+                Self::DoubleAttestationEvidence
+            }
+            "seed_nonce_revelation" => Self::SeedNonceRevelation,
+            "vdf_revelation" => Self::VdfRevelation,
+            "activate_account" => Self::ActivateAccount,
+            "proposals" => Self::Proposals,
+            "ballot" => Self::Ballot,
+            "reveal" => Self::Reveal,
+            "transaction" => Self::Transaction,
+            "origination" => Self::Origination,
+            "delegation" => Self::Delegation,
+            "set_deposits_limit" => Self::SetDepositsLimit,
+            "update_consensus_key" => Self::UpdateConsensusKey,
+            "drain_delegate" => Self::DrainDelegate,
+            "increase_paid_storage" => Self::IncreasePaidStorage,
+            "register_global_constant" => Self::RegisterGlobalConstant,
+            "transfer_ticket" => Self::TransferTicket,
+            "dal_publish_slot_header" | "dal_publish_commitment" => Self::DalPublishCommitment,
+            other => Self::Other(other.to_string()),
+        }
+    }
+
+    /// Name of the kind in the latest protocols.
+    pub fn to_str(&self) -> &str {
+        match self {
+            Self::Preattestation => "preattestation",
+            Self::Attestation => "attestation",
+            Self::AttestationsAggregate => "attestations_aggregate",
+            Self::DoubleBakingEvidence => "double_baking_evidence",
+            Self::DoublePreattestationEvidence => "double_preattestation_evidence",
+            Self::DoubleAttestationEvidence => "double_attestation_evidence",
+            Self::SeedNonceRevelation => "seed_nonce_revelation",
+            Self::VdfRevelation => "vdf_revelation",
+            Self::ActivateAccount => "activate_account",
+            Self::Proposals => "proposals",
+            Self::Ballot => "ballot",
+            Self::Reveal => "reveal",
+            Self::Transaction => "transaction",
+            Self::Origination => "origination",
+            Self::Delegation => "delegation",
+            Self::SetDepositsLimit => "set_deposits_limit",
+            Self::UpdateConsensusKey => "update_consensus_key",
+            Self::DrainDelegate => "drain_delegate",
+            Self::IncreasePaidStorage => "increase_paid_storage",
+            Self::RegisterGlobalConstant => "register_global_constant",
+            Self::TransferTicket => "transfer_ticket",
+            Self::DalPublishCommitment => "dal_publish_commitment",
+            Self::Other(kind) => kind,
+        }
+    }
+
+    /// Whether the operation is part of the consensus, validated in the
+    /// first validation pass.
+    pub fn is_consensus(&self) -> bool {
+        matches!(
+            self,
+            Self::Preattestation | Self::Attestation | Self::AttestationsAggregate
+        )
+    }
+
+    /// Whether the operation is signed by a manager and pays fees.
+    pub fn is_manager(&self) -> bool {
+        matches!(
+            self,
+            Self::Reveal
+                | Self::Transaction
+                | Self::Origination
+                | Self::Delegation
+                | Self::SetDepositsLimit
+                | Self::UpdateConsensusKey
+                | Self::IncreasePaidStorage
+                | Self::RegisterGlobalConstant
+                | Self::TransferTicket
+                | Self::DalPublishCommitment
+        ) || matches!(self, Self::Other(kind) if kind.starts_with("smart_rollup_"))
+    }
+}
+
+impl fmt::Display for OperationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_str())
+    }
+}
+
+impl Serialize for OperationKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.to_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for OperationKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let kind = String::deserialize(deserializer)?;
+        Ok(Self::from_kind_str(&kind))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn legacy_kinds_are_normalised() {
+        let legacy: Vec<OperationKind> =
+            serde_json::from_str(r#"["endorsement","preendorsement","dal_publish_slot_header"]"#)
+                .unwrap();
+
+        assert_eq!(
+            legacy,
+            vec![
+                OperationKind::Attestation,
+                OperationKind::Preattestation,
+                OperationKind::DalPublishCommitment
+            ]
+        );
+        assert!(legacy[0].is_consensus());
+        assert_eq!(
+            serde_json::to_string(&legacy[0]).unwrap(),
+            r#""attestation""#
+        );
+    }
+
+    #[test]
+    fn unknown_kinds_are_kept() {
+        let kind = OperationKind::from_kind_str("smart_rollup_add_messages");
+
+        assert_eq!(kind.to_str(), "smart_rollup_add_messages");
+        assert!(kind.is_manager());
+        assert!(!OperationKind::Ballot.is_manager());
+    }
+}
@@ -0,0 +1,87 @@
+//! Block metadata as served by Oxford,
+//! which adds the adaptive issuance vote.
+
+use super::block_metadata::{BlockMetadata as NormalizedBlockMetadata, BlockMetadataCommon};
+use crate::errors::ParseError;
+use crate::responses::Response;
+use crate::types::int64;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BlockMetadata {
+    #[serde(flatten)]
+    pub common: BlockMetadataCommon,
+    pub proposer: String,
+    pub proposer_consensus_key: String,
+    pub baker_consensus_key: String,
+    #[serde(deserialize_with = "int64::deserialize")]
+    pub consumed_milligas: i64,
+    pub liquidity_baking_toggle_ema: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub adaptive_issuance_vote_ema: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub adaptive_issuance_activation_cycle: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dal_attestation: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl From<BlockMetadata> for NormalizedBlockMetadata {
+    fn from(metadata: BlockMetadata) -> Self {
+        NormalizedBlockMetadata {
+            proposer: Some(metadata.proposer),
+            proposer_consensus_key: Some(metadata.proposer_consensus_key),
+            baker_consensus_key: Some(metadata.baker_consensus_key),
+            consumed_milligas: Some(metadata.consumed_milligas),
+            liquidity_baking_ema: Some(metadata.liquidity_baking_toggle_ema),
+            adaptive_issuance_vote_ema: metadata.adaptive_issuance_vote_ema,
+            adaptive_issuance_activation_cycle: metadata.adaptive_issuance_activation_cycle,
+            dal_attestation: metadata.dal_attestation,
+            ..metadata.common.into_normalized(metadata.extra)
+        }
+    }
+}
+
+impl fmt::Display for BlockMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for BlockMetadata {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        Ok(serde_json::from_str(response)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::responses::protocols::block_metadata::mock_block_metadata;
+    use crate::types::Protocol;
+
+    #[test]
+    fn parse_oxford_block_metadata_ok() {
+        let mock_response = mock_block_metadata(
+            Protocol::Oxford.hash(),
+            json!({
+                "proposer": "tz1VkjQjvH3NTVdFtGz9tWutgxLKwnhnMe2x",
+                "proposer_consensus_key": "tz1VkjQjvH3NTVdFtGz9tWutgxLKwnhnMe2x",
+                "baker_consensus_key": "tz1VkjQjvH3NTVdFtGz9tWutgxLKwnhnMe2x",
+                "consumed_milligas": 1000,
+                "liquidity_baking_toggle_ema": 1000,
+            }),
+        );
+
+        let metadata = BlockMetadata::from_response_str(&mock_response).unwrap();
+        let normalized = NormalizedBlockMetadata::from(metadata);
+        assert_eq!(normalized.protocol, Protocol::Oxford);
+        assert_eq!(normalized.liquidity_baking_ema, Some(1000));
+        assert_eq!(normalized.consumed_milligas, Some(1000));
+        assert!(normalized.proposer.is_some());
+        assert!(normalized.extra.contains_key("test_chain_status"));
+    }
+}
@@ -0,0 +1,104 @@
+use crate::errors::ParseError;
+use crate::responses::Response;
+use crate::types::Protocol;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ProtocolsResponse {
+    pub protocols: Vec<Protocol>,
+}
+
+impl ProtocolsResponse {
+    pub fn contains(&self, protocol: &Protocol) -> bool {
+        self.protocols.contains(protocol)
+    }
+}
+
+impl fmt::Display for ProtocolsResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for ProtocolsResponse {
+    /// Parses a response string in the form `["PsQuebec...", "ProtoALpha..."]`.
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let protocols = serde_json::from_str(response)?;
+
+        Ok(Self { protocols })
+    }
+}
+
+/// OCaml module of a protocol's sources.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ProtocolComponent {
+    pub name: String,
+    /// Contents of the `.mli` file, if the module has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interface: Option<String>,
+    /// Contents of the `.ml` file.
+    pub implementation: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ProtocolSourcesResponse {
+    /// Version of the protocol environment the sources are compiled against.
+    pub expected_env_version: u16,
+    pub components: Vec<ProtocolComponent>,
+}
+
+impl ProtocolSourcesResponse {
+    pub fn component(&self, name: &str) -> Option<&ProtocolComponent> {
+        self.components
+            .iter()
+            .find(|component| component.name == name)
+    }
+}
+
+impl fmt::Display for ProtocolSourcesResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for ProtocolSourcesResponse {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        Ok(serde_json::from_str(response)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_protocols_ok() {
+        let mock_response =
+            r#"["PsQuebecnLByd3JwTiGadoG4nGWi3HYiLXUjkibeFV8dCFeVMUg","PtNextProtocol"]"#;
+
+        let response = ProtocolsResponse::from_response_str(mock_response).unwrap();
+        assert!(response.contains(&Protocol::Quebec));
+        assert_eq!(
+            response.protocols[1],
+            Protocol::Unknown("PtNextProtocol".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_protocol_sources_ok() {
+        let mock_response = r#"{
+            "expected_env_version": 12,
+            "components": [
+                {"name": "Constants_repr", "interface": "val x : int", "implementation": "let x = 1"},
+                {"name": "Main", "implementation": "let main = ()"}
+            ]
+        }"#;
+
+        let response = ProtocolSourcesResponse::from_response_str(mock_response).unwrap();
+        assert_eq!(response.expected_env_version, 12);
+        assert!(response.component("Main").unwrap().interface.is_none());
+        assert!(response.component("Apply").is_none());
+    }
+}
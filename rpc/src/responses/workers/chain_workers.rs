@@ -0,0 +1,116 @@
+use super::status::WorkerStatus;
+use crate::errors::ParseError;
+use crate::responses::Response;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::fmt;
+
+/// Status of the worker handling one chain.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ChainWorker {
+    pub chain_id: String,
+    pub status: WorkerStatus,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Workers running one instance per chain: prevalidators and chain
+/// validators.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChainWorkersResponse {
+    pub workers: Vec<ChainWorker>,
+}
+
+impl ChainWorkersResponse {
+    /// Workers which are not running, e.g. crashed ones.
+    pub fn not_running(&self) -> impl Iterator<Item = &ChainWorker> {
+        self.workers
+            .iter()
+            .filter(|worker| !worker.status.is_running())
+    }
+}
+
+impl fmt::Display for ChainWorkersResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for ChainWorkersResponse {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        let workers = serde_json::from_str(response)?;
+
+        Ok(Self { workers })
+    }
+}
+
+/// Size of one of the distributed database's request tables.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TableStats {
+    pub table_length: i32,
+    pub scheduler_length: i32,
+}
+
+/// State of the distributed database a chain validator fetches blocks and
+/// operations with.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DistributedDbResponse {
+    pub p2p_readers: i32,
+    pub active_chains: i32,
+    pub operation_db: TableStats,
+    pub operations_db: TableStats,
+    pub block_header_db: TableStats,
+    pub active_connections: i32,
+    pub active_peers: i32,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl fmt::Display for DistributedDbResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for DistributedDbResponse {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        Ok(serde_json::from_str(response)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_prevalidators_ok() {
+        let mock_response = r#"[
+            {"chain_id": "NetXdQprcVkpaWU", "status": {"phase": "running", "since": "2024-03-01T12:00:00Z"}, "information": {"instances_number": 1}, "pipelines": 0},
+            {"chain_id": "NetXnHfVqm9iesp", "status": {"phase": "closed", "birth": "2024-03-01T12:00:00Z", "since": "2024-03-01T13:00:00Z"}}
+        ]"#;
+
+        let response = ChainWorkersResponse::from_response_str(mock_response).unwrap();
+        assert_eq!(response.workers.len(), 2);
+        let not_running: Vec<_> = response.not_running().collect();
+        assert_eq!(not_running.len(), 1);
+        assert_eq!(not_running[0].chain_id, "NetXnHfVqm9iesp");
+    }
+
+    #[test]
+    fn parse_distributed_db_ok() {
+        let mock_response = r#"{
+            "p2p_readers": 12,
+            "active_chains": 1,
+            "operation_db": {"table_length": 3, "scheduler_length": 3},
+            "operations_db": {"table_length": 0, "scheduler_length": 0},
+            "block_header_db": {"table_length": 1, "scheduler_length": 1},
+            "operations_hashes_db": {"table_length": 0, "scheduler_length": 0},
+            "active_connections": 12,
+            "active_peers": 12
+        }"#;
+
+        let response = DistributedDbResponse::from_response_str(mock_response).unwrap();
+        assert_eq!(response.active_peers, 12);
+        assert!(response.extra.contains_key("operations_hashes_db"));
+    }
+}
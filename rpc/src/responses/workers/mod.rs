@@ -0,0 +1,6 @@
+pub mod chain_workers;
+pub mod status;
+pub mod worker_state;
+pub use chain_workers::{ChainWorker, ChainWorkersResponse, DistributedDbResponse, TableStats};
+pub use status::{CurrentRequest, PendingRequest, WorkerStatus};
+pub use worker_state::WorkerStateResponse;
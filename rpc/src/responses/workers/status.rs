@@ -0,0 +1,65 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Lifecycle phase of a node worker.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "phase", rename_all = "snake_case")]
+pub enum WorkerStatus {
+    Launching {
+        since: DateTime<Utc>,
+    },
+    Running {
+        since: DateTime<Utc>,
+    },
+    Closing {
+        birth: DateTime<Utc>,
+        since: DateTime<Utc>,
+    },
+    Closed {
+        birth: DateTime<Utc>,
+        since: DateTime<Utc>,
+    },
+    Crashed {
+        birth: DateTime<Utc>,
+        since: DateTime<Utc>,
+        errors: Value,
+    },
+}
+
+impl WorkerStatus {
+    pub fn is_running(&self) -> bool {
+        matches!(self, Self::Running { .. })
+    }
+}
+
+/// A request waiting in a worker's queue.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PendingRequest {
+    pub pushed: DateTime<Utc>,
+    /// The request itself, whose shape depends on the worker.
+    pub request: Value,
+}
+
+/// The request a worker is processing or last processed.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CurrentRequest {
+    pub pushed: DateTime<Utc>,
+    /// Seconds the request waited in the queue.
+    pub treated: f64,
+    /// Seconds the request took to process.
+    pub completed: f64,
+    pub request: Value,
+}
+
+/// Age of the oldest of `requests` at `now`, if any.
+pub(super) fn oldest_request_age(
+    requests: &[PendingRequest],
+    now: DateTime<Utc>,
+) -> Option<Duration> {
+    requests
+        .iter()
+        .map(|request| request.pushed)
+        .min()
+        .map(|pushed| now - pushed)
+}
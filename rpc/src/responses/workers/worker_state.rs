@@ -0,0 +1,89 @@
+use super::status::{oldest_request_age, CurrentRequest, PendingRequest, WorkerStatus};
+use crate::errors::ParseError;
+use crate::responses::Response;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::fmt;
+
+/// Detailed state of a worker: its status and request queue.
+///
+/// Worker specific information is kept in `extra`.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct WorkerStateResponse {
+    pub status: WorkerStatus,
+    #[serde(default)]
+    pub pending_requests: Vec<PendingRequest>,
+    /// Recent events logged by the worker.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub backlog: Vec<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_request: Option<CurrentRequest>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl WorkerStateResponse {
+    /// How long the oldest pending request has been waiting at `now`; a
+    /// growing value is the sign of a stuck worker.
+    pub fn oldest_pending_request_age(&self, now: DateTime<Utc>) -> Option<Duration> {
+        oldest_request_age(&self.pending_requests, now)
+    }
+}
+
+impl fmt::Display for WorkerStateResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Response for WorkerStateResponse {
+    fn from_response_str(response: &str) -> Result<Self, ParseError> {
+        Ok(serde_json::from_str(response)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_block_validator_state_ok() {
+        let mock_response = r#"{
+            "status": {"phase": "running", "since": "2024-03-01T12:00:00Z"},
+            "pending_requests": [
+                {"pushed": "2024-03-01T12:10:00Z", "request": {"block": "BLockGenesisGenesisGenesisGenesisGenesisf79b5d1CoW2", "chain_id": "NetXdQprcVkpaWU", "peer": "idrpUzAJV6bBj3vHFCNwY6aFCaDCpa"}},
+                {"pushed": "2024-03-01T12:09:00Z", "request": {"block": "BLockGenesisGenesisGenesisGenesisGenesisf79b5d1CoW2", "chain_id": "NetXdQprcVkpaWU"}}
+            ],
+            "backlog": [],
+            "current_request": {"pushed": "2024-03-01T12:08:00Z", "treated": 0.001, "completed": 0.25, "request": {}}
+        }"#;
+
+        let response = WorkerStateResponse::from_response_str(mock_response).unwrap();
+        assert!(response.status.is_running());
+        assert_eq!(response.current_request.as_ref().unwrap().completed, 0.25);
+
+        let now = DateTime::parse_from_rfc3339("2024-03-01T12:11:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(
+            response.oldest_pending_request_age(now),
+            Some(Duration::minutes(2))
+        );
+    }
+
+    #[test]
+    fn parse_crashed_worker_ok() {
+        let mock_response = r#"{"status": {"phase": "crashed", "birth": "2024-03-01T12:00:00Z", "since": "2024-03-01T12:05:00Z", "errors": []}}"#;
+
+        let response = WorkerStateResponse::from_response_str(mock_response).unwrap();
+        assert!(matches!(response.status, WorkerStatus::Crashed { .. }));
+        assert!(response.pending_requests.is_empty());
+    }
+
+    #[test]
+    fn parse_unknown_phase_fails() {
+        let mock_response = r#"{"status": {"phase": "sleeping", "since": "2024-03-01T12:00:00Z"}}"#;
+        assert!(WorkerStateResponse::from_response_str(mock_response).is_err());
+    }
+}
@@ -0,0 +1,115 @@
+use super::Signer;
+use crate::crypto::{self, PublicKey, Signature, Watermark};
+use crate::errors::RpcError;
+use async_trait::async_trait;
+use tokio::sync::OnceCell;
+
+/// Key store that only signs precomputed digests, such as a cloud KMS or an
+/// HSM: it never sees the watermarked bytes, only their blake2b digest.
+#[async_trait]
+pub trait DigestBackend: Send + Sync {
+    async fn public_key(&self) -> Result<PublicKey, RpcError>;
+
+    /// Signs `digest`, returning the signature in Tezos' 64 byte form
+    /// (`r || s` with a low `s` for secp256k1 and P-256 keys), which may
+    /// require converting the DER signatures KMS services return.
+    async fn sign_digest(&self, digest: &[u8; 32]) -> Result<Signature, RpcError>;
+}
+
+/// [`Signer`] for a key behind a [`DigestBackend`].
+///
+/// Computes the signing digest locally, and checks each signature the
+/// backend returns against its public key, fetched once.
+pub struct DigestSigner<B> {
+    backend: B,
+    public_key: OnceCell<PublicKey>,
+}
+
+impl<B: DigestBackend> DigestSigner<B> {
+    pub fn new(backend: B) -> Self {
+        Self {
+            backend,
+            public_key: OnceCell::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl<B: DigestBackend> Signer for DigestSigner<B> {
+    async fn public_key(&self) -> Result<PublicKey, RpcError> {
+        let public_key = self
+            .public_key
+            .get_or_try_init(|| self.backend.public_key())
+            .await?;
+        Ok(public_key.clone())
+    }
+
+    async fn sign(
+        &self,
+        watermark: Option<&Watermark>,
+        bytes: &[u8],
+    ) -> Result<Signature, RpcError> {
+        let digest = crypto::signing_digest(watermark, bytes)?;
+        let signature = self.backend.sign_digest(&digest).await?;
+        if !self.public_key().await?.verify_digest(&digest, &signature) {
+            return Err(RpcError::Other(
+                "digest signing backend returned an invalid signature".to_string(),
+            ));
+        }
+        Ok(signature)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::crypto::{Curve, SecretKey};
+
+    /// Backend signing with an in-memory key, optionally signing with
+    /// another key to simulate a misbehaving backend.
+    struct LocalBackend {
+        secret_key: SecretKey,
+        signing_key: SecretKey,
+    }
+
+    #[async_trait]
+    impl DigestBackend for LocalBackend {
+        async fn public_key(&self) -> Result<PublicKey, RpcError> {
+            Ok(self.secret_key.public_key())
+        }
+
+        async fn sign_digest(&self, digest: &[u8; 32]) -> Result<Signature, RpcError> {
+            Ok(self.signing_key.sign_digest(digest)?)
+        }
+    }
+
+    #[tokio::test]
+    async fn digest_signer_ok() {
+        let secret_key = SecretKey::from_bytes(Curve::P256, &[7; 32]).unwrap();
+        let signer = DigestSigner::new(LocalBackend {
+            secret_key: secret_key.clone(),
+            signing_key: secret_key.clone(),
+        });
+
+        let watermark = Watermark::GenericOperation;
+        let signature = signer.sign(Some(&watermark), b"operation").await.unwrap();
+        assert!(secret_key
+            .public_key()
+            .verify(Some(&watermark), b"operation", &signature)
+            .unwrap());
+        assert_eq!(
+            signer.public_key_hash().await.unwrap(),
+            secret_key.public_key_hash()
+        );
+    }
+
+    #[tokio::test]
+    async fn wrong_digest_signature_fails() {
+        let signer = DigestSigner::new(LocalBackend {
+            secret_key: SecretKey::from_bytes(Curve::Secp256k1, &[7; 32]).unwrap(),
+            signing_key: SecretKey::from_bytes(Curve::Secp256k1, &[8; 32]).unwrap(),
+        });
+
+        assert!(signer.sign(None, b"bytes").await.is_err());
+    }
+}
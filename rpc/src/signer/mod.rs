@@ -0,0 +1,102 @@
+//! Signing of operations and other watermarked bytes, decoupled from where
+//! keys are stored.
+//!
+//! [`InMemorySigner`] holds a [`SecretKey`] in-process, while
+//! [`RemoteSigner`] delegates to a signer speaking the HTTP protocol of
+//! `octez-signer`, and [`DigestSigner`] to a [`DigestBackend`] such as a
+//! KMS, so that keys never enter the process.
+
+mod digest;
+mod remote;
+pub use digest::{DigestBackend, DigestSigner};
+pub use remote::RemoteSigner;
+
+use crate::crypto::{PublicKey, SecretKey, Signature, Watermark};
+use crate::errors::RpcError;
+use crate::operations::Operation;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait Signer: Send + Sync {
+    async fn public_key(&self) -> Result<PublicKey, RpcError>;
+
+    /// `tz1`, `tz2` or `tz3` address of the signing key.
+    async fn public_key_hash(&self) -> Result<String, RpcError> {
+        Ok(self.public_key().await?.public_key_hash())
+    }
+
+    /// Signs the digest of the watermarked `bytes`.
+    async fn sign(
+        &self,
+        watermark: Option<&Watermark>,
+        bytes: &[u8],
+    ) -> Result<Signature, RpcError>;
+
+    /// Returns a copy of `operation` signed with the generic operation
+    /// watermark, ready for injection.
+    async fn sign_operation(&self, operation: &Operation) -> Result<Operation, RpcError> {
+        let bytes = operation.forge_unsigned()?;
+        let signature = self
+            .sign(Some(&Watermark::GenericOperation), &bytes)
+            .await?;
+
+        let mut signed = operation.clone();
+        signed.signature = Some(signature);
+        Ok(signed)
+    }
+}
+
+/// [`Signer`] holding its secret key in memory.
+pub struct InMemorySigner {
+    secret_key: SecretKey,
+}
+
+impl InMemorySigner {
+    pub fn new(secret_key: SecretKey) -> Self {
+        Self { secret_key }
+    }
+}
+
+impl From<SecretKey> for InMemorySigner {
+    fn from(secret_key: SecretKey) -> Self {
+        Self::new(secret_key)
+    }
+}
+
+#[async_trait]
+impl Signer for InMemorySigner {
+    async fn public_key(&self) -> Result<PublicKey, RpcError> {
+        Ok(self.secret_key.public_key())
+    }
+
+    async fn sign(
+        &self,
+        watermark: Option<&Watermark>,
+        bytes: &[u8],
+    ) -> Result<Signature, RpcError> {
+        Ok(self.secret_key.sign(watermark, bytes)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::crypto::Curve;
+
+    #[tokio::test]
+    async fn in_memory_signer_signs_operations_ok() {
+        let operation: Operation = serde_json::from_str(
+            r#"{"branch":"BLockGenesisGenesisGenesisGenesisGenesisf79b5d1CoW2",
+                "contents":[{"kind":"delegation","source":"tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx",
+                "fee":"1000","counter":"3","gas_limit":"1100","storage_limit":"0"}]}"#,
+        )
+        .unwrap();
+        let signer =
+            InMemorySigner::new(SecretKey::from_bytes(Curve::Secp256k1, &[6; 32]).unwrap());
+
+        let signed = signer.sign_operation(&operation).await.unwrap();
+        let public_key = signer.public_key().await.unwrap();
+        assert!(signed.verify(&public_key).unwrap());
+        assert!(signer.public_key_hash().await.unwrap().starts_with("tz2"));
+    }
+}
@@ -0,0 +1,357 @@
+use super::Signer;
+use crate::crypto::{PublicKey, SecretKey, Signature, Watermark};
+use crate::encoding::forged;
+use crate::errors::{ParseError, RpcError};
+use async_trait::async_trait;
+use serde::Deserialize;
+use tokio::sync::OnceCell;
+use url::Url;
+
+/// Tag prepended to the messages signed to authenticate requests.
+const AUTHENTICATION_TAG: u8 = 0x04;
+
+#[derive(Deserialize)]
+struct PublicKeyResponse {
+    public_key: PublicKey,
+}
+
+#[derive(Deserialize)]
+struct SignatureResponse {
+    signature: String,
+}
+
+#[derive(Deserialize)]
+struct AuthorizedKeysResponse {
+    authorized_keys: Option<Vec<String>>,
+}
+
+/// [`Signer`] for a key held by a remote signer, such as `octez-signer`
+/// launched in HTTP mode.
+///
+/// The remote signer receives the watermarked bytes, and may refuse to
+/// sign some of them depending on its configuration (e.g. only blocks and
+/// attestations for a baker). Its signatures are checked against the public
+/// key it serves, fetched once.
+pub struct RemoteSigner {
+    client: reqwest::Client,
+    base_url: Url,
+    public_key_hash: String,
+    authentication_key: Option<SecretKey>,
+    public_key: OnceCell<PublicKey>,
+}
+
+impl RemoteSigner {
+    /// Signs with the key of `public_key_hash` on the signer at `base_url`,
+    /// e.g. `http://localhost:6732/`.
+    pub fn new(base_url: Url, public_key_hash: String) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            base_url,
+            public_key_hash,
+            authentication_key: None,
+            public_key: OnceCell::new(),
+        }
+    }
+
+    /// Same as [`new()`](Self::new()), authenticating requests with
+    /// `authentication_key`, which must be one of the signer's
+    /// [authorized keys](Self::authorized_keys()).
+    pub fn with_authentication(
+        base_url: Url,
+        public_key_hash: String,
+        authentication_key: SecretKey,
+    ) -> Self {
+        Self {
+            authentication_key: Some(authentication_key),
+            ..Self::new(base_url, public_key_hash)
+        }
+    }
+
+    /// Addresses of the keys allowed to authenticate requests, `None` if the
+    /// signer does not require authentication.
+    pub async fn authorized_keys(&self) -> Result<Option<Vec<String>>, RpcError> {
+        let response: AuthorizedKeysResponse = self.request("authorized_keys", None).await?;
+        Ok(response.authorized_keys)
+    }
+
+    /// Fetches the public key, checking that it matches the address.
+    async fn fetch_public_key(&self) -> Result<PublicKey, RpcError> {
+        let response: PublicKeyResponse = self.request(&self.key_path(), None).await?;
+        if response.public_key.public_key_hash() != self.public_key_hash {
+            return Err(RpcError::Other(format!(
+                "remote signer returned the public key {} for {}",
+                response.public_key, self.public_key_hash
+            )));
+        }
+        Ok(response.public_key)
+    }
+
+    fn key_path(&self) -> String {
+        format!("keys/{}", self.public_key_hash)
+    }
+
+    /// Signature of the `authentication` query parameter for `data`.
+    fn authentication(&self, data: &[u8]) -> Result<Option<String>, ParseError> {
+        let authentication_key = match &self.authentication_key {
+            Some(authentication_key) => authentication_key,
+            None => return Ok(None),
+        };
+        let mut message = vec![AUTHENTICATION_TAG];
+        message.extend(forged::encode_public_key_hash(&self.public_key_hash)?);
+        message.extend_from_slice(data);
+
+        let signature = authentication_key.sign(None, &message)?;
+        Ok(Some(signature.to_base58_for(authentication_key.curve())))
+    }
+
+    /// Sends a `GET` request to `path`, or a `POST` request when given a body,
+    /// and parses the JSON response.
+    async fn request<T: for<'de> Deserialize<'de>>(
+        &self,
+        path: &str,
+        post: Option<(String, Option<String>)>,
+    ) -> Result<T, RpcError> {
+        let mut url = self.base_url.join(path)?;
+        let request = match post {
+            Some((body, authentication)) => {
+                if let Some(authentication) = authentication {
+                    url.query_pairs_mut()
+                        .append_pair("authentication", &authentication);
+                }
+                self.client
+                    .post(url)
+                    .header(reqwest::header::CONTENT_TYPE, "application/json")
+                    .body(body)
+            }
+            None => self.client.get(url),
+        };
+
+        let response = request.send().await?;
+        let status = response.status();
+        let response_str = response.text().await?;
+        if !status.is_success() {
+            return Err(RpcError::StatusError(status, response_str));
+        }
+        Ok(serde_json::from_str(&response_str).map_err(ParseError::from)?)
+    }
+}
+
+#[async_trait]
+impl Signer for RemoteSigner {
+    /// Fetches the public key on first use, checking that it matches the
+    /// address.
+    async fn public_key(&self) -> Result<PublicKey, RpcError> {
+        let public_key = self
+            .public_key
+            .get_or_try_init(|| self.fetch_public_key())
+            .await?;
+        Ok(public_key.clone())
+    }
+
+    async fn public_key_hash(&self) -> Result<String, RpcError> {
+        Ok(self.public_key_hash.clone())
+    }
+
+    async fn sign(
+        &self,
+        watermark: Option<&Watermark>,
+        bytes: &[u8],
+    ) -> Result<Signature, RpcError> {
+        let mut data = match watermark {
+            Some(watermark) => watermark.to_bytes()?,
+            None => Vec::new(),
+        };
+        data.extend_from_slice(bytes);
+
+        let body = serde_json::Value::from(hex::encode(&data)).to_string();
+        let authentication = self.authentication(&data)?;
+        let response: SignatureResponse = self
+            .request(&self.key_path(), Some((body, authentication)))
+            .await?;
+        let signature = Signature::from_base58(&response.signature)?;
+        if !self.public_key().await?.verify(None, &data, &signature)? {
+            return Err(RpcError::Other(format!(
+                "remote signer returned an invalid signature for {}",
+                self.public_key_hash
+            )));
+        }
+        Ok(signature)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::crypto::Curve;
+    use crate::test_server;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// Local stand-in of a remote signer, implementing the `octez-signer`
+    /// HTTP endpoints for in-memory keys.
+    struct LocalSigner {
+        keys: HashMap<String, SecretKey>,
+        authorized_keys: Option<Vec<PublicKey>>,
+        /// Key signing in place of the requested one, if any.
+        impostor: Option<SecretKey>,
+        public_key_requests: Arc<AtomicUsize>,
+    }
+
+    impl LocalSigner {
+        fn new(keys: HashMap<String, SecretKey>, authorized_keys: Option<Vec<PublicKey>>) -> Self {
+            Self {
+                keys,
+                authorized_keys,
+                impostor: None,
+                public_key_requests: Arc::new(AtomicUsize::new(0)),
+            }
+        }
+    }
+
+    impl LocalSigner {
+        /// Serves the signer on a random local port, returning its URL.
+        async fn serve(self) -> Url {
+            test_server::serve(move |request| {
+                self.route(&request.method, &request.target, &request.body)
+            })
+            .await
+        }
+
+        fn route(&self, method: &str, target: &Url, body: &str) -> (&'static str, String) {
+            let path = target.path();
+            if path == "/authorized_keys" {
+                let response = match &self.authorized_keys {
+                    Some(keys) => {
+                        let hashes: Vec<String> =
+                            keys.iter().map(PublicKey::public_key_hash).collect();
+                        serde_json::json!({ "authorized_keys": hashes })
+                    }
+                    None => serde_json::json!({}),
+                };
+                return ("200 OK", response.to_string());
+            }
+
+            let key = match path
+                .strip_prefix("/keys/")
+                .and_then(|pkh| self.keys.get(pkh))
+            {
+                Some(key) => key,
+                None => return ("404 Not Found", r#"[{"kind":"temporary"}]"#.to_string()),
+            };
+            if method == "GET" {
+                self.public_key_requests.fetch_add(1, Ordering::SeqCst);
+                let response = serde_json::json!({ "public_key": key.public_key() });
+                return ("200 OK", response.to_string());
+            }
+
+            let data = hex::decode(serde_json::from_str::<String>(body).unwrap()).unwrap();
+            if let Some(authorized_keys) = &self.authorized_keys {
+                let mut message = vec![AUTHENTICATION_TAG];
+                message.extend(forged::encode_public_key_hash(&key.public_key_hash()).unwrap());
+                message.extend_from_slice(&data);
+                let authenticated = target
+                    .query_pairs()
+                    .find(|(name, _)| name == "authentication")
+                    .and_then(|(_, signature)| Signature::from_base58(&signature).ok())
+                    .map(|signature| {
+                        authorized_keys.iter().any(|public_key| {
+                            public_key.verify(None, &message, &signature).unwrap()
+                        })
+                    })
+                    .unwrap_or(false);
+                if !authenticated {
+                    return ("401 Unauthorized", r#"[{"kind":"permanent"}]"#.to_string());
+                }
+            }
+
+            let signature = self
+                .impostor
+                .as_ref()
+                .unwrap_or(key)
+                .sign(None, &data)
+                .unwrap();
+            let response = serde_json::json!({ "signature": signature.to_base58_for(key.curve()) });
+            ("200 OK", response.to_string())
+        }
+    }
+
+    fn signing_key() -> SecretKey {
+        SecretKey::from_bytes(Curve::Ed25519, &[2; 32]).unwrap()
+    }
+
+    #[tokio::test]
+    async fn remote_signer_ok() {
+        let key = signing_key();
+        let pkh = key.public_key_hash();
+        let mut keys = HashMap::new();
+        keys.insert(pkh.clone(), key.clone());
+        let local_signer = LocalSigner::new(keys, None);
+        let public_key_requests = local_signer.public_key_requests.clone();
+        let url = local_signer.serve().await;
+
+        let signer = RemoteSigner::new(url, pkh);
+        let watermark = Watermark::GenericOperation;
+        let signature = signer.sign(Some(&watermark), b"operation").await.unwrap();
+        signer.sign(None, b"other bytes").await.unwrap();
+
+        assert_eq!(signer.public_key().await.unwrap(), key.public_key());
+        assert_eq!(public_key_requests.load(Ordering::SeqCst), 1);
+        assert_eq!(signature, key.sign(Some(&watermark), b"operation").unwrap());
+        assert_eq!(signer.authorized_keys().await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn remote_signer_authentication_ok() {
+        let key = signing_key();
+        let pkh = key.public_key_hash();
+        let authentication_key = SecretKey::from_bytes(Curve::P256, &[3; 32]).unwrap();
+        let mut keys = HashMap::new();
+        keys.insert(pkh.clone(), key.clone());
+        let url = LocalSigner::new(keys, Some(vec![authentication_key.public_key()]))
+            .serve()
+            .await;
+
+        let unauthenticated = RemoteSigner::new(url.clone(), pkh.clone());
+        assert!(matches!(
+            unauthenticated.sign(None, b"bytes").await,
+            Err(RpcError::StatusError(status, _)) if status == reqwest::StatusCode::UNAUTHORIZED
+        ));
+
+        let signer = RemoteSigner::with_authentication(url, pkh, authentication_key.clone());
+        let signature = signer.sign(None, b"bytes").await.unwrap();
+        assert!(key.public_key().verify(None, b"bytes", &signature).unwrap());
+        assert_eq!(
+            signer.authorized_keys().await.unwrap(),
+            Some(vec![authentication_key.public_key_hash()])
+        );
+    }
+
+    #[tokio::test]
+    async fn unknown_remote_key_fails() {
+        let url = LocalSigner::new(HashMap::new(), None).serve().await;
+
+        let signer = RemoteSigner::new(url, signing_key().public_key_hash());
+        assert!(matches!(
+            signer.public_key().await,
+            Err(RpcError::StatusError(status, _)) if status == reqwest::StatusCode::NOT_FOUND
+        ));
+    }
+
+    #[tokio::test]
+    async fn invalid_remote_signature_fails() {
+        let key = signing_key();
+        let pkh = key.public_key_hash();
+        let mut keys = HashMap::new();
+        keys.insert(pkh.clone(), key);
+        let url = LocalSigner {
+            impostor: Some(SecretKey::from_bytes(Curve::Ed25519, &[9; 32]).unwrap()),
+            ..LocalSigner::new(keys, None)
+        }
+        .serve()
+        .await;
+
+        let signer = RemoteSigner::new(url, pkh);
+        assert!(signer.sign(None, b"bytes").await.is_err());
+    }
+}
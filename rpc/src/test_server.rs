@@ -0,0 +1,78 @@
+//! Local HTTP server standing in for a node or a remote signer in tests.
+
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use url::Url;
+
+/// Request received by a route of [`serve()`].
+pub(crate) struct Request {
+    pub method: String,
+    /// Requested URL, resolved against the server's URL.
+    pub target: Url,
+    pub body: String,
+}
+
+/// Serves every request on a random local port with `route`, which returns
+/// the status (e.g. `"404 Not Found"`) and body of the response. Returns the
+/// URL of the server.
+pub(crate) async fn serve<F>(route: F) -> Url
+where
+    F: Fn(Request) -> (&'static str, String) + Send + Sync + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+    let route = Arc::new(route);
+    let base_url = url.clone();
+    tokio::spawn(async move {
+        loop {
+            let (stream, _) = listener.accept().await.unwrap();
+            tokio::spawn(handle(stream, base_url.clone(), route.clone()));
+        }
+    });
+    url
+}
+
+async fn handle<F>(mut stream: TcpStream, base_url: Url, route: Arc<F>)
+where
+    F: Fn(Request) -> (&'static str, String),
+{
+    let mut request = Vec::new();
+    let mut buffer = [0; 1024];
+    let (head, body) = loop {
+        let read = stream.read(&mut buffer).await.unwrap();
+        request.extend_from_slice(&buffer[..read]);
+        let text = String::from_utf8_lossy(&request).to_string();
+        if let Some((head, body)) = text.split_once("\r\n\r\n") {
+            let content_length = head
+                .lines()
+                .filter_map(|line| line.split_once(':'))
+                .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+                .map(|(_, value)| value.trim().parse().unwrap())
+                .unwrap_or(0);
+            if body.len() >= content_length {
+                break (head.to_string(), body.to_string());
+            }
+        }
+        if read == 0 {
+            return;
+        }
+    };
+
+    let mut request_line = head.lines().next().unwrap().split(' ');
+    let method = request_line.next().unwrap().to_string();
+    let target = base_url.join(request_line.next().unwrap()).unwrap();
+    let (status, response) = route(Request {
+        method,
+        target,
+        body,
+    });
+
+    let http_response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        response.len(),
+        response
+    );
+    stream.write_all(http_response.as_bytes()).await.unwrap();
+}
@@ -0,0 +1,270 @@
+use crate::errors::ParseError;
+use crate::michelson::{BigMapValue, Micheline, MichelsonValue};
+use crate::responses::chains::blocks::ContractScriptResponse;
+use num_bigint::BigInt;
+
+/// Storage field names under which token contracts commonly keep their
+/// balances.
+pub const LEDGER_FIELD_NAMES: [&str; 4] = ["ledger", "balances", "tokens", "accounts"];
+
+/// A big map found in a contract's storage, along with its key and value
+/// types.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BigMapLocation {
+    pub big_map_id: BigInt,
+    pub key_type: Micheline,
+    pub value_type: Micheline,
+}
+
+impl BigMapLocation {
+    /// Finds the big map stored under the `%name` field of the contract's
+    /// storage, looking through nested records.
+    pub fn find(script: &ContractScriptResponse, name: &str) -> Result<Option<Self>, ParseError> {
+        let storage_type = match script.storage_type() {
+            Some(storage_type) => storage_type,
+            None => return Ok(None),
+        };
+        let big_map_type = match find_annotated_type(storage_type, name) {
+            Some(big_map_type) if big_map_type.is_prim("big_map") => big_map_type,
+            _ => return Ok(None),
+        };
+
+        let storage = script.decode_storage()?;
+        let big_map_id = match find_field(&storage, name) {
+            Some(MichelsonValue::BigMap(BigMapValue::Id(big_map_id))) => big_map_id.clone(),
+            _ => return Ok(None),
+        };
+
+        let args = &big_map_type
+            .as_prim()
+            .map(|prim| prim.args.clone())
+            .unwrap_or_default();
+        match args.as_slice() {
+            [key_type, value_type] => Ok(Some(Self {
+                big_map_id,
+                key_type: key_type.clone(),
+                value_type: value_type.clone(),
+            })),
+            _ => Ok(None),
+        }
+    }
+}
+
+/// A token ledger big map, mapping owners (and token ids for FA2) to
+/// balances.
+///
+/// The supported layouts are `address -> balance`, `(address, token_id) ->
+/// balance`, `(token_id, address) -> balance` and the NFT layout
+/// `token_id -> owner`. The balance may be a bare `nat`, a record with a
+/// `%balance` field, or a tuple whose first `nat` is the balance.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ledger {
+    pub location: BigMapLocation,
+}
+
+impl Ledger {
+    pub fn find(script: &ContractScriptResponse) -> Result<Option<Self>, ParseError> {
+        for name in LEDGER_FIELD_NAMES.iter() {
+            if let Some(location) = BigMapLocation::find(script, name)? {
+                return Ok(Some(Self { location }));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Builds the big map key holding the balance of `owner` for `token_id`.
+    pub fn key_for(&self, owner: &str, token_id: &BigInt) -> Result<Micheline, ParseError> {
+        let key_type = &self.location.key_type;
+        let owner = Micheline::string(owner);
+        let token_id = Micheline::int(token_id.clone());
+
+        if is_owner_type(key_type) {
+            return Ok(owner);
+        }
+        if key_type.is_prim("nat") {
+            return Ok(token_id);
+        }
+
+        let key_args = key_type.as_prim().map(|prim| prim.args.as_slice());
+        match key_args {
+            Some([left, right]) if is_owner_type(left) && right.is_prim("nat") => {
+                Ok(Micheline::prim("Pair", vec![owner, token_id], vec![]))
+            }
+            Some([left, right]) if left.is_prim("nat") && is_owner_type(right) => {
+                Ok(Micheline::prim("Pair", vec![token_id, owner], vec![]))
+            }
+            _ => Err(ParseError::RequestParsingError(format!(
+                "unsupported ledger key type {}",
+                key_type
+            ))),
+        }
+    }
+
+    /// Reads the balance of `owner` out of the value bound to its ledger key,
+    /// `None` meaning that no value was bound.
+    pub fn balance_from_value(
+        &self,
+        owner: &str,
+        value: Option<&Micheline>,
+    ) -> Result<BigInt, ParseError> {
+        let value = match value {
+            Some(value) => MichelsonValue::decode(&self.location.value_type, value)?,
+            None => return Ok(BigInt::from(0)),
+        };
+
+        if self.location.key_type.is_prim("nat") {
+            let is_owner = matches!(&value, MichelsonValue::Address(address) if address == owner);
+            return Ok(BigInt::from(is_owner as u8));
+        }
+
+        let balance = match &value {
+            MichelsonValue::Nat(balance) => Some(balance),
+            MichelsonValue::Record(_) => match value.field("balance") {
+                Some(MichelsonValue::Nat(balance)) => Some(balance),
+                _ => None,
+            },
+            MichelsonValue::Tuple(values) => values.iter().find_map(|value| match value {
+                MichelsonValue::Nat(balance) => Some(balance),
+                _ => None,
+            }),
+            _ => None,
+        };
+
+        balance.cloned().ok_or_else(|| {
+            let detail = format!("cannot find a balance in ledger value {}", value);
+            ParseError::ResponseParsingError(detail)
+        })
+    }
+}
+
+fn is_owner_type(ty: &Micheline) -> bool {
+    ty.is_prim("address") || ty.is_prim("key_hash")
+}
+
+fn find_annotated_type<'a>(ty: &'a Micheline, name: &str) -> Option<&'a Micheline> {
+    let prim = ty.as_prim()?;
+    if prim.field_annot() == Some(name) {
+        return Some(ty);
+    }
+    if prim.prim != "pair" {
+        return None;
+    }
+    prim.args
+        .iter()
+        .find_map(|arg| find_annotated_type(arg, name))
+}
+
+fn find_field<'a>(value: &'a MichelsonValue, name: &str) -> Option<&'a MichelsonValue> {
+    match value {
+        MichelsonValue::Record(fields) => fields.iter().find_map(|(field_name, field)| {
+            if field_name == name {
+                Some(field)
+            } else {
+                find_field(field, name)
+            }
+        }),
+        MichelsonValue::Tuple(values) => values.iter().find_map(|value| find_field(value, name)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::responses::Response;
+
+    fn script_with_storage(storage_type: &str, storage: &str) -> ContractScriptResponse {
+        let script = format!(
+            r#"{{"code":[{{"prim":"parameter","args":[{{"prim":"unit"}}]}},{{"prim":"storage","args":[{}]}}],"storage":{}}}"#,
+            storage_type, storage
+        );
+        ContractScriptResponse::from_response_str(&script).unwrap()
+    }
+
+    #[test]
+    fn find_nested_multi_asset_ledger_ok() {
+        let script = script_with_storage(
+            r#"{"prim":"pair","args":[
+                {"prim":"pair","args":[
+                    {"prim":"big_map","args":[{"prim":"pair","args":[{"prim":"address"},{"prim":"nat"}]},{"prim":"nat"}],"annots":["%ledger"]},
+                    {"prim":"big_map","args":[{"prim":"nat"},{"prim":"bytes"}],"annots":["%token_metadata"]}
+                ],"annots":["%assets"]},
+                {"prim":"address","annots":["%admin"]}
+            ]}"#,
+            r#"{"prim":"Pair","args":[{"prim":"Pair","args":[{"int":"31"},{"int":"32"}]},{"string":"tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx"}]}"#,
+        );
+
+        let ledger = Ledger::find(&script).unwrap().unwrap();
+        assert_eq!(ledger.location.big_map_id, BigInt::from(31));
+
+        let key = ledger
+            .key_for("tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx", &BigInt::from(3))
+            .unwrap();
+        assert_eq!(
+            key.to_string(),
+            r#"{"prim":"Pair","args":[{"string":"tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx"},{"int":"3"}]}"#
+        );
+
+        let balance = ledger
+            .balance_from_value(
+                "tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx",
+                Some(&Micheline::int(50)),
+            )
+            .unwrap();
+        assert_eq!(balance, BigInt::from(50));
+    }
+
+    #[test]
+    fn read_fa12_record_balance_ok() {
+        let script = script_with_storage(
+            r#"{"prim":"pair","args":[
+                {"prim":"big_map","args":[{"prim":"address"},{"prim":"pair","args":[{"prim":"map","args":[{"prim":"address"},{"prim":"nat"}],"annots":["%approvals"]},{"prim":"nat","annots":["%balance"]}]}],"annots":["%balances"]},
+                {"prim":"nat","annots":["%total_supply"]}
+            ]}"#,
+            r#"{"prim":"Pair","args":[{"int":"7"},{"int":"1000"}]}"#,
+        );
+
+        let ledger = Ledger::find(&script).unwrap().unwrap();
+        let value: Micheline =
+            serde_json::from_str(r#"{"prim":"Pair","args":[[],{"int":"250"}]}"#).unwrap();
+
+        let balance = ledger
+            .balance_from_value("tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx", Some(&value))
+            .unwrap();
+        assert_eq!(balance, BigInt::from(250));
+        assert_eq!(
+            ledger
+                .balance_from_value("tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx", None)
+                .unwrap(),
+            BigInt::from(0)
+        );
+    }
+
+    #[test]
+    fn read_nft_ledger_ownership_ok() {
+        let ledger = Ledger {
+            location: BigMapLocation {
+                big_map_id: BigInt::from(5),
+                key_type: Micheline::prim("nat", vec![], vec![]),
+                value_type: Micheline::prim("address", vec![], vec![]),
+            },
+        };
+
+        let owner = "tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx";
+        let value = Micheline::string(owner);
+        assert_eq!(
+            ledger.key_for(owner, &BigInt::from(9)).unwrap(),
+            Micheline::int(9)
+        );
+        assert_eq!(
+            ledger.balance_from_value(owner, Some(&value)).unwrap(),
+            BigInt::from(1)
+        );
+        assert_eq!(
+            ledger
+                .balance_from_value("tz1VkjQjvH3NTVdFtGz9tWutgxLKwnhnMe2x", Some(&value))
+                .unwrap(),
+            BigInt::from(0)
+        );
+    }
+}
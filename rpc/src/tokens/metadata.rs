@@ -0,0 +1,110 @@
+use crate::errors::ParseError;
+use crate::michelson::MichelsonValue;
+use num_bigint::BigInt;
+use std::collections::HashMap;
+
+/// Key of `token_info` holding a URI to off-chain metadata, as described by
+/// TZIP-12.
+pub const OFF_CHAIN_URI_KEY: &str = "";
+
+/// TZIP-12 token metadata, as stored in the `token_metadata` big map:
+/// a token id and a map from metadata keys to raw bytes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenMetadata {
+    pub token_id: BigInt,
+    pub token_info: HashMap<String, Vec<u8>>,
+}
+
+impl TokenMetadata {
+    /// Reads the metadata from a decoded `pair (nat %token_id) (map %token_info string bytes)`.
+    pub fn from_michelson(value: &MichelsonValue) -> Result<Self, ParseError> {
+        let (token_id, token_info) = match value {
+            MichelsonValue::Record(_) => (value.field("token_id"), value.field("token_info")),
+            MichelsonValue::Tuple(values) if values.len() == 2 => (values.first(), values.get(1)),
+            _ => (None, None),
+        };
+
+        match (token_id, token_info) {
+            (Some(MichelsonValue::Nat(token_id)), Some(MichelsonValue::Map(entries))) => {
+                let mut token_info = HashMap::new();
+                for (key, value) in entries {
+                    if let (MichelsonValue::String(key), MichelsonValue::Bytes(value)) =
+                        (key, value)
+                    {
+                        token_info.insert(key.clone(), value.clone());
+                    }
+                }
+                Ok(Self {
+                    token_id: token_id.clone(),
+                    token_info,
+                })
+            }
+            _ => {
+                let detail = format!("{} is not a TZIP-12 token metadata value", value);
+                Err(ParseError::ResponseParsingError(detail))
+            }
+        }
+    }
+
+    /// Returns the `token_info` entry `key` decoded as UTF-8.
+    pub fn get_str(&self, key: &str) -> Option<String> {
+        let value = self.token_info.get(key)?;
+        String::from_utf8(value.clone()).ok()
+    }
+
+    pub fn name(&self) -> Option<String> {
+        self.get_str("name")
+    }
+
+    pub fn symbol(&self) -> Option<String> {
+        self.get_str("symbol")
+    }
+
+    pub fn decimals(&self) -> Option<u32> {
+        self.get_str("decimals")?.parse().ok()
+    }
+
+    /// Returns the URI of the off-chain metadata, when the token's metadata
+    /// is not (or not only) stored on chain.
+    pub fn off_chain_uri(&self) -> Option<String> {
+        self.get_str(OFF_CHAIN_URI_KEY)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::michelson::Micheline;
+
+    #[test]
+    fn read_token_metadata_ok() {
+        let ty: Micheline = serde_json::from_str(
+            r#"{"prim":"pair","args":[{"prim":"nat","annots":["%token_id"]},{"prim":"map","args":[{"prim":"string"},{"prim":"bytes"}],"annots":["%token_info"]}]}"#,
+        )
+        .unwrap();
+        let value: Micheline = serde_json::from_str(&format!(
+            r#"{{"prim":"Pair","args":[{{"int":"0"}},[
+                {{"prim":"Elt","args":[{{"string":"decimals"}},{{"bytes":"{}"}}]}},
+                {{"prim":"Elt","args":[{{"string":"symbol"}},{{"bytes":"{}"}}]}}
+            ]]}}"#,
+            hex::encode("6"),
+            hex::encode("tzBTC")
+        ))
+        .unwrap();
+
+        let decoded = MichelsonValue::decode(&ty, &value).unwrap();
+        let metadata = TokenMetadata::from_michelson(&decoded).unwrap();
+
+        assert_eq!(metadata.token_id, BigInt::from(0));
+        assert_eq!(metadata.symbol().unwrap(), "tzBTC");
+        assert_eq!(metadata.decimals(), Some(6));
+        assert!(metadata.name().is_none());
+        assert!(metadata.off_chain_uri().is_none());
+    }
+
+    #[test]
+    fn read_token_metadata_from_other_value_fails() {
+        let value = MichelsonValue::Nat(BigInt::from(0));
+        assert!(TokenMetadata::from_michelson(&value).is_err());
+    }
+}
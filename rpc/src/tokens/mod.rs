@@ -0,0 +1,267 @@
+//! Helpers for FA1.2 (TZIP-7) and FA2 (TZIP-12) token contracts: standard
+//! detection, balances and token metadata.
+
+mod ledger;
+mod metadata;
+pub use ledger::{BigMapLocation, Ledger, LEDGER_FIELD_NAMES};
+pub use metadata::{TokenMetadata, OFF_CHAIN_URI_KEY};
+
+use crate::commands::chains::blocks::{
+    get_big_map_value::GetBigMapValue, get_contract_entrypoints::GetContractEntrypoints,
+    get_contract_script::GetContractScript, run_view::RunView,
+};
+use crate::errors::{ParseError, RpcError};
+use crate::michelson::{script_expr_hash, Micheline, MichelsonValue};
+use crate::responses::chains::blocks::{ContractEntrypointsResponse, ContractScriptResponse};
+use crate::types::{Block, Chain};
+use crate::RpcClient;
+use num_bigint::BigInt;
+
+const FA12_ENTRYPOINTS: [&str; 5] = [
+    "transfer",
+    "approve",
+    "getAllowance",
+    "getBalance",
+    "getTotalSupply",
+];
+const FA2_ENTRYPOINTS: [&str; 3] = ["transfer", "balance_of", "update_operators"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenStandard {
+    Fa12,
+    Fa2,
+}
+
+impl TokenStandard {
+    /// Detects the token standard implemented by a contract from the
+    /// entrypoints it exposes.
+    pub fn detect(entrypoints: &ContractEntrypointsResponse) -> Option<Self> {
+        let implements = |required: &[&str]| {
+            required
+                .iter()
+                .all(|name| entrypoints.entrypoints.contains_key(*name))
+        };
+
+        if implements(&FA2_ENTRYPOINTS) {
+            Some(Self::Fa2)
+        } else if implements(&FA12_ENTRYPOINTS) {
+            Some(Self::Fa12)
+        } else {
+            None
+        }
+    }
+}
+
+/// A token contract loaded at a given block, used to read balances and
+/// token metadata.
+///
+/// FA1.2 contracts hold a single token, so the `token_id` arguments are
+/// ignored for them.
+pub struct TokenContract {
+    pub address: String,
+    pub standard: TokenStandard,
+    pub script: ContractScriptResponse,
+    chain_id: Chain,
+    block_id: Block,
+}
+
+impl TokenContract {
+    /// Fetches the contract's entrypoints and script, failing if it is
+    /// neither an FA1.2 nor an FA2 contract.
+    pub async fn load(
+        client: &RpcClient,
+        chain_id: Chain,
+        block_id: Block,
+        address: String,
+    ) -> Result<Self, RpcError> {
+        let entrypoints_command = GetContractEntrypoints {
+            chain_id: chain_id.clone(),
+            block_id: block_id.clone(),
+            contract_id: address.clone(),
+        };
+        let entrypoints = client.execute(&entrypoints_command).await?;
+        let standard = TokenStandard::detect(&entrypoints).ok_or_else(|| {
+            RpcError::Other(format!(
+                "{} is neither an FA1.2 nor an FA2 contract",
+                address
+            ))
+        })?;
+
+        let script_command = GetContractScript {
+            chain_id: chain_id.clone(),
+            block_id: block_id.clone(),
+            contract_id: address.clone(),
+        };
+        let script = client.execute(&script_command).await?;
+
+        Ok(Self {
+            address,
+            standard,
+            script,
+            chain_id,
+            block_id,
+        })
+    }
+
+    /// Reads the balance of `owner` from the contract's ledger big map.
+    pub async fn get_balance(
+        &self,
+        client: &RpcClient,
+        owner: &str,
+        token_id: &BigInt,
+    ) -> Result<BigInt, RpcError> {
+        let ledger = Ledger::find(&self.script)?.ok_or_else(|| {
+            RpcError::Other(format!(
+                "cannot find a ledger in {}'s storage",
+                self.address
+            ))
+        })?;
+
+        let key = ledger.key_for(owner, token_id)?;
+        let value = self
+            .get_big_map_value(client, &ledger.location, &key)
+            .await?;
+        Ok(ledger.balance_from_value(owner, value.as_ref())?)
+    }
+
+    /// Reads the balance of `owner` by simulating a call to the contract's
+    /// `getBalance` (FA1.2) or `balance_of` (FA2) view entrypoint.
+    ///
+    /// `network_chain_id` is the base58 chain id (`Net...`) of the network.
+    pub async fn get_balance_from_view(
+        &self,
+        client: &RpcClient,
+        owner: &str,
+        token_id: &BigInt,
+        network_chain_id: &str,
+    ) -> Result<BigInt, RpcError> {
+        let (entrypoint, input) = match self.standard {
+            TokenStandard::Fa12 => ("getBalance", Micheline::string(owner)),
+            TokenStandard::Fa2 => {
+                let request = Micheline::prim(
+                    "Pair",
+                    vec![Micheline::string(owner), Micheline::int(token_id.clone())],
+                    vec![],
+                );
+                ("balance_of", Micheline::Seq(vec![request]))
+            }
+        };
+
+        let command = RunView::with_default_params(
+            self.chain_id.clone(),
+            self.block_id.clone(),
+            self.address.clone(),
+            entrypoint.to_string(),
+            input,
+            network_chain_id.to_string(),
+        );
+        let response = client.execute(&command).await?;
+        Ok(balance_from_view_result(&response.data)?)
+    }
+
+    /// Reads the TZIP-12 metadata of `token_id` from the contract's
+    /// `token_metadata` big map, `None` meaning the token has no entry.
+    pub async fn get_token_metadata(
+        &self,
+        client: &RpcClient,
+        token_id: &BigInt,
+    ) -> Result<Option<TokenMetadata>, RpcError> {
+        let location = BigMapLocation::find(&self.script, "token_metadata")?.ok_or_else(|| {
+            RpcError::Other(format!("{} has no token_metadata big map", self.address))
+        })?;
+
+        let key = Micheline::int(token_id.clone());
+        match self.get_big_map_value(client, &location, &key).await? {
+            Some(value) => {
+                let decoded = MichelsonValue::decode(&location.value_type, &value)?;
+                Ok(Some(TokenMetadata::from_michelson(&decoded)?))
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn get_big_map_value(
+        &self,
+        client: &RpcClient,
+        location: &BigMapLocation,
+        key: &Micheline,
+    ) -> Result<Option<Micheline>, RpcError> {
+        let command = GetBigMapValue {
+            chain_id: self.chain_id.clone(),
+            block_id: self.block_id.clone(),
+            big_map_id: location.big_map_id.to_string(),
+            script_expr: script_expr_hash(&location.key_type, key)?,
+        };
+        Ok(client.execute(&command).await?.value)
+    }
+}
+
+/// Extracts the balance from the result of `getBalance` (a `nat`) or of
+/// `balance_of` (a list of `Pair (Pair owner token_id) balance`).
+fn balance_from_view_result(data: &Micheline) -> Result<BigInt, ParseError> {
+    let balance = match data {
+        Micheline::Int { .. } => data.as_int(),
+        Micheline::Seq(responses) => responses
+            .first()
+            .and_then(Micheline::as_prim)
+            .filter(|response| response.prim == "Pair")
+            .and_then(|response| response.args.last())
+            .and_then(Micheline::as_int),
+        _ => None,
+    };
+
+    balance.ok_or_else(|| {
+        let detail = format!("unexpected balance view result {}", data);
+        ParseError::ResponseParsingError(detail)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::responses::Response;
+
+    #[test]
+    fn detect_fa2_ok() {
+        let mock_response = r#"{"entrypoints":{"transfer":{"prim":"unit"},"balance_of":{"prim":"unit"},"update_operators":{"prim":"unit"}}}"#;
+        let entrypoints = ContractEntrypointsResponse::from_response_str(mock_response).unwrap();
+
+        assert_eq!(
+            TokenStandard::detect(&entrypoints),
+            Some(TokenStandard::Fa2)
+        );
+    }
+
+    #[test]
+    fn detect_fa12_ok() {
+        let mock_response = r#"{"entrypoints":{"transfer":{"prim":"unit"},"approve":{"prim":"unit"},"getAllowance":{"prim":"unit"},"getBalance":{"prim":"unit"},"getTotalSupply":{"prim":"unit"}}}"#;
+        let entrypoints = ContractEntrypointsResponse::from_response_str(mock_response).unwrap();
+
+        assert_eq!(
+            TokenStandard::detect(&entrypoints),
+            Some(TokenStandard::Fa12)
+        );
+    }
+
+    #[test]
+    fn detect_other_contract_fails() {
+        let mock_response = r#"{"entrypoints":{"transfer":{"prim":"unit"}}}"#;
+        let entrypoints = ContractEntrypointsResponse::from_response_str(mock_response).unwrap();
+
+        assert_eq!(TokenStandard::detect(&entrypoints), None);
+    }
+
+    #[test]
+    fn balance_from_balance_of_result_ok() {
+        let data: Micheline = serde_json::from_str(
+            r#"[{"prim":"Pair","args":[{"prim":"Pair","args":[{"string":"tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx"},{"int":"0"}]},{"int":"42"}]}]"#,
+        )
+        .unwrap();
+
+        assert_eq!(balance_from_view_result(&data).unwrap(), BigInt::from(42));
+        assert_eq!(
+            balance_from_view_result(&Micheline::int(7)).unwrap(),
+            BigInt::from(7)
+        );
+    }
+}
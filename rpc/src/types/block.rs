@@ -1,3 +1,4 @@
+#[derive(Debug, Clone)]
 pub enum Block {
     Head,
     Genesis,
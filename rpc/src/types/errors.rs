@@ -5,20 +5,20 @@ use std::fmt;
 
 #[derive(Serialize, Deserialize)]
 pub struct ResponseError {
-  title: String,
-  description: String,
-  r#type: String,
-  properties: HashMap<String, Value>,
-  required: Vec<String>,
-  #[serde(rename(
-    serialize = "additionalProperties",
-    deserialize = "additionalProperties"
-  ))]
-  additional_properties: bool,
+    title: String,
+    description: String,
+    r#type: String,
+    properties: HashMap<String, Value>,
+    required: Vec<String>,
+    #[serde(rename(
+        serialize = "additionalProperties",
+        deserialize = "additionalProperties"
+    ))]
+    additional_properties: bool,
 }
 
 impl fmt::Display for ResponseError {
-  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    write!(f, "{}", json!(self).to_string())
-  }
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
 }
@@ -0,0 +1,129 @@
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::json;
+use std::fmt;
+
+/// How much of the chain's history a node keeps.
+///
+/// `additional_cycles` is the number of cycles kept on top of those the
+/// protocol requires; older nodes do not report it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryMode {
+    /// All blocks and all contexts.
+    Archive,
+    /// All blocks, but contexts only for recent cycles.
+    Full { additional_cycles: Option<u32> },
+    /// Recent blocks and contexts only.
+    Rolling { additional_cycles: Option<u32> },
+}
+
+impl HistoryMode {
+    pub fn to_str(&self) -> &str {
+        match self {
+            Self::Archive => "archive",
+            Self::Full { .. } => "full",
+            Self::Rolling { .. } => "rolling",
+        }
+    }
+
+    /// Whether the node can serve the context (balances, storage...) of
+    /// any block.
+    pub fn is_archive(&self) -> bool {
+        matches!(self, Self::Archive)
+    }
+
+    /// Whether the node stores every block since genesis.
+    pub fn keeps_all_blocks(&self) -> bool {
+        !matches!(self, Self::Rolling { .. })
+    }
+}
+
+impl fmt::Display for HistoryMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_str())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Offset {
+    additional_cycles: u32,
+}
+
+/// Node representations: `"archive"`, `"full"` or `"rolling"` on older
+/// nodes, `{"full": {"additional_cycles": 1}}` on recent ones.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawHistoryMode {
+    Name(String),
+    Full { full: Offset },
+    Rolling { rolling: Offset },
+}
+
+impl<'de> Deserialize<'de> for HistoryMode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match RawHistoryMode::deserialize(deserializer)? {
+            RawHistoryMode::Name(name) => match name.as_str() {
+                "archive" => Ok(Self::Archive),
+                "full" => Ok(Self::Full {
+                    additional_cycles: None,
+                }),
+                "rolling" => Ok(Self::Rolling {
+                    additional_cycles: None,
+                }),
+                other => Err(de::Error::custom(format!("unknown history mode {}", other))),
+            },
+            RawHistoryMode::Full { full } => Ok(Self::Full {
+                additional_cycles: Some(full.additional_cycles),
+            }),
+            RawHistoryMode::Rolling { rolling } => Ok(Self::Rolling {
+                additional_cycles: Some(rolling.additional_cycles),
+            }),
+        }
+    }
+}
+
+impl Serialize for HistoryMode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Full {
+                additional_cycles: Some(additional_cycles),
+            }
+            | Self::Rolling {
+                additional_cycles: Some(additional_cycles),
+            } => json!({ self.to_str(): { "additional_cycles": additional_cycles } })
+                .serialize(serializer),
+            _ => serializer.serialize_str(self.to_str()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn history_mode_representations_ok() {
+        let archive: HistoryMode = serde_json::from_str(r#""archive""#).unwrap();
+        let rolling: HistoryMode =
+            serde_json::from_str(r#"{"rolling":{"additional_cycles":5}}"#).unwrap();
+        let legacy_full: HistoryMode = serde_json::from_str(r#""full""#).unwrap();
+
+        assert!(archive.is_archive());
+        assert_eq!(
+            rolling,
+            HistoryMode::Rolling {
+                additional_cycles: Some(5)
+            }
+        );
+        assert!(!rolling.keeps_all_blocks());
+        assert!(legacy_full.keeps_all_blocks());
+        assert_eq!(
+            serde_json::to_string(&rolling).unwrap(),
+            r#"{"rolling":{"additional_cycles":5}}"#
+        );
+    }
+
+    #[test]
+    fn unknown_history_mode_fails() {
+        assert!(serde_json::from_str::<HistoryMode>(r#""experimental""#).is_err());
+    }
+}
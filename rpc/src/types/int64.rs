@@ -0,0 +1,54 @@
+//! Serde helpers for `int64` values, which the node serializes either as
+//! JSON numbers or, depending on the protocol, as decimal strings.
+
+use serde::{de, Deserialize, Deserializer};
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NumberOrString {
+    Number(i64),
+    String(String),
+}
+
+impl NumberOrString {
+    fn into_i64<E: de::Error>(self) -> Result<i64, E> {
+        match self {
+            Self::Number(number) => Ok(number),
+            Self::String(string) => string.trim().parse().map_err(de::Error::custom),
+        }
+    }
+}
+
+pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i64, D::Error> {
+    NumberOrString::deserialize(deserializer)?.into_i64()
+}
+
+pub(crate) fn deserialize_option<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<i64>, D::Error> {
+    Option::<NumberOrString>::deserialize(deserializer)?
+        .map(NumberOrString::into_i64)
+        .transpose()
+}
+
+#[cfg(test)]
+mod test {
+    #[derive(serde::Deserialize)]
+    struct Wrapper {
+        #[serde(deserialize_with = "super::deserialize")]
+        value: i64,
+    }
+
+    #[test]
+    fn deserialize_number_or_string_ok() {
+        let number: Wrapper = serde_json::from_str(r#"{"value":42}"#).unwrap();
+        let string: Wrapper = serde_json::from_str(r#"{"value":"-42"}"#).unwrap();
+        assert_eq!(number.value, 42);
+        assert_eq!(string.value, -42);
+    }
+
+    #[test]
+    fn deserialize_invalid_string_fails() {
+        assert!(serde_json::from_str::<Wrapper>(r#"{"value":"4.2"}"#).is_err());
+    }
+}
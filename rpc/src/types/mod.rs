@@ -1,8 +1,19 @@
 mod block;
 mod chain;
 mod errors;
+mod history_mode;
+pub(crate) mod int64;
+mod mutez;
+mod protocol;
 mod unistring;
+mod unparsing_mode;
+mod voting_period_kind;
 pub use block::Block;
 pub use chain::Chain;
 pub use errors::ResponseError;
+pub use history_mode::HistoryMode;
+pub use mutez::Mutez;
+pub use protocol::Protocol;
 pub use unistring::Unistring;
+pub use unparsing_mode::UnparsingMode;
+pub use voting_period_kind::{Ballot, VotingPeriodKind};
@@ -0,0 +1,71 @@
+use crate::errors::ParseError;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// An amount of tez expressed in mutez (10^-6 tez).
+///
+/// The node serializes amounts as decimal strings, which is also the
+/// representation used by this type's `Serialize`/`Deserialize` impls.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Mutez(u64);
+
+impl Mutez {
+    pub const fn new(mutez: u64) -> Self {
+        Self(mutez)
+    }
+
+    pub fn as_u64(&self) -> u64 {
+        self.0
+    }
+}
+
+impl From<u64> for Mutez {
+    fn from(mutez: u64) -> Self {
+        Self(mutez)
+    }
+}
+
+impl FromStr for Mutez {
+    type Err = ParseError;
+
+    fn from_str(mutez_str: &str) -> Result<Self, Self::Err> {
+        Ok(Self(mutez_str.trim().parse()?))
+    }
+}
+
+impl fmt::Display for Mutez {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Serialize for Mutez {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Mutez {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mutez_str = String::deserialize(deserializer)?;
+        mutez_str.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn mutez_string_round_trip_ok() {
+        let mutez: Mutez = serde_json::from_str(r#""1000000""#).unwrap();
+        assert_eq!(mutez.as_u64(), 1_000_000);
+        assert_eq!(serde_json::to_string(&mutez).unwrap(), r#""1000000""#);
+    }
+
+    #[test]
+    fn negative_mutez_fails() {
+        assert!(serde_json::from_str::<Mutez>(r#""-1""#).is_err());
+    }
+}
@@ -0,0 +1,278 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// Economic protocol a block was produced with, identified by its hash.
+///
+/// Variants are declared in activation order, so protocols compare by age:
+/// `Protocol::Nairobi < Protocol::Paris`. Hashes this crate does not know
+/// are kept as [`Unknown`](Self::Unknown), which compares as newer than all
+/// known protocols but could be any protocol: code depending on the age of a
+/// protocol must handle it explicitly.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Protocol {
+    Genesis,
+    /// Protocols 001 to 003, run by mainnet before its first amendment.
+    Proto001,
+    Proto002,
+    Proto003,
+    Athens,
+    /// First Babylon protocol, replaced by [`Babylon`](Self::Babylon) before
+    /// activation on mainnet but run by some test networks.
+    BabylonInitial,
+    Babylon,
+    Carthage,
+    Delphi,
+    Edo,
+    Florence,
+    Granada,
+    Hangzhou,
+    Ithaca,
+    Jakarta,
+    Kathmandu,
+    Lima,
+    Mumbai,
+    Nairobi,
+    Oxford,
+    Paris,
+    ParisC,
+    Quebec,
+    Rio,
+    /// Development protocol run by sandboxes and test networks.
+    Alpha,
+    Unknown(String),
+}
+
+const KNOWN_PROTOCOLS: [(Protocol, &str, &str); 25] = [
+    (
+        Protocol::Genesis,
+        "PrihK96nBAFSxVL1GLJTVhu9YnzkMFiBeuJRPA8NwuZVZCE1L6i",
+        "genesis",
+    ),
+    (
+        Protocol::Proto001,
+        "PtCJ7pwoxe8JasnHY8YonnLYjcVHmhiARPJvqcC6VfHT5s8k8sY",
+        "proto_001",
+    ),
+    (
+        Protocol::Proto002,
+        "PsYLVpVvgbLhAhoqAkMFUo6gudkJ9weNXhUYCiLDzcUpFpkk8Wt",
+        "proto_002",
+    ),
+    (
+        Protocol::Proto003,
+        "PsddFKi32cMJ2qPjf43Qv5GDWLDPZb3T3bF6fLKiF5HtvHNU7aP",
+        "proto_003",
+    ),
+    (
+        Protocol::Athens,
+        "Pt24m4xiPbLDhVgVfABUjirbmda3yohdN82Sp9FeuAXJ4eV9otd",
+        "athens",
+    ),
+    (
+        Protocol::BabylonInitial,
+        "PsBABY5HQTSkA4297zNHfsZNKtxULfL18y95qb3m53QJiXGmrbU",
+        "babylon_initial",
+    ),
+    (
+        Protocol::Babylon,
+        "PsBabyM1eUXZseaJdmXFApDSBqj8YBfwELoxZHHW77EMcAbbwAS",
+        "babylon",
+    ),
+    (
+        Protocol::Carthage,
+        "PsCARTHAGazKbHtnKfLzQg3kms52kSRpgnDY982a9oYsSXRLQEb",
+        "carthage",
+    ),
+    (
+        Protocol::Delphi,
+        "PsDELPH1Kxsxt8f9eWbxQeRxkjfbxoqM52jvs5Y5fBxWWh4ifpo",
+        "delphi",
+    ),
+    (
+        Protocol::Edo,
+        "PtEdo2ZkT9oKpimTah6x2embF25oss54njMuPzkJTEi5RqfdZFA",
+        "edo",
+    ),
+    (
+        Protocol::Florence,
+        "PsFLorenaUUuikDWvMDr6fGBRG8kt3e3D3fHoXK1j1BFRxeSH4i",
+        "florence",
+    ),
+    (
+        Protocol::Granada,
+        "PtGRANADsDU8R9daYKAgWnQYAJ64omN1o3KMGVCykShA97vQbvV",
+        "granada",
+    ),
+    (
+        Protocol::Hangzhou,
+        "PtHangz2aRngywmSRGGvrcTyMbbdpWdpFKuS4uMWxg2RaH9i1qx",
+        "hangzhou",
+    ),
+    (
+        Protocol::Ithaca,
+        "Psithaca2MLRFYargivpo7YvUr7wUDqyxrdhC5CQq78mRvimz6A",
+        "ithaca",
+    ),
+    (
+        Protocol::Jakarta,
+        "PtJakart2xVj7pYXJBXrqHgd82rdkLey5ZeeGwDgPp9rhQUbSqY",
+        "jakarta",
+    ),
+    (
+        Protocol::Kathmandu,
+        "PtKathmankSpLLDALzWw7CGD2j2MtyveTwboEYokqUCP4a1LxMg",
+        "kathmandu",
+    ),
+    (
+        Protocol::Lima,
+        "PtLimaPtLMwfNinJi9rCfDPWea8dFgTZ1MeJ9f1m2SRic6ayiwW",
+        "lima",
+    ),
+    (
+        Protocol::Mumbai,
+        "PtMumbai2TmsJHNGRkD8v8YDbtao7BLUC3wjASn1inAKLFCjaH1",
+        "mumbai",
+    ),
+    (
+        Protocol::Nairobi,
+        "PtNairobiyssHuh87hEhfVBGCVrK3WnS8Z2FT4ymB5tAa4r1nQf",
+        "nairobi",
+    ),
+    (
+        Protocol::Oxford,
+        "ProxfordYmVfjWnRcgjWH36fW6PArwqykTFzotUxRs6gmTcZDuH",
+        "oxford",
+    ),
+    (
+        Protocol::Paris,
+        "PtParisBxoLz5gzMmn3d9WBQNoPSZakgnkMC2VNuQ3KXfUtUQeZ",
+        "paris",
+    ),
+    (
+        Protocol::ParisC,
+        "PsParisCZo7KAh1Z1smVd9ZMZ1HHn5gkzbM94V3PLCpknFWhUAi",
+        "paris_c",
+    ),
+    (
+        Protocol::Quebec,
+        "PsQuebecnLByd3JwTiGadoG4nGWi3HYiLXUjkibeFV8dCFeVMUg",
+        "quebec",
+    ),
+    (
+        Protocol::Rio,
+        "PsRiotumaAMotcRoDWW1bysEhQy2n1M5fy8JgRp8jjRfHGmfeA7",
+        "rio",
+    ),
+    (
+        Protocol::Alpha,
+        "ProtoALphaALphaALphaALphaALphaALphaALphaALphaDdp3zK",
+        "alpha",
+    ),
+];
+
+impl Protocol {
+    /// Protocol with the given base58 hash, or [`Unknown`](Self::Unknown).
+    pub fn from_hash(hash: &str) -> Self {
+        KNOWN_PROTOCOLS
+            .iter()
+            .find(|(_, known_hash, _)| *known_hash == hash)
+            .map(|(protocol, _, _)| protocol.clone())
+            .unwrap_or_else(|| Self::Unknown(hash.to_string()))
+    }
+
+    pub fn hash(&self) -> &str {
+        match self {
+            Self::Unknown(hash) => hash,
+            known => known.entry().1,
+        }
+    }
+
+    /// Lowercase protocol name, `"unknown"` for unknown protocols.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Unknown(_) => "unknown",
+            known => known.entry().2,
+        }
+    }
+
+    pub fn is_known(&self) -> bool {
+        !matches!(self, Self::Unknown(_))
+    }
+
+    fn entry(&self) -> &'static (Protocol, &'static str, &'static str) {
+        KNOWN_PROTOCOLS
+            .iter()
+            .find(|(protocol, _, _)| protocol == self)
+            .expect("every known protocol has an entry")
+    }
+}
+
+impl fmt::Display for Protocol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.hash())
+    }
+}
+
+impl Serialize for Protocol {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.hash())
+    }
+}
+
+impl<'de> Deserialize<'de> for Protocol {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let hash = String::deserialize(deserializer)?;
+        Ok(Self::from_hash(&hash))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn known_protocol_hashes_round_trip() {
+        for (protocol, hash, _) in KNOWN_PROTOCOLS.iter() {
+            assert_eq!(&Protocol::from_hash(hash), protocol);
+            assert_eq!(protocol.hash(), *hash);
+            assert!(hash.starts_with('P'));
+            assert_eq!(
+                bs58::decode(hash)
+                    .with_check(None)
+                    .into_vec()
+                    .unwrap()
+                    .len(),
+                34
+            );
+        }
+    }
+
+    #[test]
+    fn protocols_are_ordered_by_activation() {
+        let unknown = Protocol::from_hash("PtNextProtocol");
+
+        assert!(Protocol::Nairobi < Protocol::Oxford);
+        assert!(
+            Protocol::from_hash("PsddFKi32cMJ2qPjf43Qv5GDWLDPZb3T3bF6fLKiF5HtvHNU7aP")
+                < Protocol::Babylon
+        );
+        assert!(Protocol::Quebec > Protocol::Paris);
+        assert!(!unknown.is_known());
+        assert!(unknown > Protocol::Alpha);
+        assert_eq!(unknown.hash(), "PtNextProtocol");
+    }
+
+    #[test]
+    fn protocol_serde_ok() {
+        let protocol: Protocol =
+            serde_json::from_str(r#""PsQuebecnLByd3JwTiGadoG4nGWi3HYiLXUjkibeFV8dCFeVMUg""#)
+                .unwrap();
+
+        assert_eq!(protocol, Protocol::Quebec);
+        assert_eq!(protocol.name(), "quebec");
+        assert_eq!(
+            serde_json::to_string(&protocol).unwrap(),
+            r#""PsQuebecnLByd3JwTiGadoG4nGWi3HYiLXUjkibeFV8dCFeVMUg""#
+        );
+    }
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// How the node should print Michelson values it returns: with readable
+/// strings for addresses, keys and timestamps, or in their optimized
+/// binary form.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UnparsingMode {
+    #[default]
+    Readable,
+    Optimized,
+    #[serde(rename = "Optimized_legacy")]
+    OptimizedLegacy,
+}
@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The kind of a voting period of the amendment process.
+///
+/// Protocols before Edo named exploration, cooldown and promotion periods
+/// `testing_vote`, `testing` and `promotion_vote`; these names are accepted
+/// when deserializing.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum VotingPeriodKind {
+    Proposal,
+    #[serde(alias = "testing_vote")]
+    Exploration,
+    #[serde(alias = "testing")]
+    Cooldown,
+    #[serde(alias = "promotion_vote")]
+    Promotion,
+    Adoption,
+}
+
+impl VotingPeriodKind {
+    pub fn to_str(&self) -> &str {
+        match self {
+            Self::Proposal => "proposal",
+            Self::Exploration => "exploration",
+            Self::Cooldown => "cooldown",
+            Self::Promotion => "promotion",
+            Self::Adoption => "adoption",
+        }
+    }
+
+    /// Whether delegates cast ballots (rather than proposals) during this
+    /// kind of period.
+    pub fn is_ballot_period(&self) -> bool {
+        matches!(self, Self::Exploration | Self::Promotion)
+    }
+}
+
+impl fmt::Display for VotingPeriodKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_str())
+    }
+}
+
+/// A vote cast by a delegate during an exploration or promotion period.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum Ballot {
+    Yay,
+    Nay,
+    Pass,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn legacy_period_kind_names_ok() {
+        let kind: VotingPeriodKind = serde_json::from_str(r#""testing_vote""#).unwrap();
+        assert_eq!(kind, VotingPeriodKind::Exploration);
+        assert_eq!(serde_json::to_string(&kind).unwrap(), r#""exploration""#);
+        assert!(kind.is_ballot_period());
+    }
+
+    #[test]
+    fn unknown_period_kind_fails() {
+        assert!(serde_json::from_str::<VotingPeriodKind>(r#""voting""#).is_err());
+    }
+}
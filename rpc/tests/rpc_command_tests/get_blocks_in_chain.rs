@@ -1,5 +1,5 @@
 use super::*;
-use chrono::{self, NaiveDateTime};
+use chrono::{self, DateTime, NaiveDateTime};
 use commands::chains::blocks::get_blocks_in_chain::GetBlocksInChain;
 
 #[tokio::test]
@@ -14,7 +14,7 @@ async fn get_blocks_in_chain_ok() {
 
     let block_response = client_response.unwrap();
     let blocks = block_response.block_ids.into_vec();
-    assert!(blocks.len() > 0);
+    assert!(!blocks.is_empty());
 }
 
 #[tokio::test]
@@ -41,7 +41,7 @@ async fn get_blocks_from_head_optional_params_ok() {
 
     let block_response = client_response.unwrap();
     let blocks = block_response.block_ids.into_vec();
-    assert!(blocks.len() >= 1);
+    assert!(!blocks.is_empty());
 }
 
 #[tokio::test]
@@ -77,7 +77,7 @@ async fn get_block_optional_min_date_at_epoch_ok() {
     let block_response = client_response.unwrap();
     let blocks = block_response.block_ids;
 
-    assert!(blocks.len() > 0);
+    assert!(!blocks.is_empty());
 }
 
 #[tokio::test]
@@ -100,9 +100,9 @@ async fn get_block_optional_min_date_now_ok() {
 #[tokio::test]
 async fn get_blocks_with_min_date_and_length_ok() {
     let min_date = Some(get_test_naive_datetime_at_epoch());
-    let length = Some(5);
+    let length = 5;
 
-    let command = generate_get_blocks_command_with_explicit_params(length, None, min_date);
+    let command = generate_get_blocks_command_with_explicit_params(Some(length), None, min_date);
 
     let client = get_rpc_client();
     assert!(client.check_node_online().await);
@@ -114,16 +114,16 @@ async fn get_blocks_with_min_date_and_length_ok() {
     let nested_blocks = block_response.block_ids;
 
     for block in nested_blocks {
-        assert_eq!(block.len(), length.unwrap() as usize);
+        assert_eq!(block.len(), length as usize);
     }
 }
 
 #[tokio::test]
 async fn get_blocks_with_head_and_length_ok() {
-    let length = Some(5);
+    let length = 5;
     let head_hash = Some("BKmXPAiniarmJAxvv3T3CQ9sa42TbLiDdE6c57Gc74NjcKNFQBE".to_string());
 
-    let command = generate_get_blocks_command_with_explicit_params(length, head_hash, None);
+    let command = generate_get_blocks_command_with_explicit_params(Some(length), head_hash, None);
 
     let client = get_rpc_client();
     assert!(client.check_node_online().await);
@@ -134,16 +134,16 @@ async fn get_blocks_with_head_and_length_ok() {
     let block_response = client_response.unwrap();
     let flattened_blocks = block_response.block_ids.into_flattened_vec();
 
-    assert_eq!(flattened_blocks.len(), length.unwrap() as usize);
+    assert_eq!(flattened_blocks.len(), length as usize);
 }
 
 #[tokio::test]
 async fn get_blocks_all_optional_args_ok() {
-    let length = Some(5);
+    let length = 5;
     let min_date = Some(get_test_naive_datetime_at_epoch());
     let head_hash = Some("BKmXPAiniarmJAxvv3T3CQ9sa42TbLiDdE6c57Gc74NjcKNFQBE".to_string());
 
-    let command = generate_get_blocks_command_with_explicit_params(length, head_hash, min_date);
+    let command = generate_get_blocks_command_with_explicit_params(Some(length), head_hash, min_date);
 
     let client = get_rpc_client();
     assert!(client.check_node_online().await);
@@ -154,7 +154,7 @@ async fn get_blocks_all_optional_args_ok() {
     let block_response = client_response.unwrap();
     let flattened_blocks = block_response.block_ids.into_flattened_vec();
 
-    assert_eq!(flattened_blocks.len(), length.unwrap() as usize);
+    assert_eq!(flattened_blocks.len(), length as usize);
 }
 
 fn generate_get_blocks_command_with_explicit_params(
@@ -171,7 +171,7 @@ fn get_naive_datetime_for_now() -> NaiveDateTime {
 }
 
 fn get_test_naive_datetime_at_epoch() -> NaiveDateTime {
-    NaiveDateTime::from_timestamp(0, 0)
+    DateTime::from_timestamp(0, 0).unwrap().naive_utc()
 }
 
 fn generate_get_blocks_command_for_main_chain() -> GetBlocksInChain {
@@ -13,7 +13,7 @@ async fn get_invalid_blocks_in_chain_ok() {
 
 	let invalid_blocks_response = client_response.unwrap();
 	let invalid_blocks = invalid_blocks_response.invalid_blocks.into_vec();
-	assert!(invalid_blocks.len() == 0);
+	assert!(invalid_blocks.is_empty());
 }
 
 fn generate_get_blocks_command_for_main_chain() -> GetInvalidBlocksInChain {